@@ -0,0 +1,16 @@
+use std::ops::Range;
+
+use inquire::LazySelect;
+
+fn main() {
+    let provider = |range: Range<usize>| range.map(|i| format!("Order #{:07}", i)).collect();
+
+    let ans = LazySelect::new("Which order do you want to inspect?", 1_000_000, &provider)
+        .with_page_indicator(true)
+        .prompt();
+
+    match ans {
+        Ok(order) => println!("Inspecting {}", order),
+        Err(_) => println!("There was an error, please try again"),
+    }
+}
@@ -0,0 +1,13 @@
+use inquire::{min_length, TextList};
+
+fn main() {
+    let tags = TextList::new("Which tags should be applied?")
+        .with_validator(min_length!(2))
+        .with_help_message("Press enter on an empty line to finish")
+        .prompt();
+
+    match tags {
+        Ok(tags) => println!("Applying {} tags", tags.len()),
+        Err(_) => println!("An error happened when asking for the tags, try again later."),
+    }
+}
@@ -1,4 +1,9 @@
-use inquire::{error::CustomUserError, length, required, ui::RenderConfig, Text};
+use inquire::{
+    error::CustomUserError,
+    length, required,
+    ui::{Keymap, RenderConfig},
+    Text, TrimMode,
+};
 
 fn main() {
     let answer = Text::new("What's your name?")
@@ -15,11 +20,34 @@ fn main() {
         initial_value: None,
         default: None,
         placeholder: Some("Good"),
+        max_length: None,
         help_message: None,
+        default_help_messages: true,
         formatter: Text::DEFAULT_FORMATTER,
         validators: Vec::new(),
+        trim: TrimMode::None,
+        required: false,
+        live_validation: false,
+        audible_errors: false,
+        max_attempts: None,
+        confirm_cancel: false,
+        clear_screen: false,
+        alternate_screen: false,
+        keymap: Keymap::default(),
         page_size: Text::DEFAULT_PAGE_SIZE,
+        max_suggestions: None,
+        common_prefix_completion: false,
+        multiline: false,
+        event_hook: None,
+        debug_log: None,
+        timeout: None,
+        confirmation: None,
+        history: Vec::new(),
+        history_file: None,
         autocompleter: None,
+        preview: None,
+        suggestion_transform: None,
+        suggestion_debounce: None,
         render_config: RenderConfig::default(),
     }
     .prompt()
@@ -11,6 +11,13 @@
 //! Check the example files to see some usages, recommended are `expense_tracker.rs`
 //! and `complex_autocompletion.rs`.
 
+use std::{
+    cmp::Reverse,
+    fmt::{self, Display},
+    io::ErrorKind,
+    path::PathBuf,
+};
+
 use dyn_clone::DynClone;
 
 use crate::CustomUserError;
@@ -21,9 +28,70 @@ use crate::CustomUserError;
 /// `Some(String)` will replace the current text input with the `String` in `Some`.
 pub type Replacement = Option<String>;
 
+/// Input suggestion displayed to the user, optionally accompanied by a
+/// description rendered after the suggestion value.
+///
+/// Only the `value` is used when the suggestion is completed or submitted,
+/// the description is purely informative.
+///
+/// # Example
+///
+/// ```
+/// use inquire::autocompletion::Suggestion;
+///
+/// let suggestion = Suggestion::new("commit").with_description("Record changes to the repository");
+///
+/// assert_eq!("commit", suggestion.value);
+/// assert_eq!(Some("Record changes to the repository"), suggestion.description.as_deref());
+/// assert_eq!(Suggestion::new("push"), Suggestion::from("push"));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Suggestion {
+    /// Value of the suggestion, used when it is completed or submitted.
+    pub value: String,
+
+    /// Description displayed next to the suggestion value.
+    pub description: Option<String>,
+}
+
+impl Suggestion {
+    /// Creates a [Suggestion] with the provided value and no description.
+    pub fn new<S: Into<String>>(value: S) -> Self {
+        Self {
+            value: value.into(),
+            description: None,
+        }
+    }
+
+    /// Sets the description of the suggestion.
+    pub fn with_description<S: Into<String>>(mut self, description: S) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+}
+
+impl Display for Suggestion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+impl From<String> for Suggestion {
+    fn from(value: String) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<&str> for Suggestion {
+    fn from(value: &str) -> Self {
+        Self::new(value)
+    }
+}
+
 /// Mechanism to implement autocompletion features for text inputs. The `Autocomplete` trait has two provided methods: `get_suggestions` and `get_completion`.
 ///
 /// - `get_suggestions` is called whenever the user's text input is modified, e.g. a new letter is typed, returning a `Vec<String>`. The `Vec<String>` is the list of suggestions that the prompt displays to the user according to their text input. The user can then navigate through the list and if they submit while highlighting one of these suggestions, the suggestion is treated as the final answer.
+/// - `get_described_suggestions` is the method actually called by prompts, and by default returns the suggestions from `get_suggestions` without any description. Override it in case you want to display a description, e.g. a help text, alongside each suggestion. Descriptions are only displayed, only the suggestion values are used as completions or answers.
 /// - `get_completion` is called whenever the user presses the autocompletion hotkey (`tab` by default), with the current text input and the text of the currently highlighted suggestion, if any, as parameters. This method should return whether any text replacement (an autocompletion) should be made. If the prompt receives a replacement to be made, it substitutes the current text input for the string received from the `get_completion` call.
 ///
 /// For example, in the `complex_autocompletion.rs` example file, the `FilePathCompleter` scans the file system based on the current text input, storing a list of paths that match the current text input.
@@ -38,6 +106,23 @@ pub trait Autocomplete: DynClone {
     /// content of the suggestion string.
     fn get_suggestions(&mut self, input: &str) -> Result<Vec<String>, CustomUserError>;
 
+    /// List of input suggestions, each one optionally accompanied by a description,
+    /// to be displayed to the user upon typing the text input.
+    ///
+    /// This is the method called by prompts when the text input is modified. By
+    /// default, it returns the values from [`get_suggestions`](Self::get_suggestions)
+    /// without any descriptions. Override it if you want to display descriptions.
+    fn get_described_suggestions(
+        &mut self,
+        input: &str,
+    ) -> Result<Vec<Suggestion>, CustomUserError> {
+        Ok(self
+            .get_suggestions(input)?
+            .into_iter()
+            .map(Suggestion::from)
+            .collect())
+    }
+
     /// Standalone autocompletion that can be implemented based solely on the user's
     /// input.
     ///
@@ -98,3 +183,432 @@ where
         Ok(suggestion)
     }
 }
+
+/// Autocompleter that suggests, from a fixed list of candidates, the ones that
+/// fuzzily match the user's input.
+///
+/// A candidate matches when all characters of the input appear in it in the same
+/// order, case-insensitively, e.g. `"gco"` matches `"git commit"`. Suggestions are
+/// sorted best match first, favoring characters matched at the start of words
+/// and in consecutive runs. Candidates with the same score keep their original order.
+///
+/// When the user triggers an autocompletion, the highlighted suggestion, if any,
+/// replaces the text input.
+///
+/// # Example
+///
+/// ```
+/// use inquire::autocompletion::{Autocomplete, FuzzyAutocomplete};
+///
+/// let mut autocomplete = FuzzyAutocomplete::new(vec!["git status", "git commit", "cargo"]);
+///
+/// assert_eq!(vec!["git commit"], autocomplete.get_suggestions("gco")?);
+/// # Ok::<(), inquire::error::CustomUserError>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct FuzzyAutocomplete {
+    candidates: Vec<String>,
+}
+
+impl FuzzyAutocomplete {
+    /// Creates a [FuzzyAutocomplete] suggesting values from the given candidates.
+    pub fn new<S: Into<String>>(candidates: Vec<S>) -> Self {
+        Self {
+            candidates: candidates.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Score of the match between the input and the candidate, `None` if the
+    /// characters of the input are not a subsequence of the candidate's.
+    fn score(input: &str, candidate: &str) -> Option<i64> {
+        let mut input = input.chars().flat_map(char::to_lowercase).peekable();
+
+        let mut score = 0;
+        let mut previous: Option<char> = None;
+        let mut previous_matched = false;
+
+        for c in candidate.chars() {
+            let expected = match input.peek() {
+                Some(expected) => *expected,
+                None => break,
+            };
+
+            if c.to_lowercase().eq(expected.to_lowercase()) {
+                score += 1;
+
+                if previous_matched {
+                    score += 5;
+                }
+                if previous.map_or(true, |p| !p.is_alphanumeric()) {
+                    score += 3;
+                }
+
+                input.next();
+                previous_matched = true;
+            } else {
+                score -= 1;
+                previous_matched = false;
+            }
+
+            previous = Some(c);
+        }
+
+        match input.peek() {
+            Some(_) => None,
+            None => Some(score),
+        }
+    }
+}
+
+impl Autocomplete for FuzzyAutocomplete {
+    fn get_suggestions(&mut self, input: &str) -> Result<Vec<String>, CustomUserError> {
+        let mut matches = self
+            .candidates
+            .iter()
+            .filter_map(|candidate| Self::score(input, candidate).map(|score| (score, candidate)))
+            .collect::<Vec<(i64, &String)>>();
+
+        matches.sort_by_key(|(score, _)| Reverse(*score));
+
+        Ok(matches
+            .into_iter()
+            .map(|(_, candidate)| candidate.clone())
+            .collect())
+    }
+
+    fn get_completion(
+        &mut self,
+        _: &str,
+        highlighted_suggestion: Option<String>,
+    ) -> Result<Replacement, CustomUserError> {
+        Ok(highlighted_suggestion)
+    }
+}
+
+/// Autocompleter that suggests the file system entries matching the path
+/// typed by the user.
+///
+/// The entries suggested are the ones of the directory typed so far, whose
+/// name starts with the last component of the input, e.g. `src/ma` suggests
+/// `src/main.rs`. Directories are suggested with a trailing slash, so that
+/// completing one of them lists its contents next. Hidden entries, whose
+/// names start with a dot, are only suggested when the last component of the
+/// input starts with a dot as well. A leading `~` refers to the home directory
+/// of the user, and is kept as typed in the suggestions.
+///
+/// When the user triggers an autocompletion, the highlighted suggestion, if any,
+/// replaces the text input. Otherwise, the input is extended to the longest
+/// prefix shared by all suggestions, similar to how shells complete paths.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::{autocompletion::FilePathAutocomplete, Text};
+///
+/// let path = Text::new("Profile picture:")
+///     .with_autocomplete(FilePathAutocomplete::new())
+///     .prompt();
+/// ```
+#[derive(Clone, Debug)]
+pub struct FilePathAutocomplete {
+    home: Option<PathBuf>,
+}
+
+impl FilePathAutocomplete {
+    /// Creates a [FilePathAutocomplete], expanding `~` to the home directory
+    /// of the current user.
+    pub fn new() -> Self {
+        let home = std::env::var_os("HOME")
+            .or_else(|| std::env::var_os("USERPROFILE"))
+            .map(PathBuf::from);
+
+        Self { home }
+    }
+
+    /// Splits the input into the directory typed so far, including its trailing
+    /// separator, and the prefix of the entry names to be suggested.
+    fn split_input(input: &str) -> (&str, &str) {
+        if input == "~" {
+            return ("~/", "");
+        }
+
+        match input.rfind(|c| c == '/' || c == std::path::MAIN_SEPARATOR) {
+            Some(idx) => input.split_at(idx + 1),
+            None => ("", input),
+        }
+    }
+
+    /// Path of the directory to be scanned, with `~` expanded.
+    fn scan_dir(&self, dir: &str) -> PathBuf {
+        if dir.is_empty() {
+            return PathBuf::from(".");
+        }
+
+        match (dir.strip_prefix('~'), &self.home) {
+            (Some(rest), Some(home)) if rest.is_empty() || rest.starts_with('/') => {
+                home.join(rest.trim_start_matches('/'))
+            }
+            _ => PathBuf::from(dir),
+        }
+    }
+
+    fn list_entries(&self, input: &str) -> Result<Vec<String>, CustomUserError> {
+        let (dir, prefix) = Self::split_input(input);
+        let scan_dir = self.scan_dir(dir);
+
+        let entries = match std::fs::read_dir(&scan_dir) {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(vec![]),
+            Err(err) => return Err(err.into()),
+        };
+
+        let mut suggestions = vec![];
+        for entry in entries {
+            let entry = entry?;
+            let name = entry.file_name().to_string_lossy().into_owned();
+
+            if !name.starts_with(prefix) || (name.starts_with('.') && !prefix.starts_with('.')) {
+                continue;
+            }
+
+            let suggestion = format!("{}{}", dir, name);
+            match entry.path().is_dir() {
+                true => suggestions.push(format!("{}/", suggestion)),
+                false => suggestions.push(suggestion),
+            }
+        }
+        suggestions.sort();
+
+        Ok(suggestions)
+    }
+
+    fn longest_common_prefix(suggestions: &[String]) -> &str {
+        let (first, last) = match (suggestions.first(), suggestions.last()) {
+            (Some(first), Some(last)) => (first, last),
+            _ => return "",
+        };
+
+        let len = first
+            .char_indices()
+            .zip(last.chars())
+            .find(|((_, a), b)| a != b)
+            .map_or(first.len(), |((idx, _), _)| idx);
+
+        &first[..len]
+    }
+}
+
+impl Default for FilePathAutocomplete {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Autocomplete for FilePathAutocomplete {
+    fn get_suggestions(&mut self, input: &str) -> Result<Vec<String>, CustomUserError> {
+        self.list_entries(input)
+    }
+
+    fn get_completion(
+        &mut self,
+        input: &str,
+        highlighted_suggestion: Option<String>,
+    ) -> Result<Replacement, CustomUserError> {
+        if highlighted_suggestion.is_some() {
+            return Ok(highlighted_suggestion);
+        }
+
+        let suggestions = self.list_entries(input)?;
+        let prefix = Self::longest_common_prefix(&suggestions);
+
+        Ok(match prefix.len() > input.len() {
+            true => Replacement::Some(prefix.to_owned()),
+            false => Replacement::None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{fs, path::PathBuf};
+
+    use super::{Autocomplete, FilePathAutocomplete, FuzzyAutocomplete};
+
+    fn autocomplete() -> FuzzyAutocomplete {
+        FuzzyAutocomplete::new(vec![
+            "git status",
+            "git commit",
+            "git checkout",
+            "cargo check",
+            "cargo clippy",
+            "cat",
+        ])
+    }
+
+    #[test]
+    fn fuzzy_suggestions_are_subsequence_matches() {
+        let suggestions = autocomplete().get_suggestions("gco").unwrap();
+
+        assert_eq!(vec!["git commit", "git checkout"], suggestions);
+    }
+
+    #[test]
+    fn fuzzy_suggestions_are_sorted_best_first() {
+        let suggestions = autocomplete().get_suggestions("ch").unwrap();
+        assert_eq!(vec!["git checkout", "cargo check"], suggestions);
+
+        let suggestions = autocomplete().get_suggestions("gcc").unwrap();
+        assert_eq!(vec!["git checkout", "cargo check"], suggestions);
+    }
+
+    #[test]
+    fn fuzzy_suggestions_with_same_score_keep_candidate_order() {
+        let suggestions = autocomplete().get_suggestions("ca").unwrap();
+
+        assert_eq!(vec!["cargo check", "cargo clippy", "cat"], suggestions);
+    }
+
+    #[test]
+    fn fuzzy_suggestions_are_case_insensitive() {
+        let suggestions = autocomplete().get_suggestions("GSTAT").unwrap();
+
+        assert_eq!(vec!["git status"], suggestions);
+    }
+
+    #[test]
+    fn empty_input_suggests_all_candidates_in_order() {
+        let suggestions = autocomplete().get_suggestions("").unwrap();
+
+        assert_eq!(6, suggestions.len());
+        assert_eq!("git status", suggestions[0]);
+    }
+
+    #[test]
+    fn unmatched_input_has_no_suggestions() {
+        let suggestions = autocomplete().get_suggestions("xyz").unwrap();
+
+        assert!(suggestions.is_empty());
+    }
+
+    /// Temporary directory containing `notes.txt`, `numbers.csv`, `.hidden`
+    /// and the directory `nested`, itself containing `inner.rs`.
+    struct TempTree(PathBuf);
+
+    impl TempTree {
+        fn new(name: &str) -> Self {
+            let root = std::env::temp_dir().join(format!(
+                "inquire-file-path-{}-{}",
+                name,
+                std::process::id()
+            ));
+            let _ = fs::remove_dir_all(&root);
+
+            fs::create_dir_all(root.join("nested")).unwrap();
+            fs::write(root.join("notes.txt"), "").unwrap();
+            fs::write(root.join("numbers.csv"), "").unwrap();
+            fs::write(root.join(".hidden"), "").unwrap();
+            fs::write(root.join("nested").join("inner.rs"), "").unwrap();
+
+            Self(root)
+        }
+
+        fn input(&self, rest: &str) -> String {
+            format!("{}/{}", self.0.to_string_lossy(), rest)
+        }
+    }
+
+    impl Drop for TempTree {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    #[test]
+    fn file_path_suggests_matching_files_and_directories() {
+        let tree = TempTree::new("matching");
+        let mut autocomplete = FilePathAutocomplete::new();
+
+        let suggestions = autocomplete.get_suggestions(&tree.input("n")).unwrap();
+
+        assert_eq!(
+            vec![
+                tree.input("nested/"),
+                tree.input("notes.txt"),
+                tree.input("numbers.csv")
+            ],
+            suggestions
+        );
+    }
+
+    #[test]
+    fn file_path_trailing_slash_lists_directory_contents() {
+        let tree = TempTree::new("trailing-slash");
+        let mut autocomplete = FilePathAutocomplete::new();
+
+        let suggestions = autocomplete
+            .get_suggestions(&tree.input("nested/"))
+            .unwrap();
+
+        assert_eq!(vec![tree.input("nested/inner.rs")], suggestions);
+    }
+
+    #[test]
+    fn file_path_hidden_entries_require_a_leading_dot() {
+        let tree = TempTree::new("hidden");
+        let mut autocomplete = FilePathAutocomplete::new();
+
+        let all = autocomplete.get_suggestions(&tree.input("")).unwrap();
+        assert!(!all.contains(&tree.input(".hidden")));
+
+        let dotted = autocomplete.get_suggestions(&tree.input(".")).unwrap();
+        assert_eq!(vec![tree.input(".hidden")], dotted);
+    }
+
+    #[test]
+    fn file_path_completes_the_longest_common_prefix() {
+        let tree = TempTree::new("completion");
+        let mut autocomplete = FilePathAutocomplete::new();
+
+        let completion = autocomplete
+            .get_completion(&tree.input("nu"), None)
+            .unwrap();
+        assert_eq!(Some(tree.input("numbers.csv")), completion);
+
+        let completion = autocomplete
+            .get_completion(&tree.input("ne"), None)
+            .unwrap();
+        assert_eq!(Some(tree.input("nested/")), completion);
+
+        let completion = autocomplete.get_completion(&tree.input("n"), None).unwrap();
+        assert_eq!(None, completion);
+    }
+
+    #[test]
+    fn file_path_expands_home_directory() {
+        let tree = TempTree::new("home");
+        let mut autocomplete = FilePathAutocomplete {
+            home: Some(tree.0.clone()),
+        };
+
+        let suggestions = autocomplete.get_suggestions("~/nested/").unwrap();
+        assert_eq!(vec!["~/nested/inner.rs"], suggestions);
+
+        let suggestions = autocomplete.get_suggestions("~").unwrap();
+        assert_eq!(
+            vec!["~/nested/", "~/notes.txt", "~/numbers.csv"],
+            suggestions
+        );
+    }
+
+    #[test]
+    fn file_path_missing_directory_has_no_suggestions() {
+        let tree = TempTree::new("missing");
+        let mut autocomplete = FilePathAutocomplete::new();
+
+        let suggestions = autocomplete
+            .get_suggestions(&tree.input("missing/"))
+            .unwrap();
+
+        assert!(suggestions.is_empty());
+    }
+}
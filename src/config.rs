@@ -9,6 +9,69 @@ use crate::ui::RenderConfig;
 lazy_static! {
     static ref GLOBAL_RENDER_CONFIGURATION: Mutex<RenderConfig> =
         Mutex::new(RenderConfig::default());
+    static ref GLOBAL_CONFIGURATION: Mutex<Config> = Mutex::new(Config::default());
+}
+
+/// Behavioral defaults applied to prompts when they are created.
+///
+/// The values are read by the prompt constructors, e.g. `Select::new`, so
+/// the `with_*` methods of each prompt still override them.
+///
+/// # Example
+///
+/// ```
+/// use inquire::{set_global_config, Config, Select};
+///
+/// set_global_config(Config {
+///     page_size: 10,
+///     ..Config::default()
+/// });
+///
+/// let prompt = Select::new("Question", vec!["a", "b", "c"]);
+/// assert_eq!(10, prompt.page_size);
+///
+/// let prompt = Select::new("Question", vec!["a", "b", "c"]).with_page_size(3);
+/// assert_eq!(3, prompt.page_size);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Config {
+    /// Page size of the options, or suggestions, displayed to the user.
+    pub page_size: usize,
+
+    /// Whether vim mode is enabled in prompts supporting it.
+    pub vim_mode: bool,
+
+    /// Whether prompts display their default help messages.
+    pub help_messages: bool,
+
+    /// Whether styles, i.e. colors and text attributes such as bold, are
+    /// rendered even when the output is not a terminal, e.g. when it is piped
+    /// to a file.
+    ///
+    /// Only styles are omitted from such outputs: the escape sequences moving
+    /// the cursor and clearing lines, which redraw the prompt, are always
+    /// written.
+    pub force_color: bool,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            page_size: DEFAULT_PAGE_SIZE,
+            vim_mode: DEFAULT_VIM_MODE,
+            help_messages: true,
+            force_color: false,
+        }
+    }
+}
+
+impl Config {
+    /// Enables or disables the rendering of styles, i.e. colors and text
+    /// attributes, when the output is not a terminal.
+    pub fn with_force_color(mut self, force_color: bool) -> Self {
+        self.force_color = force_color;
+        self
+    }
 }
 
 pub fn get_configuration() -> RenderConfig {
@@ -22,6 +85,34 @@ pub fn set_global_render_config(config: RenderConfig) {
     *guard = config;
 }
 
+/// Returns a copy of the global Config object.
+pub fn get_global_config() -> Config {
+    *GLOBAL_CONFIGURATION.lock().unwrap()
+}
+
+/// Acquires a write lock to the global Config object
+/// and updates the inner value with the provided argument.
+///
+/// Only prompts created after this call are affected.
+///
+/// # Example
+///
+/// ```
+/// use inquire::{get_global_config, set_global_config, Config, MultiSelect};
+///
+/// set_global_config(Config {
+///     help_messages: false,
+///     ..get_global_config()
+/// });
+///
+/// let prompt = MultiSelect::new("Question", vec![1, 2, 3]);
+/// assert_eq!(None, prompt.help_message);
+/// ```
+pub fn set_global_config(config: Config) {
+    let mut guard = GLOBAL_CONFIGURATION.lock().unwrap();
+    *guard = config;
+}
+
 /// Default page size when displaying options to the user.
 pub const DEFAULT_PAGE_SIZE: usize = 7;
 
@@ -38,6 +38,11 @@ pub enum InquireError {
     #[error("Operation was interrupted by the user")]
     OperationInterrupted,
 
+    /// The user submitted invalid answers as many times as allowed by the
+    /// maximum number of attempts of the prompt.
+    #[error("Maximum number of attempts reached")]
+    TooManyAttempts,
+
     /// Error while executing IO operations.
     #[error("User-provided error: {0}")]
     Custom(#[from] CustomUserError),
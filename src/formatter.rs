@@ -65,6 +65,22 @@ use crate::list_option::ListOption;
 /// ```
 pub type StringFormatter<'a> = &'a dyn Fn(&str) -> String;
 
+/// Type alias for formatters used in [`TextList`](crate::TextList) prompts.
+///
+/// Formatters receive the items added by the user and return a [String] to be
+/// displayed to the user as the final answer.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::formatter::StringListFormatter;
+///
+/// let formatter: StringListFormatter = &|items| format!("{} tags", items.len());
+/// let items = vec![String::from("rust"), String::from("cli")];
+/// assert_eq!(String::from("2 tags"), formatter(&items));
+/// ```
+pub type StringListFormatter<'a> = &'a dyn Fn(&[String]) -> String;
+
 /// Type alias for formatters used in [Confirm](crate::Confirm) prompts.
 ///
 /// Formatters receive the user input and return a [String] to be displayed
@@ -101,6 +117,26 @@ pub type BoolFormatter<'a> = &'a dyn Fn(bool) -> String;
 /// ```
 pub type OptionFormatter<'a, T> = &'a dyn Fn(ListOption<&T>) -> String;
 
+/// Type alias for formatters used to display each option of a [Select](crate::Select) prompt.
+///
+/// Formatters receive the option and whether it is currently highlighted, and
+/// return a [String] to be displayed in place of the option in the list.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::list_option::ListOption;
+/// use inquire::formatter::OptionDisplayFormatter;
+///
+/// let formatter: OptionDisplayFormatter<str> = &|i, selected| match selected {
+///     true => format!("> {}", i.value),
+///     false => format!("  {}", i.value),
+/// };
+/// assert_eq!(String::from("> a"), formatter(ListOption::new(0, "a"), true));
+/// assert_eq!(String::from("  b"), formatter(ListOption::new(1, "b"), false));
+/// ```
+pub type OptionDisplayFormatter<'a, T> = &'a dyn Fn(ListOption<&T>, bool) -> String;
+
 /// Type alias for formatters used in [`MultiSelect`](crate::MultiSelect) prompts.
 ///
 /// Formatters receive the user input and return a [String] to be displayed
@@ -184,6 +220,42 @@ pub type DateFormatter<'a> = &'a dyn Fn(chrono::NaiveDate) -> String;
 /// ```
 pub const DEFAULT_STRING_FORMATTER: StringFormatter = &|val| String::from(val);
 
+/// String formatter that displays the digits of the input in groups of four,
+/// separated by spaces, such as credit card numbers. Any character that is not
+/// an ASCII digit is discarded.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::formatter::DIGIT_GROUPS_FORMATTER;
+///
+/// let formatter = DIGIT_GROUPS_FORMATTER;
+/// assert_eq!(String::from("4111 1111 1111 1111"), formatter("4111111111111111"));
+/// assert_eq!(String::from("4111 1111 1"), formatter("4111-1111-1"));
+/// ```
+pub const DIGIT_GROUPS_FORMATTER: StringFormatter = &|val| {
+    let digits: Vec<char> = val.chars().filter(char::is_ascii_digit).collect();
+
+    digits
+        .chunks(4)
+        .map(|group| group.iter().collect::<String>())
+        .collect::<Vec<String>>()
+        .join(" ")
+};
+
+/// String formatter that trims the leading and trailing whitespace of the
+/// input and converts it to lowercase.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::formatter::NORMALIZED_STRING_FORMATTER;
+///
+/// let formatter = NORMALIZED_STRING_FORMATTER;
+/// assert_eq!(String::from("times square"), formatter("  Times Square "));
+/// ```
+pub const NORMALIZED_STRING_FORMATTER: StringFormatter = &|val| val.trim().to_lowercase();
+
 /// String formatter used by default in [Confirm](crate::Confirm) prompts.
 /// Translates `bool` to `"Yes"` and `false` to `"No"`.
 ///
@@ -226,3 +298,30 @@ pub const DEFAULT_BOOL_FORMATTER: BoolFormatter = &|ans| {
 /// );
 /// ```
 pub const DEFAULT_DATE_FORMATTER: DateFormatter = &|val| val.format("%B %-e, %Y").to_string();
+
+#[cfg(test)]
+mod test {
+    use super::{DIGIT_GROUPS_FORMATTER, NORMALIZED_STRING_FORMATTER};
+
+    #[test]
+    fn digit_groups_formatter() {
+        let formatter = DIGIT_GROUPS_FORMATTER;
+
+        assert_eq!("", formatter(""));
+        assert_eq!("", formatter("abc"));
+        assert_eq!("123", formatter("123"));
+        assert_eq!("1234", formatter("1234"));
+        assert_eq!("1234 5", formatter("12345"));
+        assert_eq!("4111 1111 1111 1111", formatter("4111 1111 1111 1111"));
+        assert_eq!("4111 1111 1111 1111", formatter(" 4111-1111-1111-1111 "));
+    }
+
+    #[test]
+    fn normalized_string_formatter() {
+        let formatter = NORMALIZED_STRING_FORMATTER;
+
+        assert_eq!("", formatter("   "));
+        assert_eq!("new york", formatter("New York"));
+        assert_eq!("são paulo", formatter("\tSÃO PAULO\n"));
+    }
+}
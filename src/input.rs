@@ -77,15 +77,23 @@ impl Input {
             Key::Backspace => self.backspace(),
             Key::Char('h', m) if m.contains(KeyModifiers::CONTROL) => false,
 
+            Key::Char('w', m) if m.contains(KeyModifiers::CONTROL) => self.delete_previous_word(),
+            Key::Char('u', m) if m.contains(KeyModifiers::CONTROL) => self.delete_to_start(),
+
             Key::Delete(m) if m.contains(KeyModifiers::CONTROL) => self.delete_next_word(),
             Key::Delete(_) => self.delete(1),
+            Key::Char('k', m) if m.contains(KeyModifiers::CONTROL) => self.delete_to_end(),
 
             Key::Home => self.move_backward(MoveKind::Line),
             Key::Left(m) if m.contains(KeyModifiers::CONTROL) => self.move_backward(MoveKind::Word),
+            Key::Char('b', m) if m.contains(KeyModifiers::ALT) => {
+                self.move_backward(MoveKind::Word)
+            }
             Key::Left(_) => self.move_backward(MoveKind::Char),
 
             Key::End => self.move_forward(MoveKind::Line),
             Key::Right(m) if m.contains(KeyModifiers::CONTROL) => self.move_forward(MoveKind::Word),
+            Key::Char('f', m) if m.contains(KeyModifiers::ALT) => self.move_forward(MoveKind::Word),
             Key::Right(_) => self.move_forward(MoveKind::Char),
 
             Key::Char(c, _) => self.insert(c),
@@ -244,6 +252,27 @@ impl Input {
         self.delete(len)
     }
 
+    pub fn delete_previous_word(&mut self) -> bool {
+        let end = self.cursor;
+        let start = self.prev_word_index();
+
+        self.cursor = start;
+        self.delete(end - start)
+    }
+
+    fn delete_to_start(&mut self) -> bool {
+        let len = self.cursor;
+
+        self.cursor = 0;
+        self.delete(len)
+    }
+
+    fn delete_to_end(&mut self) -> bool {
+        let len = self.length.saturating_sub(self.cursor);
+
+        self.delete(len)
+    }
+
     fn delete(&mut self, qty: usize) -> bool {
         let start = self.cursor;
         let end = start.saturating_add(qty);
@@ -325,6 +354,48 @@ mod test {
         }
     }
 
+    #[test]
+    fn delete_previous_word() {
+        let mut input = Input::new_with("hello 🌍 wörld🍞 ok").with_cursor(13);
+
+        assert!(input.handle_key(Key::Char('w', KeyModifiers::CONTROL)));
+        assert_eq!("hello 🌍 🍞 ok", input.content());
+        assert_eq!(8, input.cursor());
+
+        assert!(input.handle_key(Key::Char('w', KeyModifiers::CONTROL)));
+        assert_eq!("🍞 ok", input.content());
+        assert_eq!(0, input.cursor());
+
+        assert!(!input.handle_key(Key::Char('w', KeyModifiers::CONTROL)));
+        assert_eq!("🍞 ok", input.content());
+    }
+
+    #[test]
+    fn delete_to_start() {
+        let mut input = Input::new_with("🧘🏻‍♂️ab🌍cd").with_cursor(3);
+
+        assert!(input.handle_key(Key::Char('u', KeyModifiers::CONTROL)));
+        assert_eq!("🌍cd", input.content());
+        assert_eq!(0, input.cursor());
+        assert_eq!(3, input.length());
+
+        assert!(!input.handle_key(Key::Char('u', KeyModifiers::CONTROL)));
+        assert_eq!("🌍cd", input.content());
+    }
+
+    #[test]
+    fn delete_to_end() {
+        let mut input = Input::new_with("ab🧘🏻‍♂️cd🌍").with_cursor(2);
+
+        assert!(input.handle_key(Key::Char('k', KeyModifiers::CONTROL)));
+        assert_eq!("ab", input.content());
+        assert_eq!(2, input.cursor());
+        assert_eq!(2, input.length());
+
+        assert!(!input.handle_key(Key::Char('k', KeyModifiers::CONTROL)));
+        assert_eq!("ab", input.content());
+    }
+
     #[test]
     // https://github.com/mikaelmello/inquire/issues/5
     fn regression_issue_5() {
@@ -4,9 +4,11 @@
 //! for information via the CLI. With `inquire`, you can use:
 //!
 //! - [`Text`] to get text input from the user, with _built-in autocompletion support_;
+//! - [`TextList`] to get a list of text inputs from the user, one item at a time;
 //! - [`Editor`]* to get longer text inputs by opening a text editor for the user;
 //! - [`DateSelect`]* to get a date input from the user, selected via an _interactive calendar_;
 //! - [`Select`] to ask the user to select one option from a given list;
+//! - [`LazySelect`] to ask the user to select one option from a very large list, retrieved on demand;
 //! - [`MultiSelect`] to ask the user to select an arbitrary number of options from a given list;
 //! - [`Confirm`] for simple yes/no confirmation prompts;
 //! - [`CustomType`] for text prompts that you would like to parse to a custom type, such as numbers or UUIDs;
@@ -55,6 +57,8 @@
 //! ```
 //!
 //! [`Text`]: crate::Text
+//! [`TextList`]: crate::TextList
+//! [`LazySelect`]: crate::LazySelect
 //! [`DateSelect`]: crate::DateSelect
 //! [`Select`]: crate::Select
 //! [`MultiSelect`]: crate::MultiSelect
@@ -76,6 +80,9 @@ mod input;
 pub mod list_option;
 pub mod parser;
 mod prompts;
+#[cfg(feature = "crossterm")]
+#[cfg_attr(docsrs, doc(cfg(feature = "crossterm")))]
+pub mod scripted;
 mod terminal;
 pub mod type_aliases;
 pub mod ui;
@@ -83,6 +90,6 @@ mod utils;
 pub mod validator;
 
 pub use crate::autocompletion::Autocomplete;
-pub use crate::config::set_global_render_config;
+pub use crate::config::{get_global_config, set_global_config, set_global_render_config, Config};
 pub use crate::error::{CustomUserError, InquireError};
 pub use crate::prompts::*;
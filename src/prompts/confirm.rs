@@ -1,5 +1,5 @@
 use crate::{
-    config::get_configuration,
+    config::{get_configuration, get_global_config},
     error::{InquireError, InquireResult},
     formatter::{BoolFormatter, DEFAULT_BOOL_FORMATTER},
     parser::{BoolParser, DEFAULT_BOOL_PARSER},
@@ -23,6 +23,7 @@ use crate::{
 ///
 /// - **Prompt message**: Required when creating the prompt.
 /// - **Default value**: Default value returned when the user submits an empty response.
+///   - Pressing tab on an empty input also accepts it.
 /// - **Placeholder**: Short hint that describes the expected value of the input.
 /// - **Help message**: Message displayed at the line below the prompt.
 /// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
@@ -66,6 +67,12 @@ pub struct Confirm<'a> {
     /// Help message to be presented to the user.
     pub help_message: Option<&'a str>,
 
+    /// Whether the default help messages, such as the hint at the key accepting
+    /// the default value, are displayed when no help message is set.
+    ///
+    /// Defaults to the `help_messages` setting of the global [`Config`](crate::Config).
+    pub default_help_messages: bool,
+
     /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
     pub formatter: BoolFormatter<'a>,
 
@@ -114,6 +121,7 @@ impl<'a> Confirm<'a> {
             default: None,
             placeholder: None,
             help_message: None,
+            default_help_messages: get_global_config().help_messages,
             formatter: Self::DEFAULT_FORMATTER,
             parser: Self::DEFAULT_PARSER,
             default_value_formatter: Self::DEFAULT_DEFAULT_VALUE_FORMATTER,
@@ -224,11 +232,189 @@ impl<'a> From<Confirm<'a>> for CustomType<'a, bool> {
             default_value_formatter: co.default_value_formatter,
             placeholder: co.placeholder,
             help_message: co.help_message,
+            default_help_messages: co.default_help_messages,
             formatter: co.formatter,
             parser: co.parser,
             validators: vec![],
             error_message: co.error_message,
+            min: None,
+            max: None,
+            step: None,
+            max_attempts: None,
             render_config: co.render_config,
         }
     }
 }
+
+#[cfg(test)]
+#[cfg(feature = "crossterm")]
+mod test {
+    use super::Confirm;
+    use crate::{
+        parser::BoolParser,
+        terminal::crossterm::CrosstermTerminal,
+        ui::{Backend, RenderConfig},
+    };
+    use crossterm::event::{KeyCode, KeyEvent};
+
+    macro_rules! text_to_events {
+        ($text:expr) => {{
+            $text.chars().map(KeyCode::Char)
+        }};
+    }
+
+    macro_rules! confirm_test {
+        ($name:ident,$input:expr,$output:expr,$prompt:expr) => {
+            confirm_test! {$name, $input, $output, $prompt, ""}
+        };
+
+        ($name:ident,$input:expr,$output:expr,$prompt:expr,$rendered:expr) => {
+            #[test]
+            fn $name() {
+                let read: Vec<KeyEvent> = $input.into_iter().map(KeyEvent::from).collect();
+                let mut read = read.into_iter();
+
+                let mut write: Vec<u8> = Vec::new();
+                {
+                    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+                    let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+                    let ans = $prompt.prompt_with_backend(&mut backend).unwrap();
+
+                    assert_eq!($output, ans);
+                }
+
+                let output = String::from_utf8(write).unwrap();
+                assert!(output.contains($rendered));
+            }
+        };
+    }
+
+    confirm_test!(
+        default_true_on_enter,
+        vec![KeyCode::Enter],
+        true,
+        Confirm::new("Question?").with_default(true),
+        "(Y/n)"
+    );
+
+    confirm_test!(
+        default_false_on_enter,
+        vec![KeyCode::Enter],
+        false,
+        Confirm::new("Question?").with_default(false),
+        "(y/N)"
+    );
+
+    confirm_test!(
+        explicit_yes,
+        text_to_events!("yes\n"),
+        true,
+        Confirm::new("Question?").with_default(false)
+    );
+
+    confirm_test!(
+        explicit_yes_uppercase,
+        text_to_events!("Y\n"),
+        true,
+        Confirm::new("Question?")
+    );
+
+    confirm_test!(
+        explicit_no,
+        text_to_events!("No\n"),
+        false,
+        Confirm::new("Question?").with_default(true)
+    );
+
+    confirm_test!(
+        invalid_then_valid,
+        {
+            let mut events = vec![];
+            events.append(&mut text_to_events!("maybe").collect());
+            events.push(KeyCode::Enter);
+            for _ in 0..5 {
+                events.push(KeyCode::Backspace);
+            }
+            events.append(&mut text_to_events!("n").collect());
+            events.push(KeyCode::Enter);
+            events
+        },
+        false,
+        Confirm::new("Question?"),
+        Confirm::DEFAULT_ERROR_MESSAGE
+    );
+
+    const PORTUGUESE_PARSER: BoolParser = &|ans| match ans.to_lowercase().as_str() {
+        "s" | "sim" => Ok(true),
+        "n" | "não" | "nao" => Ok(false),
+        _ => Err(()),
+    };
+
+    confirm_test!(
+        custom_parser_accepts_localized_yes,
+        text_to_events!("sim\n"),
+        true,
+        Confirm::new("Continuar?").with_parser(PORTUGUESE_PARSER)
+    );
+
+    confirm_test!(
+        custom_parser_rejects_default_tokens,
+        {
+            let mut events: Vec<KeyCode> = text_to_events!("yes\n").collect();
+            for _ in 0..3 {
+                events.push(KeyCode::Backspace);
+            }
+            events.extend(text_to_events!("não\n"));
+            events
+        },
+        false,
+        Confirm::new("Continuar?").with_parser(PORTUGUESE_PARSER),
+        Confirm::DEFAULT_ERROR_MESSAGE
+    );
+
+    confirm_test!(
+        custom_parser_keeps_empty_input_as_default,
+        vec![KeyCode::Enter],
+        true,
+        Confirm::new("Continuar?")
+            .with_default(true)
+            .with_parser(PORTUGUESE_PARSER)
+    );
+
+    confirm_test!(
+        tab_accepts_default,
+        vec![KeyCode::Tab],
+        false,
+        Confirm::new("Question?").with_default(false),
+        "(y/N)  \r\n[tab to accept the default, enter to submit]"
+    );
+
+    #[test]
+    fn accept_default_key_is_not_hinted_without_default_help_messages() {
+        let mut read = vec![KeyEvent::from(KeyCode::Tab)].into_iter();
+        let mut write: Vec<u8> = Vec::new();
+
+        {
+            let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+            let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+            let prompt = Confirm {
+                default_help_messages: false,
+                ..Confirm::new("Question?").with_default(false)
+            };
+
+            assert!(!prompt.prompt_with_backend(&mut backend).unwrap());
+        }
+
+        let output = String::from_utf8(write).unwrap();
+        assert!(!output.contains("tab to accept the default"));
+    }
+
+    confirm_test!(
+        tab_is_ignored_without_default,
+        vec![KeyCode::Tab, KeyCode::Char('y'), KeyCode::Enter],
+        true,
+        Confirm::new("Question?")
+    );
+}
@@ -1,16 +1,20 @@
 use std::str::FromStr;
 
 use crate::{
-    config::get_configuration,
+    config::{get_configuration, get_global_config},
     error::{InquireError, InquireResult},
     formatter::CustomTypeFormatter,
     input::Input,
     parser::CustomTypeParser,
     terminal::get_default_terminal,
-    ui::{Backend, CustomTypeBackend, Key, RenderConfig},
+    ui::{Backend, CustomTypeBackend, Key, KeyModifiers, RenderConfig},
     validator::{CustomTypeValidator, ErrorMessage, Validation},
 };
 
+/// Help message hinting at the key accepting the default value of text inputs.
+pub(crate) const DEFAULT_HELP_MESSAGE_WITH_DEFAULT: &str =
+    "tab to accept the default, enter to submit";
+
 /// Generic prompt suitable for when you need to parse the user input into a specific type, for example an `f64` or a `rust_decimal`, maybe even an `uuid`.
 ///
 /// This prompt has all of the validation, parsing and error handling features built-in to reduce as much boilerplaste as possible from your prompts. Its defaults are necessarily very simple in order to cover a large range of generic cases, for example a "Invalid input" error message.
@@ -40,7 +44,12 @@ use crate::{
 ///     validators: vec![],
 ///     placeholder: Some("123.45"),
 ///     error_message: "Please type a valid number.".into(),
+///     min: None,
+///     max: None,
+///     step: None,
+///     max_attempts: None,
 ///     help_message: "Do not use currency and the number should use dots as the decimal separator.".into(),
+///     default_help_messages: true,
 ///     parser: &|i| match i.parse::<f64>() {
 ///         Ok(val) => Ok(val),
 ///         Err(_) => Err(()),
@@ -81,6 +90,12 @@ pub struct CustomType<'a, T> {
     /// Help message to be presented to the user.
     pub help_message: Option<&'a str>,
 
+    /// Whether the default help messages, such as the hint at the key accepting
+    /// the default value, are displayed when no help message is set.
+    ///
+    /// Defaults to the `help_messages` setting of the global [`Config`](crate::Config).
+    pub default_help_messages: bool,
+
     /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
     pub formatter: CustomTypeFormatter<'a, T>,
 
@@ -101,6 +116,25 @@ pub struct CustomType<'a, T> {
     /// Error message displayed when value could not be parsed from input.
     pub error_message: String,
 
+    /// Lower bound of integer inputs, e.g. of a `CustomType<i64>` prompt.
+    ///
+    /// When any of `min`, `max` or `step` is set, the input behaves as a
+    /// stepper: the up and down arrow keys increment and decrement it, and
+    /// integers typed or submitted out of bounds are clamped.
+    pub min: Option<i64>,
+
+    /// Upper bound of integer inputs. See [`min`](Self::min).
+    pub max: Option<i64>,
+
+    /// Amount by which the up and down arrow keys increment and decrement
+    /// integer inputs, 1 by default. See [`min`](Self::min).
+    pub step: Option<i64>,
+
+    /// Maximum number of submissions rejected by the parser or the validators
+    /// before the prompt returns [`InquireError::TooManyAttempts`]. Unlimited
+    /// by default.
+    pub max_attempts: Option<usize>,
+
     /// RenderConfig to apply to the rendered interface.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -129,11 +163,16 @@ where
             default: None,
             placeholder: None,
             help_message: None,
+            default_help_messages: get_global_config().help_messages,
             formatter: &|val| val.to_string(),
             default_value_formatter: &|val| val.to_string(),
             parser: &|a| a.parse::<T>().map_err(|_| ()),
             validators: Self::DEFAULT_VALIDATORS,
             error_message: "Invalid input".into(),
+            min: None,
+            max: None,
+            step: None,
+            max_attempts: None,
             render_config: get_configuration(),
         }
     }
@@ -208,7 +247,6 @@ where
     /// The possible error is displayed to the user one line above the prompt.
     pub fn with_validators(mut self, validators: &[Box<dyn CustomTypeValidator<T>>]) -> Self {
         for validator in validators {
-            #[allow(clippy::clone_double_ref)]
             self.validators.push(validator.clone());
         }
         self
@@ -220,6 +258,14 @@ where
         self
     }
 
+    /// Sets the maximum number of submissions rejected by the parser or the
+    /// validators, after which the prompt returns [`InquireError::TooManyAttempts`]
+    /// instead of asking again.
+    pub fn with_max_attempts(mut self, max_attempts: usize) -> Self {
+        self.max_attempts = Some(max_attempts);
+        self
+    }
+
     /// Sets the provided color theme to this prompt.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -266,10 +312,36 @@ where
     }
 }
 
+impl<'a> CustomType<'a, i64> {
+    /// Sets the lower bound of the input, turning the prompt into a stepper:
+    /// the up and down arrow keys increment and decrement the value, which is
+    /// clamped to the bounds.
+    pub fn with_min(mut self, min: i64) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Sets the upper bound of the input, turning the prompt into a stepper:
+    /// the up and down arrow keys increment and decrement the value, which is
+    /// clamped to the bounds.
+    pub fn with_max(mut self, max: i64) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Sets the amount by which the up and down arrow keys increment and
+    /// decrement the value, turning the prompt into a stepper.
+    pub fn with_step(mut self, step: i64) -> Self {
+        self.step = Some(step);
+        self
+    }
+}
+
 struct CustomTypePrompt<'a, T> {
     message: &'a str,
     error: Option<ErrorMessage>,
     help_message: Option<&'a str>,
+    default_help_messages: bool,
     default: Option<T>,
     input: Input,
     formatter: CustomTypeFormatter<'a, T>,
@@ -277,6 +349,11 @@ struct CustomTypePrompt<'a, T> {
     validators: Vec<Box<dyn CustomTypeValidator<T>>>,
     parser: CustomTypeParser<'a, T>,
     error_message: String,
+    min: Option<i64>,
+    max: Option<i64>,
+    step: Option<i64>,
+    max_attempts: Option<usize>,
+    failed_attempts: usize,
 }
 
 impl<'a, T> From<CustomType<'a, T>> for CustomTypePrompt<'a, T>
@@ -289,6 +366,7 @@ where
             error: None,
             default: co.default,
             help_message: co.help_message,
+            default_help_messages: co.default_help_messages,
             formatter: co.formatter,
             default_value_formatter: co.default_value_formatter,
             validators: co.validators,
@@ -298,6 +376,11 @@ where
                 .map(|p| Input::new().with_placeholder(p))
                 .unwrap_or_else(Input::new),
             error_message: co.error_message,
+            min: co.min,
+            max: co.max,
+            step: co.step,
+            max_attempts: co.max_attempts,
+            failed_attempts: 0,
         }
     }
 }
@@ -307,13 +390,87 @@ where
     T: Clone,
 {
     fn on_change(&mut self, key: Key) {
-        self.input.handle_key(key);
+        if !self.is_stepper() {
+            self.input.handle_key(key);
+            return;
+        }
+
+        match key {
+            Key::Up(KeyModifiers::NONE) => self.step_value(true),
+            Key::Down(KeyModifiers::NONE) => self.step_value(false),
+            key => {
+                if self.input.handle_key(key) {
+                    self.clamp_typed_value();
+                }
+            }
+        }
+    }
+
+    fn is_stepper(&self) -> bool {
+        self.min.is_some() || self.max.is_some() || self.step.is_some()
+    }
+
+    fn clamp(&self, value: i64) -> i64 {
+        let value = self.max.map_or(value, |max| value.min(max));
+        self.min.map_or(value, |min| value.max(min))
+    }
+
+    /// Increments or decrements the integer input, starting from the default
+    /// value, or zero, when the input is empty.
+    fn step_value(&mut self, forward: bool) {
+        let current = match self.input.content() {
+            "" => self
+                .default
+                .clone()
+                .and_then(|val| (self.default_value_formatter)(val).parse::<i64>().ok())
+                .unwrap_or(0),
+            content => match content.parse::<i64>() {
+                Ok(val) => val,
+                Err(_) => return,
+            },
+        };
+
+        let step = self.step.unwrap_or(1);
+        let value = match forward {
+            true => current.saturating_add(step),
+            false => current.saturating_sub(step),
+        };
+
+        self.set_input_value(self.clamp(value));
+    }
+
+    /// Clamps a typed integer once typing further digits could not bring it
+    /// back within bounds, e.g. `150` with a maximum of `100`.
+    fn clamp_typed_value(&mut self) {
+        let value = match self.input.content().parse::<i64>() {
+            Ok(val) => val,
+            Err(_) => return,
+        };
+
+        let out_of_reach = match (self.min, self.max) {
+            (_, Some(max)) if value > max && value >= 0 => true,
+            (Some(min), _) if value < min && value <= 0 => true,
+            _ => false,
+        };
+
+        if out_of_reach {
+            self.set_input_value(self.clamp(value));
+        }
+    }
+
+    fn set_input_value(&mut self, value: i64) {
+        let input = Input::new_with(value.to_string());
+
+        self.input = match self.input.placeholder() {
+            Some(placeholder) => input.with_placeholder(placeholder),
+            None => input,
+        };
     }
 
     fn validate_current_answer(&self, value: &T) -> InquireResult<Validation> {
         for validator in &self.validators {
             match validator.validate(value) {
-                Ok(Validation::Valid) => {}
+                Ok(Validation::Valid) | Ok(Validation::Warning(_)) => {}
                 Ok(Validation::Invalid(msg)) => return Ok(Validation::Invalid(msg)),
                 Err(err) => return Err(InquireError::Custom(err)),
             }
@@ -322,12 +479,24 @@ where
         Ok(Validation::Valid)
     }
 
-    fn get_final_answer(&self) -> Result<T, String> {
+    /// Whether the tab key accepts the default value, which is the case when
+    /// the input is empty.
+    fn can_accept_default(&self) -> bool {
+        self.default.is_some() && self.input.is_empty()
+    }
+
+    fn get_final_answer(&mut self) -> Result<T, String> {
         match &self.default {
             Some(val) if self.input.content().is_empty() => return Ok(val.clone()),
             _ => {}
         }
 
+        if self.is_stepper() {
+            if let Ok(value) = self.input.content().parse::<i64>() {
+                self.set_input_value(self.clamp(value));
+            }
+        }
+
         match (self.parser)(self.input.content()) {
             Ok(val) => Ok(val),
             Err(_) => Err(self.error_message.clone()),
@@ -353,6 +522,8 @@ where
 
         if let Some(message) = self.help_message {
             backend.render_help_message(message)?;
+        } else if self.default_help_messages && self.can_accept_default() {
+            backend.render_help_message(DEFAULT_HELP_MESSAGE_WITH_DEFAULT)?;
         }
 
         backend.frame_finish()?;
@@ -360,6 +531,20 @@ where
         Ok(())
     }
 
+    /// Displays the error of a rejected submission, failing once the maximum
+    /// number of attempts is reached.
+    fn reject_answer(&mut self, error: ErrorMessage) -> InquireResult<()> {
+        self.failed_attempts += 1;
+        if let Some(max_attempts) = self.max_attempts {
+            if self.failed_attempts >= max_attempts {
+                return Err(InquireError::TooManyAttempts);
+            }
+        }
+
+        self.error = Some(error);
+        Ok(())
+    }
+
     fn prompt<B: CustomTypeBackend>(mut self, backend: &mut B) -> InquireResult<T> {
         let final_answer: T;
 
@@ -371,16 +556,18 @@ where
             match key {
                 Key::Interrupt => interrupt_prompt!(),
                 Key::Cancel => cancel_prompt!(backend, self.message),
-                Key::Submit => match self.get_final_answer() {
-                    Ok(answer) => match self.validate_current_answer(&answer)? {
-                        Validation::Valid => {
-                            final_answer = answer;
-                            break;
-                        }
-                        Validation::Invalid(msg) => self.error = Some(msg),
-                    },
-                    Err(message) => self.error = Some(message.into()),
-                },
+                key if key == Key::Submit || (key == Key::Tab && self.can_accept_default()) => {
+                    match self.get_final_answer() {
+                        Ok(answer) => match self.validate_current_answer(&answer)? {
+                            Validation::Valid | Validation::Warning(_) => {
+                                final_answer = answer;
+                                break;
+                            }
+                            Validation::Invalid(msg) => self.reject_answer(msg)?,
+                        },
+                        Err(message) => self.reject_answer(message.into())?,
+                    }
+                }
                 key => self.on_change(key),
             }
         }
@@ -390,3 +577,207 @@ where
         finish_prompt_with_answer!(backend, self.message, &formatted, final_answer);
     }
 }
+
+#[cfg(test)]
+#[cfg(feature = "crossterm")]
+mod test {
+    use super::CustomType;
+    use crate::{
+        error::InquireError,
+        terminal::crossterm::CrosstermTerminal,
+        ui::{Backend, RenderConfig},
+        validator::Validation,
+    };
+    use crossterm::event::{KeyCode, KeyEvent};
+
+    macro_rules! text_to_events {
+        ($text:expr) => {{
+            $text.chars().map(KeyCode::Char)
+        }};
+    }
+
+    macro_rules! custom_type_test {
+        ($name:ident,$input:expr,$output:expr,$prompt:expr) => {
+            custom_type_test! {$name, $input, $output, $prompt, ""}
+        };
+
+        ($name:ident,$input:expr,$output:expr,$prompt:expr,$rendered:expr) => {
+            #[test]
+            fn $name() {
+                let read: Vec<KeyEvent> = $input.into_iter().map(KeyEvent::from).collect();
+                let mut read = read.into_iter();
+
+                let mut write: Vec<u8> = Vec::new();
+                {
+                    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+                    let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+                    let ans = $prompt.prompt_with_backend(&mut backend).unwrap();
+
+                    assert_eq!($output, ans);
+                }
+
+                let output = String::from_utf8(write).unwrap();
+                assert!(output.contains($rendered));
+            }
+        };
+    }
+
+    custom_type_test!(
+        valid_u32,
+        text_to_events!("42\n"),
+        42u32,
+        CustomType::<u32>::new("Question?")
+    );
+
+    custom_type_test!(
+        invalid_then_valid_u32,
+        {
+            let mut events = vec![];
+            events.append(&mut text_to_events!("-1\n").collect());
+            events.push(KeyCode::Backspace);
+            events.push(KeyCode::Backspace);
+            events.append(&mut text_to_events!("1\n").collect());
+            events
+        },
+        1u32,
+        CustomType::<u32>::new("Question?").with_error_message("Please type a positive integer"),
+        "Please type a positive integer"
+    );
+
+    #[test]
+    fn max_attempts_fails_after_rejected_submissions() {
+        // the second submission of "-1" fails before "5" is typed
+        let mut read = text_to_events!("-1\n\n5\n").map(KeyEvent::from);
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+        let ans = CustomType::<u32>::new("Question?")
+            .with_max_attempts(2)
+            .prompt_with_backend(&mut backend);
+
+        assert!(matches!(ans, Err(InquireError::TooManyAttempts)));
+    }
+
+    custom_type_test!(
+        valid_f64,
+        text_to_events!("2.75\n"),
+        2.75f64,
+        CustomType::<f64>::new("Question?")
+    );
+
+    custom_type_test!(
+        invalid_then_valid_f64,
+        {
+            let mut events = vec![];
+            events.append(&mut text_to_events!("1,5\n").collect());
+            events.push(KeyCode::Backspace);
+            events.push(KeyCode::Backspace);
+            events.append(&mut text_to_events!(".5\n").collect());
+            events
+        },
+        1.5f64,
+        CustomType::<f64>::new("Question?"),
+        "Invalid input"
+    );
+
+    custom_type_test!(
+        default_on_empty_input,
+        vec![KeyCode::Enter],
+        7u32,
+        CustomType::<u32>::new("Question?").with_default(7)
+    );
+
+    custom_type_test!(
+        validator_runs_on_parsed_value,
+        {
+            let mut events = vec![];
+            events.append(&mut text_to_events!("10\n").collect());
+            events.push(KeyCode::Backspace);
+            events.append(&mut text_to_events!("\n").collect());
+            events
+        },
+        1u32,
+        CustomType::<u32>::new("Question?").with_validator(|val: &u32| match *val < 5 {
+            true => Ok(Validation::Valid),
+            false => Ok(Validation::Invalid("Too big".into())),
+        }),
+        "Too big"
+    );
+
+    custom_type_test!(
+        stepper_up_is_clamped_to_max,
+        vec![
+            KeyCode::Up,
+            KeyCode::Up,
+            KeyCode::Up,
+            KeyCode::Up,
+            KeyCode::Up,
+            KeyCode::Enter,
+        ],
+        3i64,
+        CustomType::<i64>::new("Question?").with_min(0).with_max(3),
+        "? Question? 3"
+    );
+
+    custom_type_test!(
+        stepper_down_is_clamped_to_min,
+        vec![KeyCode::Down, KeyCode::Down, KeyCode::Down, KeyCode::Enter],
+        -2i64,
+        CustomType::<i64>::new("Question?")
+            .with_min(-2)
+            .with_max(2)
+            .with_default(1)
+    );
+
+    custom_type_test!(
+        stepper_moves_by_step,
+        vec![
+            KeyCode::Up,
+            KeyCode::Up,
+            KeyCode::Up,
+            KeyCode::Down,
+            KeyCode::Enter
+        ],
+        7i64,
+        CustomType::<i64>::new("Question?")
+            .with_max(12)
+            .with_step(5)
+    );
+
+    custom_type_test!(
+        stepper_accepts_typed_digits,
+        {
+            let mut events = vec![];
+            events.append(&mut text_to_events!("41").collect());
+            events.push(KeyCode::Up);
+            events.push(KeyCode::Enter);
+            events
+        },
+        42i64,
+        CustomType::<i64>::new("Question?")
+            .with_min(0)
+            .with_max(100)
+    );
+
+    custom_type_test!(
+        stepper_clamps_typed_value_above_max,
+        text_to_events!("150\n"),
+        100i64,
+        CustomType::<i64>::new("Question?")
+            .with_min(10)
+            .with_max(100),
+        "? Question? 100"
+    );
+
+    custom_type_test!(
+        stepper_clamps_submitted_value_below_min,
+        text_to_events!("5\n"),
+        10i64,
+        CustomType::<i64>::new("Question?")
+            .with_min(10)
+            .with_max(100)
+    );
+}
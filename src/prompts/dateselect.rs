@@ -5,7 +5,7 @@ use std::{
 };
 
 use crate::{
-    config::get_configuration,
+    config::{self, get_configuration, get_global_config},
     date_utils::{get_current_date, get_month},
     error::{InquireError, InquireResult},
     formatter::{self, DateFormatter},
@@ -106,8 +106,8 @@ impl<'a> DateSelect<'a> {
     /// Default formatter, set to [DEFAULT_DATE_FORMATTER](crate::formatter::DEFAULT_DATE_FORMATTER)
     pub const DEFAULT_FORMATTER: DateFormatter<'a> = formatter::DEFAULT_DATE_FORMATTER;
 
-    /// Default value of vim mode. It is true because there is no typing functionality to be lost here.
-    pub const DEFAULT_VIM_MODE: bool = true;
+    /// Default value of vim mode, equal to the global default [config::DEFAULT_VIM_MODE].
+    pub const DEFAULT_VIM_MODE: bool = config::DEFAULT_VIM_MODE;
 
     /// Default help message.
     pub const DEFAULT_HELP_MESSAGE: Option<&'a str> =
@@ -127,13 +127,15 @@ impl<'a> DateSelect<'a> {
 
     /// Creates a [DateSelect] with the provided message, along with default configuration values.
     pub fn new(message: &'a str) -> Self {
+        let config = get_global_config();
+
         Self {
             message,
             starting_date: get_current_date(),
             min_date: Self::DEFAULT_MIN_DATE,
             max_date: Self::DEFAULT_MAX_DATE,
-            help_message: Self::DEFAULT_HELP_MESSAGE,
-            vim_mode: Self::DEFAULT_VIM_MODE,
+            help_message: Self::DEFAULT_HELP_MESSAGE.filter(|_| config.help_messages),
+            vim_mode: config.vim_mode,
             formatter: Self::DEFAULT_FORMATTER,
             validators: Self::DEFAULT_VALIDATORS,
             week_start: Self::DEFAULT_WEEK_START,
@@ -375,8 +377,8 @@ impl<'a> DateSelectPrompt<'a> {
 
             Key::Up(KeyModifiers::CONTROL) => self.shift_months(-12),
             Key::Down(KeyModifiers::CONTROL) => self.shift_months(12),
-            Key::Left(KeyModifiers::CONTROL) => self.shift_months(-1),
-            Key::Right(KeyModifiers::CONTROL) => self.shift_months(1),
+            Key::Left(KeyModifiers::CONTROL) | Key::PageUp => self.shift_months(-1),
+            Key::Right(KeyModifiers::CONTROL) | Key::PageDown => self.shift_months(1),
             _ => {}
         }
     }
@@ -384,7 +386,7 @@ impl<'a> DateSelectPrompt<'a> {
     fn validate_current_answer(&self) -> InquireResult<Validation> {
         for validator in &self.validators {
             match validator.validate(self.current_date) {
-                Ok(Validation::Valid) => {}
+                Ok(Validation::Valid) | Ok(Validation::Warning(_)) => {}
                 Ok(Validation::Invalid(msg)) => return Ok(Validation::Invalid(msg)),
                 Err(err) => return Err(InquireError::Custom(err)),
             }
@@ -439,7 +441,7 @@ impl<'a> DateSelectPrompt<'a> {
                 Key::Interrupt => interrupt_prompt!(),
                 Key::Cancel => cancel_prompt!(backend, self.message),
                 Key::Submit | Key::Char(' ', _) => match self.validate_current_answer()? {
-                    Validation::Valid => {
+                    Validation::Valid | Validation::Warning(_) => {
                         final_answer = self.cur_answer();
                         break;
                     }
@@ -483,7 +485,7 @@ mod test {
             #[test]
             fn $name() {
                 let read: Vec<KeyEvent> = $input.into_iter().map(KeyEvent::from).collect();
-                let mut read = read.iter();
+                let mut read = read.into_iter();
 
                 let mut write: Vec<u8> = Vec::new();
                 let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
@@ -505,6 +507,49 @@ mod test {
         DateSelect::new("Date").with_default(NaiveDate::from_ymd(2021, 1, 9))
     );
 
+    date_test!(
+        move_a_few_days,
+        vec![
+            KeyCode::Right,
+            KeyCode::Right,
+            KeyCode::Down,
+            KeyCode::Left,
+            KeyCode::Enter
+        ],
+        NaiveDate::from_ymd(2021, 1, 17),
+        DateSelect::new("Date").with_default(NaiveDate::from_ymd(2021, 1, 9))
+    );
+
+    date_test!(
+        page_up_and_page_down_change_months,
+        vec![
+            KeyCode::PageDown,
+            KeyCode::PageDown,
+            KeyCode::PageUp,
+            KeyCode::Enter
+        ],
+        NaiveDate::from_ymd(2021, 2, 9),
+        DateSelect::new("Date").with_default(NaiveDate::from_ymd(2021, 1, 9))
+    );
+
+    date_test!(
+        navigation_is_bounded_by_min_date,
+        vec![KeyCode::Up, KeyCode::PageUp, KeyCode::Enter],
+        NaiveDate::from_ymd(2021, 1, 5),
+        DateSelect::new("Date")
+            .with_default(NaiveDate::from_ymd(2021, 1, 9))
+            .with_min_date(NaiveDate::from_ymd(2021, 1, 5))
+    );
+
+    date_test!(
+        navigation_is_bounded_by_max_date,
+        vec![KeyCode::Down, KeyCode::PageDown, KeyCode::Enter],
+        NaiveDate::from_ymd(2021, 1, 12),
+        DateSelect::new("Date")
+            .with_default(NaiveDate::from_ymd(2021, 1, 9))
+            .with_max_date(NaiveDate::from_ymd(2021, 1, 12))
+    );
+
     #[test]
     /// Tests that a closure that actually closes on a variable can be used
     /// as a DateSelect validator.
@@ -513,7 +558,7 @@ mod test {
             .into_iter()
             .map(KeyEvent::from)
             .collect();
-        let mut read = read.iter();
+        let mut read = read.into_iter();
 
         let today_date = get_current_date();
 
@@ -322,7 +322,7 @@ impl<'a> EditorPrompt<'a> {
         let cur_answer = self.cur_answer()?;
         for validator in &self.validators {
             match validator.validate(&cur_answer) {
-                Ok(Validation::Valid) => {}
+                Ok(Validation::Valid) | Ok(Validation::Warning(_)) => {}
                 Ok(Validation::Invalid(msg)) => return Ok(Validation::Invalid(msg)),
                 Err(err) => return Err(InquireError::Custom(err)),
             }
@@ -353,7 +353,7 @@ impl<'a> EditorPrompt<'a> {
                 Key::Cancel => cancel_prompt!(backend, self.message),
                 Key::Char('e', _) => self.run_editor()?,
                 Key::Submit => match self.validate_current_answer()? {
-                    Validation::Valid => break self.cur_answer()?,
+                    Validation::Valid | Validation::Warning(_) => break self.cur_answer()?,
                     Validation::Invalid(msg) => self.error = Some(msg),
                 },
                 _ => {}
@@ -0,0 +1,521 @@
+use std::{collections::BTreeSet, fmt::Display};
+
+use crate::{
+    config::{self, get_configuration, get_global_config},
+    error::{InquireError, InquireResult},
+    formatter::OptionFormatter,
+    input::Input,
+    list_option::ListOption,
+    terminal::get_default_terminal,
+    type_aliases::OptionProvider,
+    ui::{Backend, Key, KeyModifiers, RenderConfig, SelectBackend},
+    utils::{page_window, Page},
+};
+
+/// Prompt suitable for when you need the user to select one option among a
+/// very large amount of them, retrieved on demand.
+///
+/// Instead of a `Vec` of options, this prompt receives the total amount of
+/// options and a provider function, which is called with the range of indexes
+/// of the options to be displayed. Only the page currently visible to the user
+/// is retrieved on each render, along with the selected option on submission,
+/// so that the options never need to be materialized altogether.
+///
+/// As a consequence, the options can not be filtered by the user, who
+/// navigates through them with the arrow, page and home/end keys.
+///
+/// Like all others, this prompt also allows you to customize several aspects of it:
+///
+/// - **Prompt message**: Required when creating the prompt.
+/// - **Options length**: Total amount of options. Must be **non-zero**.
+/// - **Option provider**: Function returning the options in a given range of indexes.
+/// - **Starting cursor**: Index of the cursor when the prompt is first rendered. Default is 0 (first option). If the index is out-of-range of the options, the prompt will fail with an [`InquireError::InvalidConfiguration`] error.
+/// - **Help message**: Message displayed at the line below the prompt.
+/// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
+///   - Prints the selected option string value by default.
+/// - **Page size**: Number of options displayed at once, 7 by default.
+/// - **Page indicator**: Whether to display the range of options currently shown, e.g. `showing 8-14 of 20`. Disabled by default.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::LazySelect;
+///
+/// let provider = |range: std::ops::Range<usize>| range.map(|i| format!("Order #{}", i)).collect();
+///
+/// let ans = LazySelect::new("Which order do you want to inspect?", 1_000_000, &provider).prompt();
+///
+/// match ans {
+///     Ok(order) => println!("Inspecting {}", order),
+///     Err(_) => println!("There was an error, please try again"),
+/// }
+/// ```
+///
+/// [`InquireError::InvalidConfiguration`]: crate::error::InquireError::InvalidConfiguration
+#[derive(Clone)]
+pub struct LazySelect<'a, T> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// Total amount of options.
+    pub len: usize,
+
+    /// Function returning the options in a given range of indexes, which must
+    /// return exactly as many options as there are indexes in the range.
+    pub provider: OptionProvider<'a, T>,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Page size of the options displayed to the user.
+    pub page_size: usize,
+
+    /// Whether to display the range of options currently shown to the user,
+    /// when there are more options than fit in a single page.
+    pub page_indicator: bool,
+
+    /// Whether vim mode is enabled. When enabled, the user can
+    /// navigate through the options using j and k, and jump to the first
+    /// and last options using g and G.
+    pub vim_mode: bool,
+
+    /// Starting cursor index of the selection.
+    pub starting_cursor: usize,
+
+    /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
+    pub formatter: OptionFormatter<'a, T>,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still suport NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig,
+}
+
+impl<'a, T> LazySelect<'a, T>
+where
+    T: Display,
+{
+    /// String formatter used by default in [LazySelect](crate::LazySelect) prompts.
+    /// Simply prints the string value contained in the selected option.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inquire::list_option::ListOption;
+    /// use inquire::LazySelect;
+    ///
+    /// let formatter = LazySelect::<&str>::DEFAULT_FORMATTER;
+    /// assert_eq!(String::from("First option"), formatter(ListOption::new(0, &"First option")));
+    /// assert_eq!(String::from("First option"), formatter(ListOption::new(11, &"First option")));
+    /// ```
+    pub const DEFAULT_FORMATTER: OptionFormatter<'a, T> = &|ans| ans.to_string();
+
+    /// Default page size.
+    pub const DEFAULT_PAGE_SIZE: usize = config::DEFAULT_PAGE_SIZE;
+
+    /// Default value of the page indicator.
+    pub const DEFAULT_PAGE_INDICATOR: bool = false;
+
+    /// Default value of vim mode.
+    pub const DEFAULT_VIM_MODE: bool = config::DEFAULT_VIM_MODE;
+
+    /// Default starting cursor index.
+    pub const DEFAULT_STARTING_CURSOR: usize = 0;
+
+    /// Default help message.
+    pub const DEFAULT_HELP_MESSAGE: Option<&'a str> = Some("↑↓ to move, enter to select");
+
+    /// Creates a [LazySelect] with the provided message, amount of options and
+    /// option provider, along with default configuration values.
+    pub fn new(message: &'a str, len: usize, provider: OptionProvider<'a, T>) -> Self {
+        let config = get_global_config();
+
+        Self {
+            message,
+            len,
+            provider,
+            help_message: Self::DEFAULT_HELP_MESSAGE.filter(|_| config.help_messages),
+            page_size: config.page_size,
+            page_indicator: Self::DEFAULT_PAGE_INDICATOR,
+            vim_mode: config.vim_mode,
+            starting_cursor: Self::DEFAULT_STARTING_CURSOR,
+            formatter: Self::DEFAULT_FORMATTER,
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Removes the set help message.
+    pub fn without_help_message(mut self) -> Self {
+        self.help_message = None;
+        self
+    }
+
+    /// Sets the page size.
+    pub fn with_page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
+    /// Enables or disables the page indicator, e.g. `showing 8-14 of 20`,
+    /// displayed when there are more options than fit in a single page.
+    pub fn with_page_indicator(mut self, page_indicator: bool) -> Self {
+        self.page_indicator = page_indicator;
+        self
+    }
+
+    /// Enables or disables vim_mode.
+    pub fn with_vim_mode(mut self, vim_mode: bool) -> Self {
+        self.vim_mode = vim_mode;
+        self
+    }
+
+    /// Sets the formatter.
+    pub fn with_formatter(mut self, formatter: OptionFormatter<'a, T>) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    /// Sets the starting cursor index.
+    pub fn with_starting_cursor(mut self, starting_cursor: usize) -> Self {
+        self.starting_cursor = starting_cursor;
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still suport NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns the owned object selected by the user.
+    pub fn prompt(self) -> InquireResult<T> {
+        self.raw_prompt().map(|op| op.value)
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<T>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns a [`ListOption`](crate::list_option::ListOption) containing
+    /// the index of the selection and the owned object selected by the user.
+    pub fn raw_prompt(self) -> InquireResult<ListOption<T>> {
+        let terminal = get_default_terminal()?;
+        let mut backend = Backend::new(terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    pub(crate) fn prompt_with_backend<B: SelectBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<ListOption<T>> {
+        LazySelectPrompt::new(self)?.prompt(backend)
+    }
+}
+
+struct LazySelectPrompt<'a, T> {
+    message: &'a str,
+    len: usize,
+    provider: OptionProvider<'a, T>,
+    help_message: Option<&'a str>,
+    vim_mode: bool,
+    cursor_index: usize,
+    page_size: usize,
+    page_indicator: bool,
+    input: Input,
+    formatter: OptionFormatter<'a, T>,
+}
+
+impl<'a, T> LazySelectPrompt<'a, T>
+where
+    T: Display,
+{
+    fn new(so: LazySelect<'a, T>) -> InquireResult<Self> {
+        if so.len == 0 {
+            return Err(InquireError::InvalidConfiguration(
+                "Available options can not be empty".into(),
+            ));
+        }
+
+        if so.starting_cursor >= so.len {
+            return Err(InquireError::InvalidConfiguration(format!(
+                "Starting cursor index {} is out-of-bounds for length {} of options",
+                so.starting_cursor, so.len
+            )));
+        }
+
+        Ok(Self {
+            message: so.message,
+            len: so.len,
+            provider: so.provider,
+            help_message: so.help_message,
+            vim_mode: so.vim_mode,
+            cursor_index: so.starting_cursor,
+            page_size: so.page_size,
+            page_indicator: so.page_indicator,
+            input: Input::new(),
+            formatter: so.formatter,
+        })
+    }
+
+    fn move_cursor_up(&mut self, qty: usize, wrap: bool) {
+        if wrap {
+            let after_wrap = qty.saturating_sub(self.cursor_index);
+            self.cursor_index = self
+                .cursor_index
+                .checked_sub(qty)
+                .unwrap_or_else(|| self.len.saturating_sub(after_wrap))
+        } else {
+            self.cursor_index = self.cursor_index.saturating_sub(qty);
+        }
+    }
+
+    fn move_cursor_down(&mut self, qty: usize, wrap: bool) {
+        self.cursor_index = self.cursor_index.saturating_add(qty);
+
+        if self.cursor_index >= self.len {
+            self.cursor_index = match wrap {
+                true => self.cursor_index % self.len,
+                false => self.len.saturating_sub(1),
+            }
+        }
+    }
+
+    fn on_change(&mut self, key: Key) {
+        match key {
+            Key::Up(KeyModifiers::NONE) => self.move_cursor_up(1, true),
+            Key::Char('k', KeyModifiers::NONE) if self.vim_mode => self.move_cursor_up(1, true),
+            Key::PageUp => self.move_cursor_up(self.page_size, false),
+            Key::Home => self.move_cursor_up(usize::MAX, false),
+            Key::Char('g', KeyModifiers::NONE) if self.vim_mode => {
+                self.move_cursor_up(usize::MAX, false)
+            }
+
+            Key::Down(KeyModifiers::NONE) => self.move_cursor_down(1, true),
+            Key::Char('j', KeyModifiers::NONE) if self.vim_mode => self.move_cursor_down(1, true),
+            Key::PageDown => self.move_cursor_down(self.page_size, false),
+            Key::End => self.move_cursor_down(usize::MAX, false),
+            Key::Char('G', m)
+                if self.vim_mode && !m.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) =>
+            {
+                self.move_cursor_down(usize::MAX, false)
+            }
+
+            _ => {}
+        }
+    }
+
+    fn get_final_answer(&self) -> InquireResult<ListOption<T>> {
+        let index = self.cursor_index;
+
+        match (self.provider)(index..index + 1).into_iter().next() {
+            Some(value) => Ok(ListOption::new(index, value)),
+            None => Err(InquireError::InvalidConfiguration(format!(
+                "Option provider returned no option for index {}",
+                index
+            ))),
+        }
+    }
+
+    fn render<B: SelectBackend>(&mut self, backend: &mut B) -> InquireResult<()> {
+        let prompt = &self.message;
+
+        backend.frame_setup()?;
+
+        backend.render_select_prompt(prompt, &self.input)?;
+
+        let (start, end, selection) = page_window(self.page_size, self.len, self.cursor_index);
+
+        let choices = (self.provider)(start..end)
+            .iter()
+            .enumerate()
+            .map(|(pos, value)| ListOption::new(start + pos, value.to_string()))
+            .collect::<Vec<ListOption<String>>>();
+
+        let page = Page {
+            first: start == 0,
+            last: end == self.len,
+            content: &choices,
+            start,
+            selection,
+            total: self.len,
+        };
+
+        let page_indicator = self.page_indicator && self.len > self.page_size;
+        backend.render_options(
+            page,
+            &BTreeSet::new(),
+            &BTreeSet::new(),
+            false,
+            page_indicator,
+        )?;
+
+        if let Some(help_message) = self.help_message {
+            backend.render_help_message(help_message)?;
+        }
+
+        backend.frame_finish()?;
+
+        Ok(())
+    }
+
+    fn prompt<B: SelectBackend>(mut self, backend: &mut B) -> InquireResult<ListOption<T>> {
+        loop {
+            self.render(backend)?;
+
+            let key = backend.read_key()?;
+
+            match key {
+                Key::Interrupt => interrupt_prompt!(),
+                Key::Cancel => cancel_prompt!(backend, self.message),
+                Key::Submit => break,
+                key => self.on_change(key),
+            }
+        }
+
+        let final_answer = self.get_final_answer()?;
+        let formatted = (self.formatter)(final_answer.as_ref());
+
+        finish_prompt_with_answer!(backend, self.message, &formatted, final_answer);
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "crossterm")]
+mod test {
+    use std::{cell::RefCell, ops::Range};
+
+    use super::{LazySelect, LazySelectPrompt};
+    use crate::{
+        error::InquireError,
+        list_option::ListOption,
+        terminal::crossterm::CrosstermTerminal,
+        ui::{Backend, RenderConfig},
+    };
+    use crossterm::event::{KeyCode, KeyEvent};
+
+    macro_rules! lazy_select_test {
+        ($name:ident,$input:expr,$output:expr,$prompt:expr) => {
+            #[test]
+            fn $name() {
+                let read: Vec<KeyEvent> = $input.into_iter().map(KeyEvent::from).collect();
+                let mut read = read.into_iter();
+
+                let mut write: Vec<u8> = Vec::new();
+                let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+                let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+                let ans = $prompt.prompt_with_backend(&mut backend).unwrap();
+
+                assert_eq!($output, ans);
+            }
+        };
+    }
+
+    fn numbers(range: Range<usize>) -> Vec<String> {
+        range.map(|i| i.to_string()).collect()
+    }
+
+    #[test]
+    fn only_the_visible_window_is_requested() {
+        let read: Vec<KeyEvent> = vec![
+            KeyCode::Down,
+            KeyCode::Down,
+            KeyCode::Down,
+            KeyCode::End,
+            KeyCode::Up,
+            KeyCode::Enter,
+        ]
+        .into_iter()
+        .map(KeyEvent::from)
+        .collect();
+        let mut read = read.into_iter();
+
+        let requests: RefCell<Vec<Range<usize>>> = RefCell::new(vec![]);
+        let provider = |range: Range<usize>| {
+            requests.borrow_mut().push(range.clone());
+            range.map(|i| format!("Item {}", i)).collect()
+        };
+
+        let mut write: Vec<u8> = Vec::new();
+        {
+            let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+            let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+            let ans = LazySelect::new("Item?", 1_000_000, &provider)
+                .with_page_size(5)
+                .prompt_with_backend(&mut backend)
+                .unwrap();
+
+            assert_eq!(ListOption::new(999_998, String::from("Item 999998")), ans);
+        }
+
+        let output = String::from_utf8(write).unwrap();
+        assert!(output.contains("> Item 999998\r\n  Item 999999\r\n"));
+
+        let requests = requests.into_inner();
+        assert_eq!(
+            vec![
+                0..5,
+                0..5,
+                0..5,
+                1..6,
+                999_995..1_000_000,
+                999_995..1_000_000
+            ],
+            requests[..6]
+        );
+        // the submitted answer is requested on its own
+        assert_eq!(vec![999_998..999_999], requests[6..]);
+    }
+
+    lazy_select_test!(
+        navigation_wraps_around_the_options,
+        vec![KeyCode::Up, KeyCode::Enter],
+        ListOption::new(999_999, String::from("999999")),
+        LazySelect::new("Item?", 1_000_000, &numbers)
+    );
+
+    #[test]
+    fn empty_options_are_an_invalid_configuration() {
+        let prompt = LazySelectPrompt::new(LazySelect::new("Item?", 0, &numbers));
+
+        assert!(matches!(prompt, Err(InquireError::InvalidConfiguration(_))));
+    }
+}
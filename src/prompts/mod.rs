@@ -7,10 +7,12 @@ mod custom_type;
 mod dateselect;
 #[cfg(feature = "editor")]
 mod editor;
+mod lazy_select;
 mod multiselect;
 mod password;
 mod select;
 mod text;
+mod text_list;
 
 pub use confirm::Confirm;
 pub use custom_type::CustomType;
@@ -18,7 +20,9 @@ pub use custom_type::CustomType;
 pub use dateselect::DateSelect;
 #[cfg(feature = "editor")]
 pub use editor::Editor;
+pub use lazy_select::LazySelect;
 pub use multiselect::MultiSelect;
-pub use password::{Password, PasswordDisplayMode};
-pub use select::Select;
-pub use text::Text;
+pub use password::{Password, PasswordDisplayMode, PasswordStrength};
+pub use select::{FilterMode, Select};
+pub use text::{Text, TrimMode};
+pub use text_list::TextList;
@@ -1,7 +1,7 @@
 use std::{collections::BTreeSet, fmt::Display};
 
 use crate::{
-    config::{self, get_configuration},
+    config::{self, get_configuration, get_global_config},
     error::{InquireError, InquireResult},
     formatter::MultiOptionFormatter,
     input::Input,
@@ -9,7 +9,7 @@ use crate::{
     terminal::get_default_terminal,
     type_aliases::Filter,
     ui::{Backend, Key, KeyModifiers, MultiSelectBackend, RenderConfig},
-    utils::paginate,
+    utils::{closest_selectable, paginate},
     validator::{ErrorMessage, MultiOptionValidator, Validation},
 };
 
@@ -26,16 +26,21 @@ use crate::{
 ///
 /// - **Prompt message**: Required when creating the prompt.
 /// - **Options list**: Options displayed to the user. Must be **non-empty**.
-/// - **Default selections**: Options that are selected by default when the prompt is first rendered. The user can unselect them. If any of the indices is out-of-range of the option list, the prompt will fail with an [`InquireError::InvalidConfiguration`] error.
+/// - **Default selections**: Options that are selected by default when the prompt is first rendered. The user can unselect them. Indices out-of-range of the option list are ignored.
 /// - **Starting cursor**: Index of the cursor when the prompt is first rendered. Default is 0 (first option). If the index is out-of-range of the option list, the prompt will fail with an [`InquireError::InvalidConfiguration`] error.
 /// - **Help message**: Message displayed at the line below the prompt.
 /// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
 ///   - Prints the selected options string value, joined using a comma as the separator, by default.
 /// - **Validator**: Custom validator to make sure a given submitted input pass the specified requirements, e.g. not allowing 0 selected options or limiting the number of options that the user is allowed to select.
 ///   - No validators are on by default.
+/// - **Selection bounds**: Minimum and maximum amount of options the user must select. When set, the current selection count is displayed below the options, e.g. `selected 2/min 1`, and the prompt can only be submitted when the count is within the bounds.
+///   - No bounds are set by default. If the minimum is greater than the maximum, the prompt will fail with an [`InquireError::InvalidConfiguration`] error.
 /// - **Page size**: Number of options displayed at once, 7 by default.
+/// - **Page indicator**: Whether to display the range of options currently shown, e.g. `showing 8-14 of 20`, on lists longer than the page size. Disabled by default.
 /// - **Display option indexes**: On long lists, it might be helpful to display the indexes of the options to the user. Via the `RenderConfig`, you can set the display mode of the indexes as a prefix of an option. The default configuration is `None`, to not render any index when displaying the options.
+/// - **Disabled options**: Indexes of options displayed dimmed, which are skipped when navigating and whose selection can not be toggled.
 /// - **Filter function**: Function that defines if an option is displayed or not based on the current filter input.
+/// - **No matches message**: Message displayed in place of the options when the filter input matches none of them, `"No matches found"` by default.
 /// - **Keep filter flag**: Whether the current filter input should be cleared or not after a selection is made. Defaults to true.
 ///
 /// # Example
@@ -57,16 +62,35 @@ pub struct MultiSelect<'a, T> {
     /// Help message to be presented to the user.
     pub help_message: Option<&'a str>,
 
+    /// Message displayed in place of the options when none of them match
+    /// the current filter input.
+    pub no_matches_message: &'a str,
+
     /// Page size of the options displayed to the user.
     pub page_size: usize,
 
+    /// Whether to display the range of options currently shown to the user,
+    /// when there are more options than fit in a single page.
+    pub page_indicator: bool,
+
     /// Whether vim mode is enabled. When enabled, the user can
-    /// navigate through the options using hjkl.
+    /// navigate through the options using hjkl, jump to the first and last
+    /// options using g and G, and typed characters only filter the options
+    /// after pressing `/`. Pressing esc while
+    /// filtering returns to navigation.
     pub vim_mode: bool,
 
+    /// Whether the mouse can be used to toggle options, on terminals that
+    /// support it.
+    pub mouse: bool,
+
     /// Starting cursor index of the selection.
     pub starting_cursor: usize,
 
+    /// Indexes of the options displayed as disabled, whose selection can not
+    /// be toggled.
+    pub disabled: &'a [usize],
+
     /// Function called with the current user input to filter the provided
     /// options.
     pub filter: Filter<'a, T>,
@@ -82,6 +106,12 @@ pub struct MultiSelect<'a, T> {
     /// In case of error, the message is displayed one line above the prompt.
     pub validator: Option<Box<dyn MultiOptionValidator<T>>>,
 
+    /// Minimum amount of options the user must select before submitting.
+    pub min_selected: Option<usize>,
+
+    /// Maximum amount of options the user is allowed to select when submitting.
+    pub max_selected: Option<usize>,
+
     /// RenderConfig to apply to the rendered interface.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -156,6 +186,9 @@ where
     /// Default page size, equal to the global default page size [config::DEFAULT_PAGE_SIZE]
     pub const DEFAULT_PAGE_SIZE: usize = config::DEFAULT_PAGE_SIZE;
 
+    /// Default value of the page indicator.
+    pub const DEFAULT_PAGE_INDICATOR: bool = false;
+
     /// Default value of vim mode, equal to the global default value [config::DEFAULT_PAGE_SIZE]
     pub const DEFAULT_VIM_MODE: bool = config::DEFAULT_VIM_MODE;
 
@@ -169,20 +202,31 @@ where
     pub const DEFAULT_HELP_MESSAGE: Option<&'a str> =
         Some("↑↓ to move, space to select one, → to all, ← to none, type to filter");
 
+    /// Default message displayed when no options match the filter input.
+    pub const DEFAULT_NO_MATCHES_MESSAGE: &'a str = "No matches found";
+
     /// Creates a [MultiSelect] with the provided message and options, along with default configuration values.
     pub fn new(message: &'a str, options: Vec<T>) -> Self {
+        let config = get_global_config();
+
         Self {
             message,
             options,
             default: None,
-            help_message: Self::DEFAULT_HELP_MESSAGE,
-            page_size: Self::DEFAULT_PAGE_SIZE,
-            vim_mode: Self::DEFAULT_VIM_MODE,
+            help_message: Self::DEFAULT_HELP_MESSAGE.filter(|_| config.help_messages),
+            no_matches_message: Self::DEFAULT_NO_MATCHES_MESSAGE,
+            page_size: config.page_size,
+            page_indicator: Self::DEFAULT_PAGE_INDICATOR,
+            vim_mode: config.vim_mode,
+            mouse: false,
             starting_cursor: Self::DEFAULT_STARTING_CURSOR,
+            disabled: &[],
             keep_filter: Self::DEFAULT_KEEP_FILTER,
             filter: Self::DEFAULT_FILTER,
             formatter: Self::DEFAULT_FORMATTER,
             validator: None,
+            min_selected: None,
+            max_selected: None,
             render_config: get_configuration(),
         }
     }
@@ -199,18 +243,43 @@ where
         self
     }
 
+    /// Sets the message displayed when no options match the filter input.
+    pub fn with_no_matches_message(mut self, message: &'a str) -> Self {
+        self.no_matches_message = message;
+        self
+    }
+
     /// Sets the page size.
     pub fn with_page_size(mut self, page_size: usize) -> Self {
         self.page_size = page_size;
         self
     }
 
+    /// Enables or disables the page indicator, e.g. `showing 8-14 of 20`,
+    /// displayed when there are more options than fit in a single page.
+    pub fn with_page_indicator(mut self, page_indicator: bool) -> Self {
+        self.page_indicator = page_indicator;
+        self
+    }
+
     /// Enables or disables vim_mode.
     pub fn with_vim_mode(mut self, vim_mode: bool) -> Self {
         self.vim_mode = vim_mode;
         self
     }
 
+    /// Enables or disables mouse support. When enabled, clicking an option
+    /// highlights it and toggles its selection, and the scroll wheel moves
+    /// the cursor as the arrow keys do.
+    ///
+    /// The mouse is captured while the prompt runs, which usually prevents
+    /// the user from selecting text with it. Terminals without mouse
+    /// support, and the termion and console back-ends, ignore this option.
+    pub fn with_mouse(mut self, mouse: bool) -> Self {
+        self.mouse = mouse;
+        self
+    }
+
     /// Sets the keep filter behavior.
     pub fn with_keep_filter(mut self, keep_filter: bool) -> Self {
         self.keep_filter = keep_filter;
@@ -242,7 +311,26 @@ where
         self
     }
 
-    /// Sets the indexes to be selected by the default.
+    /// Sets the minimum amount of options the user must select.
+    ///
+    /// The current selection count is displayed below the options, and
+    /// submitting with fewer selected options displays an error message.
+    pub fn with_min_selected(mut self, min_selected: usize) -> Self {
+        self.min_selected = Some(min_selected);
+        self
+    }
+
+    /// Sets the maximum amount of options the user is allowed to select.
+    ///
+    /// The current selection count is displayed below the options, and
+    /// submitting with more selected options displays an error message.
+    pub fn with_max_selected(mut self, max_selected: usize) -> Self {
+        self.max_selected = Some(max_selected);
+        self
+    }
+
+    /// Sets the indexes to be selected by the default. Indexes that are out of
+    /// range of the options are ignored.
     pub fn with_default(mut self, default: &'a [usize]) -> Self {
         self.default = Some(default);
         self
@@ -254,6 +342,18 @@ where
         self
     }
 
+    /// Sets the indexes of the options displayed as disabled, e.g. features
+    /// that are currently unavailable.
+    ///
+    /// Disabled options are rendered with the `disabled_option` style sheet of
+    /// the render config. They are skipped when the user moves the cursor, and
+    /// their selection is not changed by any key. Disabled options that are
+    /// selected by default remain selected.
+    pub fn with_disabled(mut self, disabled: &'a [usize]) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
     /// Sets the provided color theme to this prompt.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -330,7 +430,20 @@ where
         self,
         backend: &mut B,
     ) -> InquireResult<Vec<ListOption<T>>> {
-        MultiSelectPrompt::new(self)?.prompt(backend)
+        let mouse = self.mouse;
+        let prompt = MultiSelectPrompt::new(self)?;
+
+        if mouse {
+            backend.enable_mouse()?;
+        }
+
+        let result = prompt.prompt(backend);
+
+        if mouse {
+            backend.disable_mouse()?;
+        }
+
+        result
     }
 }
 
@@ -339,16 +452,22 @@ struct MultiSelectPrompt<'a, T> {
     options: Vec<T>,
     string_options: Vec<String>,
     help_message: Option<&'a str>,
+    no_matches_message: &'a str,
     vim_mode: bool,
+    filtering: bool,
     cursor_index: usize,
     checked: BTreeSet<usize>,
+    disabled: BTreeSet<usize>,
     page_size: usize,
+    page_indicator: bool,
     keep_filter: bool,
     input: Input,
     filtered_options: Vec<usize>,
     filter: Filter<'a, T>,
     formatter: MultiOptionFormatter<'a, T>,
     validator: Option<Box<dyn MultiOptionValidator<T>>>,
+    min_selected: Option<usize>,
+    max_selected: Option<usize>,
     error: Option<ErrorMessage>,
 }
 
@@ -362,41 +481,57 @@ where
                 "Available options can not be empty".into(),
             ));
         }
-        if let Some(default) = mso.default {
-            for i in default {
-                if i >= &mso.options.len() {
-                    return Err(InquireError::InvalidConfiguration(format!(
-                        "Index {} is out-of-bounds for length {} of options",
-                        i,
-                        &mso.options.len()
-                    )));
-                }
+        if mso.starting_cursor >= mso.options.len() {
+            return Err(InquireError::InvalidConfiguration(format!(
+                "Starting cursor index {} is out-of-bounds for length {} of options",
+                mso.starting_cursor,
+                &mso.options.len()
+            )));
+        }
+        if let (Some(min), Some(max)) = (mso.min_selected, mso.max_selected) {
+            if min > max {
+                return Err(InquireError::InvalidConfiguration(format!(
+                    "Minimum of {} selected options is greater than the maximum of {}",
+                    min, max
+                )));
             }
         }
 
         let string_options = mso.options.iter().map(T::to_string).collect();
         let filtered_options = (0..mso.options.len()).collect();
-        let checked_options = mso
-            .default
-            .map_or_else(BTreeSet::new, |d| d.iter().cloned().collect());
-
-        Ok(Self {
+        // defaults are often loaded from previous answers, which might refer to
+        // options that no longer exist
+        let options_len = mso.options.len();
+        let checked_options = mso.default.map_or_else(BTreeSet::new, |d| {
+            d.iter().cloned().filter(|i| *i < options_len).collect()
+        });
+
+        let mut prompt = Self {
             message: mso.message,
             options: mso.options,
             string_options,
             filtered_options,
             help_message: mso.help_message,
+            no_matches_message: mso.no_matches_message,
             vim_mode: mso.vim_mode,
+            filtering: !mso.vim_mode,
             cursor_index: mso.starting_cursor,
             page_size: mso.page_size,
+            page_indicator: mso.page_indicator,
             keep_filter: mso.keep_filter,
             input: Input::new(),
             filter: mso.filter,
             formatter: mso.formatter,
             validator: mso.validator,
+            min_selected: mso.min_selected,
+            max_selected: mso.max_selected,
             error: None,
             checked: checked_options,
-        })
+            disabled: mso.disabled.iter().cloned().collect(),
+        };
+        prompt.skip_disabled(true, false);
+
+        Ok(prompt)
     }
 
     fn filter_options(&self) -> Vec<usize> {
@@ -404,13 +539,33 @@ where
             .iter()
             .enumerate()
             .filter_map(|(i, opt)| match self.input.content() {
-                val if val.is_empty() => Some(i),
+                "" => Some(i),
                 val if (self.filter)(val, opt, self.string_options.get(i).unwrap(), i) => Some(i),
                 _ => None,
             })
             .collect()
     }
 
+    fn is_selectable(&self, cursor_index: usize) -> bool {
+        match self.filtered_options.get(cursor_index) {
+            Some(index) => !self.disabled.contains(index),
+            None => false,
+        }
+    }
+
+    /// Moves the cursor to the closest option that is not disabled, looking
+    /// first in the direction the cursor was moving.
+    fn skip_disabled(&mut self, forward: bool, wrap: bool) {
+        let len = self.filtered_options.len();
+        let closest = closest_selectable(self.cursor_index, len, forward, wrap, |i| {
+            self.is_selectable(i)
+        });
+
+        if let Some(index) = closest {
+            self.cursor_index = index;
+        }
+    }
+
     fn move_cursor_up(&mut self, qty: usize, wrap: bool) {
         if wrap {
             let after_wrap = qty.saturating_sub(self.cursor_index);
@@ -421,6 +576,8 @@ where
         } else {
             self.cursor_index = self.cursor_index.saturating_sub(qty);
         }
+
+        self.skip_disabled(false, wrap);
     }
 
     fn move_cursor_down(&mut self, qty: usize, wrap: bool) {
@@ -435,9 +592,15 @@ where
                 self.filtered_options.len().saturating_sub(1)
             }
         }
+
+        self.skip_disabled(true, wrap);
     }
 
     fn toggle_cursor_selection(&mut self) {
+        if !self.is_selectable(self.cursor_index) {
+            return;
+        }
+
         let idx = match self.filtered_options.get(self.cursor_index) {
             Some(val) => val,
             None => return,
@@ -454,36 +617,69 @@ where
         }
     }
 
+    /// Moves the cursor to the clicked option and toggles its selection.
+    fn click_option(&mut self, index: usize) {
+        let position = self.filtered_options.iter().position(|i| *i == index);
+
+        if let Some(position) = position.filter(|p| self.is_selectable(*p)) {
+            self.cursor_index = position;
+            self.toggle_cursor_selection();
+        }
+    }
+
+    /// Selects all of the options displayed, except for the disabled ones.
+    fn select_all(&mut self) {
+        let disabled = &self.disabled;
+        self.checked.retain(|idx| disabled.contains(idx));
+        for idx in &self.filtered_options {
+            if !self.disabled.contains(idx) {
+                self.checked.insert(*idx);
+            }
+        }
+
+        if !self.keep_filter {
+            self.input.clear();
+        }
+    }
+
+    /// Unselects all of the options, except for the disabled ones.
+    fn clear_selection(&mut self) {
+        let disabled = &self.disabled;
+        self.checked.retain(|idx| disabled.contains(idx));
+
+        if !self.keep_filter {
+            self.input.clear();
+        }
+    }
+
     fn on_change(&mut self, key: Key) {
         match key {
             Key::Up(KeyModifiers::NONE) => self.move_cursor_up(1, true),
-            Key::Char('k', KeyModifiers::NONE) if self.vim_mode => self.move_cursor_up(1, true),
+            Key::Char('k', KeyModifiers::NONE) if !self.filtering => self.move_cursor_up(1, true),
             Key::PageUp => self.move_cursor_up(self.page_size, false),
             Key::Home => self.move_cursor_up(usize::MAX, false),
+            Key::Char('g', KeyModifiers::NONE) if !self.filtering => {
+                self.move_cursor_up(usize::MAX, false)
+            }
 
             Key::Down(KeyModifiers::NONE) => self.move_cursor_down(1, true),
-            Key::Char('j', KeyModifiers::NONE) if self.vim_mode => self.move_cursor_down(1, true),
+            Key::Char('j', KeyModifiers::NONE) if !self.filtering => self.move_cursor_down(1, true),
             Key::PageDown => self.move_cursor_down(self.page_size, false),
             Key::End => self.move_cursor_down(usize::MAX, false),
+            Key::Char('G', m)
+                if !self.filtering && !m.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) =>
+            {
+                self.move_cursor_down(usize::MAX, false)
+            }
 
             Key::Char(' ', KeyModifiers::NONE) => self.toggle_cursor_selection(),
-            Key::Right(KeyModifiers::NONE) => {
-                self.checked.clear();
-                for idx in &self.filtered_options {
-                    self.checked.insert(*idx);
-                }
+            Key::Right(KeyModifiers::NONE) => self.select_all(),
+            Key::Char('a', KeyModifiers::CONTROL) => self.select_all(),
+            Key::Left(KeyModifiers::NONE) => self.clear_selection(),
+            Key::Char('d', KeyModifiers::CONTROL) => self.clear_selection(),
+            Key::Char('/', KeyModifiers::NONE) if !self.filtering => self.filtering = true,
+            Key::Char(..) if !self.filtering => {}
 
-                if !self.keep_filter {
-                    self.input.clear();
-                }
-            }
-            Key::Left(KeyModifiers::NONE) => {
-                self.checked.clear();
-
-                if !self.keep_filter {
-                    self.input.clear();
-                }
-            }
             key => {
                 let dirty = self.input.handle_key(key);
 
@@ -493,12 +689,38 @@ where
                         self.cursor_index = options.len().saturating_sub(1);
                     }
                     self.filtered_options = options;
+                    self.skip_disabled(true, false);
                 }
             }
         };
     }
 
+    /// Returns an error message when the amount of selected options is out of
+    /// the configured selection bounds.
+    fn selection_bounds_error(&self) -> Option<ErrorMessage> {
+        let selected = self.checked.len();
+        let plural = |n: usize| if n == 1 { "option" } else { "options" };
+
+        match (self.min_selected, self.max_selected) {
+            (Some(min), _) if selected < min => Some(ErrorMessage::Custom(format!(
+                "Please select at least {} {}",
+                min,
+                plural(min)
+            ))),
+            (_, Some(max)) if selected > max => Some(ErrorMessage::Custom(format!(
+                "Please select at most {} {}",
+                max,
+                plural(max)
+            ))),
+            _ => None,
+        }
+    }
+
     fn validate_current_answer(&self) -> InquireResult<Validation> {
+        if let Some(err) = self.selection_bounds_error() {
+            return Ok(Validation::Invalid(err));
+        }
+
         if let Some(validator) = &self.validator {
             let selected_options = self
                 .options
@@ -534,6 +756,12 @@ where
         answer
     }
 
+    /// Whether the range of options displayed should be rendered below them,
+    /// which is only the case when they do not fit in a single page.
+    fn shows_page_indicator(&self) -> bool {
+        self.page_indicator && self.filtered_options.len() > self.page_size
+    }
+
     fn render<B: MultiSelectBackend>(&mut self, backend: &mut B) -> InquireResult<()> {
         let prompt = &self.message;
 
@@ -553,8 +781,21 @@ where
             .collect::<Vec<ListOption<&T>>>();
 
         let page = paginate(self.page_size, &choices, self.cursor_index);
+        let page_indicator = self.shows_page_indicator();
 
-        backend.render_options(page, &self.checked)?;
+        if choices.is_empty() {
+            backend.render_no_matches_message(self.no_matches_message)?;
+        } else {
+            backend.render_options(page, &self.checked, &self.disabled, page_indicator)?;
+        }
+
+        if self.min_selected.is_some() || self.max_selected.is_some() {
+            backend.render_selection_count(
+                self.checked.len(),
+                self.min_selected,
+                self.max_selected,
+            )?;
+        }
 
         if let Some(help_message) = self.help_message {
             backend.render_help_message(help_message)?;
@@ -576,11 +817,17 @@ where
 
             match key {
                 Key::Interrupt => interrupt_prompt!(),
+                Key::Cancel if self.vim_mode && self.filtering => self.filtering = false,
                 Key::Cancel => cancel_prompt!(backend, self.message),
                 Key::Submit => match self.validate_current_answer()? {
-                    Validation::Valid => break,
+                    Validation::Valid | Validation::Warning(_) => break,
                     Validation::Invalid(msg) => self.error = Some(msg),
                 },
+                Key::Click { row, .. } => {
+                    if let Some(index) = backend.option_at_row(row) {
+                        self.click_option(index);
+                    }
+                }
                 key => self.on_change(key),
             }
         }
@@ -596,14 +843,44 @@ where
 #[cfg(test)]
 #[cfg(feature = "crossterm")]
 mod test {
+    use super::MultiSelectPrompt;
     use crate::{
+        error::InquireError,
         formatter::MultiOptionFormatter,
         list_option::ListOption,
         terminal::crossterm::CrosstermTerminal,
         ui::{Backend, RenderConfig},
+        validator::{MaxLengthValidator, MinLengthValidator},
         MultiSelect,
     };
-    use crossterm::event::{KeyCode, KeyEvent};
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    macro_rules! multiselect_test {
+        ($name:ident,$input:expr,$output:expr,$prompt:expr) => {
+            multiselect_test! {$name, $input, $output, $prompt, |_rendered| {}}
+        };
+
+        ($name:ident,$input:expr,$output:expr,$prompt:expr,|$rendered:ident| $check:block) => {
+            #[test]
+            fn $name() {
+                let read: Vec<KeyEvent> = $input.into_iter().map(KeyEvent::from).collect();
+                let mut read = read.into_iter();
+
+                let mut write: Vec<u8> = Vec::new();
+                {
+                    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+                    let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+                    let ans = $prompt.prompt_with_backend(&mut backend).unwrap();
+
+                    assert_eq!($output, ans);
+                }
+
+                let $rendered = String::from_utf8(write).unwrap();
+                $check
+            }
+        };
+    }
 
     #[test]
     /// Tests that a closure that actually closes on a variable can be used
@@ -613,7 +890,7 @@ mod test {
             .into_iter()
             .map(KeyEvent::from)
             .collect();
-        let mut read = read.iter();
+        let mut read = read.into_iter();
 
         let formatted = String::from("Thanks!");
         let formatter: MultiOptionFormatter<i32> = &|_| formatted.clone();
@@ -648,7 +925,7 @@ mod test {
         .map(|c| KeyEvent::from(*c))
         .collect();
 
-        let mut read = read.iter();
+        let mut read = read.into_iter();
 
         let options = vec![1, 2, 3];
 
@@ -676,7 +953,7 @@ mod test {
         .into_iter()
         .map(KeyEvent::from)
         .collect();
-        let mut read = read.iter();
+        let mut read = read.into_iter();
 
         let options = vec![1, 2, 3];
 
@@ -690,4 +967,300 @@ mod test {
 
         assert_eq!(vec![ListOption::new(1, 2), ListOption::new(2, 3)], ans);
     }
+
+    multiselect_test!(
+        toggling_twice_unselects_option,
+        vec![
+            KeyCode::Char(' '),
+            KeyCode::Down,
+            KeyCode::Char(' '),
+            KeyCode::Down,
+            KeyCode::Char(' '),
+            KeyCode::Up,
+            KeyCode::Char(' '),
+            KeyCode::Enter,
+        ],
+        vec![ListOption::new(0, "a"), ListOption::new(2, "c")],
+        MultiSelect::new("Question", vec!["a", "b", "c", "d"])
+    );
+
+    multiselect_test!(
+        default_options_are_preselected,
+        vec![KeyCode::Down, KeyCode::Char(' '), KeyCode::Enter],
+        vec![ListOption::new(3, "d")],
+        MultiSelect::new("Question", vec!["a", "b", "c", "d"]).with_default(&[1, 3])
+    );
+
+    #[test]
+    fn default_options_are_rendered_checked_before_any_input() {
+        let mut read = std::iter::empty();
+
+        let mut write: Vec<u8> = Vec::new();
+        let ans = {
+            let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+            let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+            MultiSelect::new("Question", vec!["a", "b", "c"])
+                .with_default(&[0, 2])
+                .prompt_with_backend(&mut backend)
+        };
+        let output = String::from_utf8(write).unwrap();
+
+        assert!(ans.is_err());
+        assert!(output.contains("> [x] a\r\n  [ ] b\r\n  [x] c\r\n"));
+    }
+
+    multiselect_test!(
+        out_of_range_default_options_are_ignored,
+        vec![KeyCode::Enter],
+        vec![ListOption::new(1, "b")],
+        MultiSelect::new("Question", vec!["a", "b", "c"]).with_default(&[1, 3, 10])
+    );
+
+    multiselect_test!(
+        minimum_selection_count_is_enforced_on_submit,
+        vec![
+            KeyCode::Char(' '),
+            KeyCode::Enter,
+            KeyCode::Down,
+            KeyCode::Char(' '),
+            KeyCode::Enter,
+        ],
+        vec![ListOption::new(0, "a"), ListOption::new(1, "b")],
+        MultiSelect::new("Question", vec!["a", "b", "c", "d"])
+            .with_validator(MinLengthValidator::new(2).with_message("Pick two")),
+        |output| {
+            assert!(output.contains("Pick two"));
+        }
+    );
+
+    multiselect_test!(
+        maximum_selection_count_is_enforced_on_submit,
+        vec![
+            KeyCode::Right,
+            KeyCode::Enter,
+            KeyCode::Left,
+            KeyCode::Char(' '),
+            KeyCode::Enter,
+        ],
+        vec![ListOption::new(0, "a")],
+        MultiSelect::new("Question", vec!["a", "b", "c", "d"])
+            .with_validator(MaxLengthValidator::new(1))
+    );
+
+    multiselect_test!(
+        minimum_selection_blocks_submit_and_is_counted_live,
+        vec![
+            KeyCode::Enter,
+            KeyCode::Char(' '),
+            KeyCode::Enter,
+            KeyCode::Down,
+            KeyCode::Char(' '),
+            KeyCode::Enter,
+        ],
+        vec![ListOption::new(0, "a"), ListOption::new(1, "b")],
+        MultiSelect::new("Question", vec!["a", "b", "c"]).with_min_selected(2),
+        |output| {
+            assert!(output.contains("selected 0/min 2"));
+            assert!(output.contains("selected 1/min 2"));
+            assert!(output.contains("selected 2/min 2"));
+            assert!(output.contains("Please select at least 2 options"));
+        }
+    );
+
+    multiselect_test!(
+        maximum_selection_blocks_submit,
+        vec![
+            KeyCode::Right,
+            KeyCode::Enter,
+            KeyCode::Char(' '),
+            KeyCode::Enter,
+        ],
+        vec![ListOption::new(1, "b"), ListOption::new(2, "c")],
+        MultiSelect::new("Question", vec!["a", "b", "c"])
+            .with_min_selected(1)
+            .with_max_selected(2),
+        |output| {
+            assert!(output.contains("selected 3/min 1/max 2"));
+            assert!(output.contains("Please select at most 2 options"));
+        }
+    );
+
+    multiselect_test!(
+        starting_cursor_beyond_first_page_is_rendered,
+        vec![KeyCode::Char(' '), KeyCode::Enter],
+        vec![ListOption::new(9, String::from("Option 09"))],
+        MultiSelect::new(
+            "Question",
+            (0..12).map(|i| format!("Option {:02}", i)).collect()
+        )
+        .with_page_size(5)
+        .with_starting_cursor(9),
+        |output| {
+            for i in 7..12 {
+                assert!(output.contains(&format!("Option {:02}", i)));
+            }
+            for i in 0..7 {
+                assert!(!output.contains(&format!("Option {:02}", i)));
+            }
+        }
+    );
+
+    #[test]
+    fn min_selected_greater_than_max_selected_is_invalid_configuration() {
+        let multiselect = MultiSelect::new("Question", vec!["a", "b", "c"])
+            .with_min_selected(2)
+            .with_max_selected(1);
+
+        let prompt = MultiSelectPrompt::new(multiselect);
+
+        assert!(matches!(prompt, Err(InquireError::InvalidConfiguration(_))));
+    }
+
+    #[test]
+    fn out_of_bounds_starting_cursor_is_invalid_configuration() {
+        let multiselect = MultiSelect::new("Question", vec!["a", "b", "c"]).with_starting_cursor(3);
+
+        let prompt = MultiSelectPrompt::new(multiselect);
+
+        assert!(matches!(prompt, Err(InquireError::InvalidConfiguration(_))));
+    }
+
+    multiselect_test!(
+        page_indicator_is_rendered_on_long_lists,
+        vec![KeyCode::End, KeyCode::Char(' '), KeyCode::Enter],
+        vec![ListOption::new(29, 30)],
+        MultiSelect::new("Question", (1..=30).collect::<Vec<usize>>())
+            .with_page_size(10)
+            .with_page_indicator(true),
+        |output| {
+            assert!(output.contains("showing 1-10 of 30"));
+            assert!(output.contains("showing 21-30 of 30"));
+        }
+    );
+
+    multiselect_test!(
+        no_matches_message_is_rendered,
+        vec![
+            KeyCode::Char('x'),
+            KeyCode::Backspace,
+            KeyCode::Char(' '),
+            KeyCode::Enter,
+        ],
+        vec![ListOption::new(0, "a")],
+        MultiSelect::new("Question", vec!["a", "b", "c"]),
+        |output| {
+            assert!(output.contains(MultiSelect::<&str>::DEFAULT_NO_MATCHES_MESSAGE));
+        }
+    );
+
+    multiselect_test!(
+        custom_filter_replaces_substring_filter,
+        vec![
+            KeyCode::Char('z'),
+            KeyCode::Char(' '),
+            KeyCode::Down,
+            KeyCode::Char(' '),
+            KeyCode::Enter,
+        ],
+        vec![ListOption::new(0, "a"), ListOption::new(2, "c")],
+        MultiSelect::new("Question", vec!["a", "b", "c", "d", "e"])
+            .with_filter(&|_, _, _, i| i % 2 == 0)
+    );
+
+    fn with_disabled_middle() -> MultiSelect<'static, &'static str> {
+        MultiSelect::new("Question", vec!["a", "b", "c"]).with_disabled(&[1])
+    }
+
+    multiselect_test!(
+        navigation_jumps_over_disabled_option,
+        vec![KeyCode::Down, KeyCode::Char(' '), KeyCode::Enter],
+        vec![ListOption::new(2, "c")],
+        with_disabled_middle()
+    );
+
+    multiselect_test!(
+        space_does_not_toggle_disabled_option,
+        vec![KeyCode::Char('b'), KeyCode::Char(' '), KeyCode::Enter],
+        vec![ListOption::new(1, "b")],
+        with_disabled_middle().with_default(&[1])
+    );
+
+    multiselect_test!(
+        select_all_leaves_disabled_option_untouched,
+        vec![KeyCode::Right, KeyCode::Enter],
+        vec![ListOption::new(0, "a"), ListOption::new(2, "c")],
+        with_disabled_middle()
+    );
+
+    multiselect_test!(
+        select_none_leaves_disabled_option_untouched,
+        vec![KeyCode::Left, KeyCode::Enter],
+        vec![ListOption::new(1, "b")],
+        with_disabled_middle().with_default(&[0, 1])
+    );
+
+    fn ctrl_a() -> KeyEvent {
+        KeyEvent::new(KeyCode::Char('a'), KeyModifiers::CONTROL)
+    }
+
+    fn ctrl_d() -> KeyEvent {
+        KeyEvent::new(KeyCode::Char('d'), KeyModifiers::CONTROL)
+    }
+
+    fn enter() -> KeyEvent {
+        KeyEvent::from(KeyCode::Enter)
+    }
+
+    multiselect_test!(
+        ctrl_a_selects_all_options,
+        vec![ctrl_a(), enter()],
+        vec![
+            ListOption::new(0, "a"),
+            ListOption::new(1, "b"),
+            ListOption::new(2, "c")
+        ],
+        MultiSelect::new("Question", vec!["a", "b", "c"])
+    );
+
+    multiselect_test!(
+        ctrl_a_skips_disabled_options,
+        vec![ctrl_a(), enter()],
+        vec![ListOption::new(1, "b"), ListOption::new(2, "c")],
+        MultiSelect::new("Question", vec!["a", "b", "c"]).with_disabled(&[0])
+    );
+
+    multiselect_test!(
+        ctrl_d_clears_selection,
+        vec![ctrl_a(), ctrl_d(), enter()],
+        Vec::<ListOption<&str>>::new(),
+        MultiSelect::new("Question", vec!["a", "b", "c"]).with_default(&[1])
+    );
+
+    multiselect_test!(
+        vim_mode_navigates_with_j_and_k,
+        vec![
+            KeyCode::Char('j'),
+            KeyCode::Char('j'),
+            KeyCode::Char(' '),
+            KeyCode::Char('k'),
+            KeyCode::Char(' '),
+            KeyCode::Enter,
+        ],
+        vec![ListOption::new(1, "b"), ListOption::new(2, "c")],
+        MultiSelect::new("Question", vec!["a", "b", "c"]).with_vim_mode(true)
+    );
+
+    multiselect_test!(
+        vim_mode_filters_after_slash,
+        vec![
+            KeyCode::Char('k'),
+            KeyCode::Char('/'),
+            KeyCode::Char('j'),
+            KeyCode::Char(' '),
+            KeyCode::Enter,
+        ],
+        vec![ListOption::new(1, "jam")],
+        MultiSelect::new("Question", vec!["apple", "jam", "kiwi"]).with_vim_mode(true)
+    );
 }
@@ -1,9 +1,12 @@
+use std::fmt;
+
 use crate::{
     config::get_configuration,
     error::{InquireError, InquireResult},
     formatter::StringFormatter,
     input::Input,
     terminal::get_default_terminal,
+    type_aliases::PasswordScorer,
     ui::{Backend, Key, KeyModifiers, PasswordBackend, RenderConfig},
     validator::{ErrorMessage, StringValidator, Validation},
 };
@@ -24,6 +27,32 @@ pub enum PasswordDisplayMode {
     Full,
 }
 
+/// Strength of a password, as displayed by the strength meter of a
+/// [Password] prompt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum PasswordStrength {
+    /// The password is easy to guess.
+    Weak,
+
+    /// The password is acceptable, but could be longer or more varied.
+    Medium,
+
+    /// The password is hard to guess.
+    Strong,
+}
+
+impl fmt::Display for PasswordStrength {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self {
+            PasswordStrength::Weak => "weak",
+            PasswordStrength::Medium => "medium",
+            PasswordStrength::Strong => "strong",
+        };
+
+        write!(f, "{}", label)
+    }
+}
+
 // Helper type for representing the password confirmation flow.
 struct PasswordConfirmation<'a> {
     // The message of the prompt.
@@ -56,6 +85,9 @@ struct PasswordConfirmation<'a> {
 ///   - By default, it prints eight asterisk characters: `********`.
 /// - **Validators**: Custom validators to make sure a given submitted input pass the specified requirements, e.g. not allowing empty inputs or requiring special characters.
 ///   - No validators are on by default.
+/// - **Strength meter**: Displays the strength of the password below the input as the user types it, without revealing the password itself.
+///   - Disabled by default, it can be enabled with the `with_strength_meter()` method.
+///   - The strength is scored by default from the length and the classes of characters of the password, and a custom scorer can be set with the `with_strength_scorer()` method.
 ///
 /// Remember that for CLI applications it is standard to not allow use any display modes other than `Hidden` and to not allow the user to see the text input in any way. _Use the customization options at your discretion_.
 ///
@@ -119,6 +151,12 @@ pub struct Password<'a> {
     /// The possible error is displayed to the user one line above the prompt.
     pub validators: Vec<Box<dyn StringValidator>>,
 
+    /// Whether to display the strength of the password below the input.
+    pub strength_meter: bool,
+
+    /// Function scoring the strength of the password displayed by the strength meter.
+    pub strength_scorer: PasswordScorer<'a>,
+
     /// RenderConfig to apply to the rendered interface.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -149,6 +187,43 @@ impl<'a> Password<'a> {
     /// Default password display mode.
     pub const DEFAULT_DISPLAY_MODE: PasswordDisplayMode = PasswordDisplayMode::Hidden;
 
+    /// Default strength scorer. Passwords of at least 12 characters mixing at
+    /// least three classes of characters, among lowercase letters, uppercase
+    /// letters, digits and symbols, are strong. Passwords of at least 8
+    /// characters mixing at least two classes are medium, and the rest weak.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inquire::{Password, PasswordStrength};
+    ///
+    /// let scorer = Password::DEFAULT_STRENGTH_SCORER;
+    /// assert_eq!(PasswordStrength::Weak, scorer("password"));
+    /// assert_eq!(PasswordStrength::Medium, scorer("passw0rd"));
+    /// assert_eq!(PasswordStrength::Strong, scorer("Correct-Horse-7"));
+    /// ```
+    pub const DEFAULT_STRENGTH_SCORER: PasswordScorer<'a> = &|password| {
+        let length = password.chars().count();
+        let has = |class: fn(&char) -> bool| password.chars().any(|c| class(&c));
+        let classes = [
+            has(char::is_ascii_lowercase),
+            has(char::is_ascii_uppercase),
+            has(char::is_ascii_digit),
+            has(|c| !c.is_ascii_alphanumeric()),
+        ]
+        .iter()
+        .filter(|present| **present)
+        .count();
+
+        if length >= 12 && classes >= 3 {
+            PasswordStrength::Strong
+        } else if length >= 8 && classes >= 2 {
+            PasswordStrength::Medium
+        } else {
+            PasswordStrength::Weak
+        }
+    };
+
     /// Creates a [Password] with the provided message and default options.
     pub fn new(message: &'a str) -> Self {
         Self {
@@ -161,6 +236,8 @@ impl<'a> Password<'a> {
             help_message: Self::DEFAULT_HELP_MESSAGE,
             formatter: Self::DEFAULT_FORMATTER,
             validators: Self::DEFAULT_VALIDATORS,
+            strength_meter: false,
+            strength_scorer: Self::DEFAULT_STRENGTH_SCORER,
             render_config: get_configuration(),
         }
     }
@@ -237,12 +314,25 @@ impl<'a> Password<'a> {
     /// The possible error is displayed to the user one line above the prompt.
     pub fn with_validators(mut self, validators: &[Box<dyn StringValidator>]) -> Self {
         for validator in validators {
-            #[allow(clippy::clone_double_ref)]
             self.validators.push(validator.clone());
         }
         self
     }
 
+    /// Enables or disables the strength meter, displaying the strength of the
+    /// password below the input as the user types it.
+    pub fn with_strength_meter(mut self, strength_meter: bool) -> Self {
+        self.strength_meter = strength_meter;
+        self
+    }
+
+    /// Sets the function scoring the strength of the password displayed by
+    /// the strength meter.
+    pub fn with_strength_scorer(mut self, scorer: PasswordScorer<'a>) -> Self {
+        self.strength_scorer = scorer;
+        self
+    }
+
     /// Sets the provided color theme to this prompt.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -300,6 +390,7 @@ struct PasswordPrompt<'a> {
     confirmation_stage: bool,
     formatter: StringFormatter<'a>,
     validators: Vec<Box<dyn StringValidator>>,
+    strength_scorer: Option<PasswordScorer<'a>>,
     error: Option<ErrorMessage>,
 }
 
@@ -326,6 +417,10 @@ impl<'a> From<Password<'a>> for PasswordPrompt<'a> {
             confirmation_stage: false,
             formatter: so.formatter,
             validators: so.validators,
+            strength_scorer: match so.strength_meter {
+                true => Some(so.strength_scorer),
+                false => None,
+            },
             input: Input::new(),
             error: None,
         }
@@ -391,7 +486,7 @@ impl<'a> PasswordPrompt<'a> {
 
     fn handle_submit(&mut self) -> InquireResult<Option<String>> {
         let answer = match self.validate_current_answer()? {
-            Validation::Valid => self.confirm_current_answer(),
+            Validation::Valid | Validation::Warning(_) => self.confirm_current_answer(),
             Validation::Invalid(msg) => {
                 self.error = Some(msg);
                 None
@@ -432,7 +527,7 @@ impl<'a> PasswordPrompt<'a> {
     fn validate_current_answer(&self) -> InquireResult<Validation> {
         for validator in &self.validators {
             match validator.validate(self.active_input().content()) {
-                Ok(Validation::Valid) => {}
+                Ok(Validation::Valid) | Ok(Validation::Warning(_)) => {}
                 Ok(Validation::Invalid(msg)) => return Ok(Validation::Invalid(msg)),
                 Err(err) => return Err(InquireError::Custom(err)),
             }
@@ -491,6 +586,12 @@ impl<'a> PasswordPrompt<'a> {
             }
         }
 
+        if let Some(scorer) = self.strength_scorer {
+            if !self.confirmation_stage && !self.input.is_empty() {
+                backend.render_strength_meter(scorer(self.input.content()))?;
+            }
+        }
+
         if let Some(message) = self.help_message {
             backend.render_help_message(message)?;
         }
@@ -531,13 +632,13 @@ impl<'a> PasswordPrompt<'a> {
 #[cfg(test)]
 #[cfg(feature = "crossterm")]
 mod test {
-    use super::Password;
+    use super::{Password, PasswordDisplayMode};
     use crate::{
         terminal::crossterm::CrosstermTerminal,
         ui::{Backend, RenderConfig},
         validator::{ErrorMessage, Validation},
     };
-    use crossterm::event::{KeyCode, KeyEvent};
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
     macro_rules! text_to_events {
         ($text:expr) => {{
@@ -551,7 +652,7 @@ mod test {
             $(#[$meta])?
             fn $name() {
                 let read: Vec<KeyEvent> = $input.into_iter().map(KeyEvent::from).collect();
-                let mut read = read.iter();
+                let mut read = read.into_iter();
 
                 let mut write: Vec<u8> = Vec::new();
                 let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
@@ -684,4 +785,88 @@ mod test {
         "",
         Password::new("")
     );
+
+    #[test]
+    fn display_toggle_reveals_masked_input() {
+        let mut read: Vec<KeyEvent> = text_to_events!("secret").map(KeyEvent::from).collect();
+        read.push(KeyEvent::new(KeyCode::Char('r'), KeyModifiers::CONTROL));
+        read.push(KeyEvent::from(KeyCode::Enter));
+        let mut read = read.into_iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        {
+            let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+            let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+            let ans = Password::new("")
+                .without_confirmation()
+                .with_display_mode(PasswordDisplayMode::Masked)
+                .with_display_toggle_enabled()
+                .prompt_with_backend(&mut backend)
+                .unwrap();
+
+            assert_eq!("secret", ans);
+        }
+
+        let output = String::from_utf8(write).unwrap();
+        let masked = output
+            .find("******")
+            .expect("masked input was not rendered");
+        let revealed = output
+            .find("secret")
+            .expect("revealed input was not rendered");
+
+        assert!(masked < revealed);
+    }
+
+    #[test]
+    fn strength_meter_follows_input() {
+        let mut read: Vec<KeyEvent> = text_to_events!("abc").map(KeyEvent::from).collect();
+        read.extend(text_to_events!("Def-12345\n").map(KeyEvent::from));
+        let mut read = read.into_iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        {
+            let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+            let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+            let ans = Password::new("Password:")
+                .without_confirmation()
+                .with_strength_meter(true)
+                .prompt_with_backend(&mut backend)
+                .unwrap();
+
+            assert_eq!("abcDef-12345", ans);
+        }
+
+        let output = String::from_utf8(write).unwrap();
+        let weak = output
+            .find("Strength: [#--] weak")
+            .expect("weak strength was not rendered");
+        let strong = output
+            .find("Strength: [###] strong")
+            .expect("strong strength was not rendered");
+
+        assert!(weak < strong);
+        assert!(!output.contains("abc"));
+    }
+
+    #[test]
+    fn strength_meter_is_disabled_by_default() {
+        let mut read = text_to_events!("abc\n").map(KeyEvent::from);
+
+        let mut write: Vec<u8> = Vec::new();
+        {
+            let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+            let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+            Password::new("Password:")
+                .without_confirmation()
+                .prompt_with_backend(&mut backend)
+                .unwrap();
+        }
+
+        let output = String::from_utf8(write).unwrap();
+        assert!(!output.contains("Strength"));
+    }
 }
@@ -15,7 +15,19 @@ macro_rules! interrupt_prompt {
 
 macro_rules! finish_prompt_with_answer {
     ($backend:expr, $prompt_message:expr, $formatted_answer:expr, $answer: expr) => {{
-        $backend.frame_setup()?;
+        finish_prompt_with_answer!($backend, $prompt_message, $formatted_answer, $answer, false)
+    }};
+    (
+        $backend:expr,
+        $prompt_message:expr,
+        $formatted_answer:expr,
+        $answer: expr,
+        $keep_on_screen: expr
+    ) => {{
+        match $keep_on_screen {
+            true => $backend.frame_setup_below()?,
+            false => $backend.frame_setup()?,
+        }
         $backend.render_prompt_with_answer($prompt_message, $formatted_answer)?;
         $backend.frame_finish()?;
 
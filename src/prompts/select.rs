@@ -1,17 +1,65 @@
-use std::fmt::Display;
+use std::{borrow::Cow, collections::BTreeSet, fmt::Display, time::Duration};
 
 use crate::{
-    config::{self, get_configuration},
+    config::{self, get_configuration, get_global_config},
     error::{InquireError, InquireResult},
-    formatter::OptionFormatter,
+    formatter::{OptionDisplayFormatter, OptionFormatter},
     input::Input,
     list_option::ListOption,
     terminal::get_default_terminal,
     type_aliases::Filter,
     ui::{Backend, Key, KeyModifiers, RenderConfig, SelectBackend},
-    utils::paginate,
+    utils::{closest_selectable, fold_accents, paginate},
 };
 
+/// Period of inactivity after which the characters typed in type-ahead mode,
+/// or the digits typed in numbered mode, are forgotten, so that the next one
+/// starts a new search.
+const TYPEAHEAD_RESET: Duration = Duration::from_millis(1000);
+
+/// Modes of comparison between the filter input of a [`Select`] prompt and
+/// the string values of its options.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FilterMode {
+    /// Options are displayed when they contain the filter input exactly.
+    CaseSensitive,
+
+    /// Options are displayed when they contain the filter input, ignoring
+    /// the case of both. This is the default mode.
+    CaseInsensitive,
+
+    /// Options are displayed when they contain the filter input, ignoring
+    /// both case and diacritics, e.g. `"cafe"` matches `"Café"`.
+    AccentInsensitive,
+}
+
+impl FilterMode {
+    /// Returns whether `value` matches the `filter` input in this mode.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inquire::FilterMode;
+    ///
+    /// assert_eq!(false, FilterMode::CaseSensitive.matches("CAFE", "Café"));
+    /// assert_eq!(false, FilterMode::CaseInsensitive.matches("CAFE", "Café"));
+    /// assert_eq!(true,  FilterMode::AccentInsensitive.matches("CAFE", "Café"));
+    /// ```
+    pub fn matches(self, filter: &str, value: &str) -> bool {
+        match self {
+            Self::CaseSensitive => value.contains(filter),
+            Self::CaseInsensitive => value.to_lowercase().contains(&filter.to_lowercase()),
+            Self::AccentInsensitive => fold_accents(value).contains(&fold_accents(filter)),
+        }
+    }
+}
+
+impl Default for FilterMode {
+    fn default() -> Self {
+        Self::CaseInsensitive
+    }
+}
+
 /// Prompt suitable for when you need the user to select one option among many.
 ///
 /// The user can select and submit the current highlighted option by pressing enter.
@@ -32,8 +80,13 @@ use crate::{
 /// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
 ///   - Prints the selected option string value by default.
 /// - **Page size**: Number of options displayed at once, 7 by default.
+/// - **Page indicator**: Whether to display the range of options currently shown, e.g. `showing 8-14 of 20`, on lists longer than the page size. Disabled by default.
 /// - **Display option indexes**: On long lists, it might be helpful to display the indexes of the options to the user. Via the `RenderConfig`, you can set the display mode of the indexes as a prefix of an option. The default configuration is `None`, to not render any index when displaying the options.
+/// - **Section headers**: Indexes of options rendered as headers of the options that follow them, which are skipped when navigating and can not be selected.
+/// - **Disabled options**: Indexes of options displayed dimmed, which are skipped when navigating and can not be selected.
 /// - **Filter function**: Function that defines if an option is displayed or not based on the current filter input.
+/// - **Filter mode**: Whether the filter input is compared to the options ignoring their case, and optionally their diacritics. Case is ignored by default.
+/// - **No matches message**: Message displayed in place of the options when the filter input matches none of them, `"No matches found"` by default.
 ///
 /// # Example
 ///
@@ -64,16 +117,58 @@ pub struct Select<'a, T> {
     /// Help message to be presented to the user.
     pub help_message: Option<&'a str>,
 
+    /// Message displayed in place of the options when none of them match
+    /// the current filter input.
+    pub no_matches_message: &'a str,
+
     /// Page size of the options displayed to the user.
     pub page_size: usize,
 
+    /// Whether to display the range of options currently shown to the user,
+    /// when there are more options than fit in a single page.
+    pub page_indicator: bool,
+
     /// Whether vim mode is enabled. When enabled, the user can
-    /// navigate through the options using hjkl.
+    /// navigate through the options using hjkl, jump to the first and last
+    /// options using g and G, and typed characters only filter the options
+    /// after pressing `/`. Pressing esc while
+    /// filtering returns to navigation.
     pub vim_mode: bool,
 
+    /// Whether typed characters jump to the next option starting with them,
+    /// as in native list boxes, instead of filtering the options.
+    pub typeahead: bool,
+
+    /// Whether the options are numbered, typing a number moving the cursor to
+    /// the option with that 1-based index.
+    pub numbered: bool,
+
+    /// Whether the prompt, including its options, is left on screen after
+    /// submission, the answer being rendered below it instead of replacing it.
+    pub keep_on_screen: bool,
+
+    /// Whether the options are displayed sorted alphabetically, instead of in
+    /// the order they were given.
+    pub sorted: bool,
+
+    /// Whether the mouse can be used to pick options, on terminals that
+    /// support it.
+    pub mouse: bool,
+
     /// Starting cursor index of the selection.
     pub starting_cursor: usize,
 
+    /// Indexes of the options rendered as section headers, which can not be
+    /// selected.
+    pub headers: &'a [usize],
+
+    /// Indexes of the options displayed as disabled, which can not be selected.
+    pub disabled: &'a [usize],
+
+    /// Hints describing the options, parallel to them, the hint of the
+    /// highlighted option being displayed in place of the help message.
+    pub option_hints: &'a [&'a str],
+
     /// Function called with the current user input to filter the provided
     /// options.
     pub filter: Filter<'a, T>,
@@ -81,6 +176,10 @@ pub struct Select<'a, T> {
     /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
     pub formatter: OptionFormatter<'a, T>,
 
+    /// Function that formats each option when displayed in the list, overriding
+    /// the string value of the option.
+    pub option_formatter: Option<OptionDisplayFormatter<'a, T>>,
+
     /// RenderConfig to apply to the rendered interface.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -143,6 +242,9 @@ where
     /// Default page size.
     pub const DEFAULT_PAGE_SIZE: usize = config::DEFAULT_PAGE_SIZE;
 
+    /// Default value of the page indicator.
+    pub const DEFAULT_PAGE_INDICATOR: bool = false;
+
     /// Default value of vim mode.
     pub const DEFAULT_VIM_MODE: bool = config::DEFAULT_VIM_MODE;
 
@@ -153,17 +255,37 @@ where
     pub const DEFAULT_HELP_MESSAGE: Option<&'a str> =
         Some("↑↓ to move, enter to select, type to filter");
 
+    /// Default help message when type-ahead is enabled.
+    pub const TYPEAHEAD_HELP_MESSAGE: Option<&'a str> =
+        Some("↑↓ to move, enter to select, type to jump");
+
+    /// Default message displayed when no options match the filter input.
+    pub const DEFAULT_NO_MATCHES_MESSAGE: &'a str = "No matches found";
+
     /// Creates a [Select] with the provided message and options, along with default configuration values.
     pub fn new(message: &'a str, options: Vec<T>) -> Self {
+        let config = get_global_config();
+
         Self {
             message,
             options,
-            help_message: Self::DEFAULT_HELP_MESSAGE,
-            page_size: Self::DEFAULT_PAGE_SIZE,
-            vim_mode: Self::DEFAULT_VIM_MODE,
+            help_message: Self::DEFAULT_HELP_MESSAGE.filter(|_| config.help_messages),
+            no_matches_message: Self::DEFAULT_NO_MATCHES_MESSAGE,
+            page_size: config.page_size,
+            page_indicator: Self::DEFAULT_PAGE_INDICATOR,
+            vim_mode: config.vim_mode,
+            typeahead: false,
+            numbered: false,
+            keep_on_screen: false,
+            sorted: false,
+            mouse: false,
             starting_cursor: Self::DEFAULT_STARTING_CURSOR,
+            headers: &[],
+            disabled: &[],
+            option_hints: &[],
             filter: Self::DEFAULT_FILTER,
             formatter: Self::DEFAULT_FORMATTER,
+            option_formatter: None,
             render_config: get_configuration(),
         }
     }
@@ -180,36 +302,189 @@ where
         self
     }
 
+    /// Sets the message displayed when no options match the filter input.
+    pub fn with_no_matches_message(mut self, message: &'a str) -> Self {
+        self.no_matches_message = message;
+        self
+    }
+
     /// Sets the page size.
     pub fn with_page_size(mut self, page_size: usize) -> Self {
         self.page_size = page_size;
         self
     }
 
+    /// Enables or disables the page indicator, e.g. `showing 8-14 of 20`,
+    /// displayed when there are more options than fit in a single page.
+    pub fn with_page_indicator(mut self, page_indicator: bool) -> Self {
+        self.page_indicator = page_indicator;
+        self
+    }
+
     /// Enables or disables vim_mode.
     pub fn with_vim_mode(mut self, vim_mode: bool) -> Self {
         self.vim_mode = vim_mode;
         self
     }
 
+    /// Enables or disables type-ahead. When enabled, typed characters jump to
+    /// the next option starting with them instead of filtering the options.
+    /// Characters typed in quick succession are matched together, e.g. "sa"
+    /// jumps to "San Diego" rather than to an option starting with "a".
+    /// The default help message is adjusted accordingly, when set.
+    ///
+    /// Back-ends unable to poll for input with a timeout, such as termion and
+    /// console, can not tell when the user stopped typing: with them, every
+    /// character starts a new search.
+    pub fn with_typeahead(mut self, typeahead: bool) -> Self {
+        let is_default_help_message = self.help_message == Self::DEFAULT_HELP_MESSAGE
+            || self.help_message == Self::TYPEAHEAD_HELP_MESSAGE;
+        if is_default_help_message {
+            self.help_message = match typeahead {
+                true => Self::TYPEAHEAD_HELP_MESSAGE,
+                false => Self::DEFAULT_HELP_MESSAGE,
+            };
+        }
+
+        self.typeahead = typeahead;
+        self
+    }
+
+    /// Enables or disables numbered mode. When enabled, typed digits move the
+    /// cursor to the option with that 1-based index instead of filtering the
+    /// options. Digits typed in quick succession are read together, e.g. "12"
+    /// jumps to the twelfth option when there are at least twelve. As with
+    /// [`with_typeahead`](Self::with_typeahead), back-ends unable to poll for
+    /// input read every digit on its own.
+    ///
+    /// Options are prefixed with their index, formatted as defined by the
+    /// index prefix of the render config, or as `1)` when it defines none.
+    ///
+    /// Numbers always refer to the position of the options in the list given
+    /// to the prompt, i.e. the index of the answer plus one, so that they
+    /// don't change when the options are filtered or sorted with
+    /// [`with_sorted`](Self::with_sorted).
+    pub fn with_numbered(mut self, numbered: bool) -> Self {
+        self.numbered = numbered;
+        self
+    }
+
+    /// Enables or disables keeping the whole interaction on screen after
+    /// submission, e.g. for transcripts. When enabled, the cursor is moved
+    /// below the options and the answer is rendered there, instead of the
+    /// options being cleared.
+    pub fn with_keep_on_screen(mut self, keep_on_screen: bool) -> Self {
+        self.keep_on_screen = keep_on_screen;
+        self
+    }
+
+    /// Enables or disables sorting the displayed options alphabetically,
+    /// ignoring case. Options are displayed in the order they were given by
+    /// default.
+    ///
+    /// Sorting only affects the display: the answer keeps the index of the
+    /// option in the original list, as do the starting cursor and the numbers
+    /// of [`with_numbered`](Self::with_numbered). Options are sorted within
+    /// the sections delimited by headers, which keep their positions.
+    pub fn with_sorted(mut self, sorted: bool) -> Self {
+        self.sorted = sorted;
+        self
+    }
+
+    /// Enables or disables mouse support. When enabled, clicking an option
+    /// highlights it, clicking the highlighted option submits it, and the
+    /// scroll wheel moves the cursor as the arrow keys do.
+    ///
+    /// The mouse is captured while the prompt runs, which usually prevents
+    /// the user from selecting text with it. Terminals without mouse
+    /// support, and the termion and console back-ends, ignore this option.
+    pub fn with_mouse(mut self, mouse: bool) -> Self {
+        self.mouse = mouse;
+        self
+    }
+
     /// Sets the filter function.
     pub fn with_filter(mut self, filter: Filter<'a, T>) -> Self {
         self.filter = filter;
         self
     }
 
+    /// Sets the filter function to one comparing the filter input to the
+    /// options' string values in the given mode, replacing any filter set.
+    pub fn with_filter_mode(mut self, mode: FilterMode) -> Self {
+        self.filter = match mode {
+            FilterMode::CaseSensitive => &|filter, _, string_value, _| {
+                FilterMode::CaseSensitive.matches(filter, string_value)
+            },
+            FilterMode::CaseInsensitive => &|filter, _, string_value, _| {
+                FilterMode::CaseInsensitive.matches(filter, string_value)
+            },
+            FilterMode::AccentInsensitive => &|filter, _, string_value, _| {
+                FilterMode::AccentInsensitive.matches(filter, string_value)
+            },
+        };
+        self
+    }
+
     /// Sets the formatter.
     pub fn with_formatter(mut self, formatter: OptionFormatter<'a, T>) -> Self {
         self.formatter = formatter;
         self
     }
 
-    /// Sets the starting cursor index.
+    /// Sets the function used to display each option in the list.
+    ///
+    /// The function receives the option and whether it is currently highlighted.
+    pub fn with_option_formatter(
+        mut self,
+        option_formatter: OptionDisplayFormatter<'a, T>,
+    ) -> Self {
+        self.option_formatter = Some(option_formatter);
+        self
+    }
+
+    /// Sets the starting cursor index, i.e. the index in the list of options
+    /// of the one highlighted when the prompt starts. When the options are
+    /// sorted, the cursor starts on that option wherever it is displayed,
+    /// which by default is the first option of the list.
     pub fn with_starting_cursor(mut self, starting_cursor: usize) -> Self {
         self.starting_cursor = starting_cursor;
         self
     }
 
+    /// Sets the indexes of the options rendered as section headers.
+    ///
+    /// Headers organize the options that follow them, up to the next header.
+    /// They are skipped when the user moves the cursor and can not be selected.
+    /// While filtering, a header is displayed as long as any of its options is.
+    pub fn with_headers(mut self, headers: &'a [usize]) -> Self {
+        self.headers = headers;
+        self
+    }
+
+    /// Sets the indexes of the options displayed as disabled, e.g. features
+    /// that are currently unavailable.
+    ///
+    /// Disabled options are rendered with the `disabled_option` style sheet of
+    /// the render config. They are skipped when the user moves the cursor and
+    /// can not be selected.
+    pub fn with_disabled(mut self, disabled: &'a [usize]) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Sets one-line hints describing the options, given in the same order as
+    /// the options themselves.
+    ///
+    /// The hint of the highlighted option is displayed in place of the help
+    /// message, and updated as the user moves the cursor. Options without a
+    /// hint, either empty or beyond the end of the hints, display the help
+    /// message as usual.
+    pub fn with_option_hints(mut self, option_hints: &'a [&'a str]) -> Self {
+        self.option_hints = option_hints;
+        self
+    }
+
     /// Sets the provided color theme to this prompt.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -231,6 +506,15 @@ where
         self.raw_prompt().map(|op| op.value)
     }
 
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns the index of the option selected by the user, relative to
+    /// the original list of options passed to the prompt.
+    pub fn prompt_index(self) -> InquireResult<usize> {
+        self.raw_prompt().map(|op| op.index)
+    }
+
     /// Parses the provided behavioral and rendering options and prompts
     /// the CLI user for input according to the defined rules.
     ///
@@ -263,7 +547,20 @@ where
         self,
         backend: &mut B,
     ) -> InquireResult<ListOption<T>> {
-        SelectPrompt::new(self)?.prompt(backend)
+        let mouse = self.mouse;
+        let prompt = SelectPrompt::new(self)?;
+
+        if mouse {
+            backend.enable_mouse()?;
+        }
+
+        let result = prompt.prompt(backend);
+
+        if mouse {
+            backend.disable_mouse()?;
+        }
+
+        result
     }
 }
 
@@ -272,13 +569,26 @@ struct SelectPrompt<'a, T> {
     options: Vec<T>,
     string_options: Vec<String>,
     filtered_options: Vec<usize>,
+    headers: BTreeSet<usize>,
+    disabled: BTreeSet<usize>,
+    option_hints: &'a [&'a str],
     help_message: Option<&'a str>,
+    no_matches_message: &'a str,
     vim_mode: bool,
+    filtering: bool,
+    typeahead: bool,
+    typeahead_buffer: String,
+    numbered: bool,
+    number_buffer: String,
+    keep_on_screen: bool,
+    display_order: Vec<usize>,
     cursor_index: usize,
     page_size: usize,
+    page_indicator: bool,
     input: Input,
     filter: Filter<'a, T>,
     formatter: OptionFormatter<'a, T>,
+    option_formatter: Option<OptionDisplayFormatter<'a, T>>,
 }
 
 impl<'a, T> SelectPrompt<'a, T>
@@ -300,34 +610,99 @@ where
             )));
         }
 
-        let string_options = so.options.iter().map(T::to_string).collect();
-        let filtered_options = (0..so.options.len()).collect();
+        let headers: BTreeSet<usize> = so.headers.iter().cloned().collect();
+        let disabled: BTreeSet<usize> = so.disabled.iter().cloned().collect();
+        if (0..so.options.len()).all(|i| headers.contains(&i) || disabled.contains(&i)) {
+            return Err(InquireError::InvalidConfiguration(
+                "Available options can not all be headers or disabled".into(),
+            ));
+        }
+
+        let string_options: Vec<String> = so.options.iter().map(T::to_string).collect();
+
+        let mut display_order: Vec<usize> = (0..so.options.len()).collect();
+        if so.sorted {
+            for section in display_order.split_mut(|i| headers.contains(i)) {
+                section.sort_by_cached_key(|i| string_options[*i].to_lowercase());
+            }
+        }
+        let filtered_options = display_order.clone();
+        let cursor_index = display_order
+            .iter()
+            .position(|i| *i == so.starting_cursor)
+            .unwrap_or_default();
 
-        Ok(Self {
+        let mut prompt = Self {
             message: so.message,
             options: so.options,
             string_options,
             filtered_options,
+            headers,
+            disabled,
+            option_hints: so.option_hints,
             help_message: so.help_message,
+            no_matches_message: so.no_matches_message,
             vim_mode: so.vim_mode,
-            cursor_index: so.starting_cursor,
+            filtering: !so.vim_mode,
+            typeahead: so.typeahead,
+            typeahead_buffer: String::new(),
+            numbered: so.numbered,
+            number_buffer: String::new(),
+            keep_on_screen: so.keep_on_screen,
+            display_order,
+            cursor_index,
             page_size: so.page_size,
+            page_indicator: so.page_indicator,
             input: Input::new(),
             filter: so.filter,
             formatter: so.formatter,
-        })
+            option_formatter: so.option_formatter,
+        };
+        prompt.skip_unselectable(true, false);
+
+        Ok(prompt)
     }
 
     fn filter_options(&self) -> Vec<usize> {
-        self.options
-            .iter()
-            .enumerate()
-            .filter_map(|(i, opt)| match self.input.content() {
-                val if val.is_empty() => Some(i),
-                val if (self.filter)(val, opt, self.string_options.get(i).unwrap(), i) => Some(i),
-                _ => None,
-            })
-            .collect()
+        let val = self.input.content();
+        if val.is_empty() {
+            return self.display_order.clone();
+        }
+
+        let mut filtered = vec![];
+        let mut pending_header = None;
+
+        for &i in &self.display_order {
+            let opt = self.options.get(i).unwrap();
+            if self.headers.contains(&i) {
+                pending_header = Some(i);
+            } else if (self.filter)(val, opt, self.string_options.get(i).unwrap(), i) {
+                filtered.extend(pending_header.take());
+                filtered.push(i);
+            }
+        }
+
+        filtered
+    }
+
+    fn is_selectable(&self, cursor_index: usize) -> bool {
+        match self.filtered_options.get(cursor_index) {
+            Some(index) => !self.headers.contains(index) && !self.disabled.contains(index),
+            None => false,
+        }
+    }
+
+    /// Moves the cursor to the closest option that is neither a header nor
+    /// disabled, looking first in the direction the cursor was moving.
+    fn skip_unselectable(&mut self, forward: bool, wrap: bool) {
+        let len = self.filtered_options.len();
+        let closest = closest_selectable(self.cursor_index, len, forward, wrap, |i| {
+            self.is_selectable(i)
+        });
+
+        if let Some(index) = closest {
+            self.cursor_index = index;
+        }
     }
 
     fn move_cursor_up(&mut self, qty: usize, wrap: bool) {
@@ -340,6 +715,8 @@ where
         } else {
             self.cursor_index = self.cursor_index.saturating_sub(qty);
         }
+
+        self.skip_unselectable(false, wrap);
     }
 
     fn move_cursor_down(&mut self, qty: usize, wrap: bool) {
@@ -354,19 +731,116 @@ where
                 self.filtered_options.len().saturating_sub(1)
             }
         }
+
+        self.skip_unselectable(true, wrap);
+    }
+
+    /// Moves the cursor to the next option starting with the characters typed
+    /// so far. A repeated single character cycles through the options starting
+    /// with it.
+    fn typeahead(&mut self, c: char) {
+        let cycling = self.typeahead_buffer.chars().all(|typed| typed == c);
+        if !cycling || self.typeahead_buffer.is_empty() {
+            self.typeahead_buffer.push(c);
+        }
+
+        let prefix = match cycling {
+            true => c.to_lowercase().collect(),
+            false => self.typeahead_buffer.to_lowercase(),
+        };
+        let len = self.filtered_options.len();
+        let start = match cycling {
+            true => self.cursor_index + 1,
+            false => self.cursor_index,
+        };
+
+        let next = (start..start + len).map(|i| i % len).find(|i| {
+            let value = &self.string_options[self.filtered_options[*i]];
+            self.is_selectable(*i) && value.to_lowercase().starts_with(&prefix)
+        });
+
+        if let Some(index) = next {
+            self.cursor_index = index;
+        }
+    }
+
+    fn jump_to_number(&mut self, digit: char) {
+        self.number_buffer.push(digit);
+
+        let number = match self.number_buffer.parse::<usize>() {
+            Ok(number) if number > 0 && number <= self.options.len() => number,
+            _ => {
+                // start over from the digit, which may be the first of a new number
+                self.number_buffer = digit.to_string();
+                match digit.to_digit(10) {
+                    Some(number) => number as usize,
+                    None => return,
+                }
+            }
+        };
+
+        let index = number.wrapping_sub(1);
+        let position = self.filtered_options.iter().position(|i| *i == index);
+
+        if let Some(position) = position.filter(|p| self.is_selectable(*p)) {
+            self.cursor_index = position;
+        }
+    }
+
+    /// Moves the cursor to the clicked option, returning whether it was
+    /// already highlighted, i.e. whether the click submits it.
+    fn click_option(&mut self, index: usize) -> bool {
+        let position = self.filtered_options.iter().position(|i| *i == index);
+
+        match position.filter(|p| self.is_selectable(*p)) {
+            Some(position) if position == self.cursor_index => true,
+            Some(position) => {
+                self.cursor_index = position;
+                false
+            }
+            None => false,
+        }
     }
 
     fn on_change(&mut self, key: Key) {
+        if !matches!(key, Key::Char(c, _) if c.is_ascii_digit()) {
+            self.number_buffer.clear();
+        }
+
         match key {
             Key::Up(KeyModifiers::NONE) => self.move_cursor_up(1, true),
-            Key::Char('k', KeyModifiers::NONE) if self.vim_mode => self.move_cursor_up(1, true),
+            Key::Char('k', KeyModifiers::NONE) if !self.filtering => self.move_cursor_up(1, true),
             Key::PageUp => self.move_cursor_up(self.page_size, false),
             Key::Home => self.move_cursor_up(usize::MAX, false),
+            Key::Char('g', KeyModifiers::NONE) if !self.filtering => {
+                self.move_cursor_up(usize::MAX, false)
+            }
 
             Key::Down(KeyModifiers::NONE) => self.move_cursor_down(1, true),
-            Key::Char('j', KeyModifiers::NONE) if self.vim_mode => self.move_cursor_down(1, true),
+            Key::Char('j', KeyModifiers::NONE) if !self.filtering => self.move_cursor_down(1, true),
             Key::PageDown => self.move_cursor_down(self.page_size, false),
             Key::End => self.move_cursor_down(usize::MAX, false),
+            Key::Char('G', m)
+                if !self.filtering && !m.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) =>
+            {
+                self.move_cursor_down(usize::MAX, false)
+            }
+
+            Key::Char('/', KeyModifiers::NONE) if !self.filtering => self.filtering = true,
+            Key::Char(c, KeyModifiers::NONE)
+                if self.numbered && c.is_ascii_digit() && !(self.vim_mode && self.filtering) =>
+            {
+                self.typeahead_buffer.clear();
+                self.jump_to_number(c)
+            }
+            Key::Char(c, m)
+                if self.typeahead
+                    && !(self.vim_mode && self.filtering)
+                    && !m.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) =>
+            {
+                self.typeahead(c)
+            }
+            Key::Char(..) if !self.filtering => {}
 
             key => {
                 let dirty = self.input.handle_key(key);
@@ -377,13 +851,14 @@ where
                         self.cursor_index = options.len().saturating_sub(1);
                     }
                     self.filtered_options = options;
+                    self.skip_unselectable(true, false);
                 }
             }
         };
     }
 
     fn has_answer_highlighted(&mut self) -> bool {
-        self.filtered_options.get(self.cursor_index).is_some()
+        self.is_selectable(self.cursor_index)
     }
 
     fn get_final_answer(&mut self) -> ListOption<T> {
@@ -396,6 +871,12 @@ where
         ListOption::new(index, value)
     }
 
+    /// Whether the range of options displayed should be rendered below them,
+    /// which is only the case when they do not fit in a single page.
+    fn shows_page_indicator(&self) -> bool {
+        self.page_indicator && self.filtered_options.len() > self.page_size
+    }
+
     fn render<B: SelectBackend>(&mut self, backend: &mut B) -> InquireResult<()> {
         let prompt = &self.message;
 
@@ -407,14 +888,41 @@ where
             .filtered_options
             .iter()
             .cloned()
-            .map(|i| ListOption::new(i, self.options.get(i).unwrap()))
-            .collect::<Vec<ListOption<&T>>>();
+            .enumerate()
+            .map(|(pos, i)| {
+                let value = match self.option_formatter {
+                    Some(formatter) => Cow::Owned(formatter(
+                        ListOption::new(i, self.options.get(i).unwrap()),
+                        pos == self.cursor_index,
+                    )),
+                    None => Cow::Borrowed(self.string_options.get(i).unwrap().as_str()),
+                };
+                ListOption::new(i, value)
+            })
+            .collect::<Vec<ListOption<Cow<'_, str>>>>();
 
         let page = paginate(self.page_size, &choices, self.cursor_index);
+        let page_indicator = self.shows_page_indicator();
 
-        backend.render_options(page)?;
+        if choices.is_empty() {
+            backend.render_no_matches_message(self.no_matches_message)?;
+        } else {
+            backend.render_options(
+                page,
+                &self.headers,
+                &self.disabled,
+                self.numbered,
+                page_indicator,
+            )?;
+        }
+
+        let hint = self
+            .filtered_options
+            .get(self.cursor_index)
+            .and_then(|i| self.option_hints.get(*i))
+            .filter(|hint| !hint.is_empty());
 
-        if let Some(help_message) = self.help_message {
+        if let Some(help_message) = hint.copied().or(self.help_message) {
             backend.render_help_message(help_message)?;
         }
 
@@ -427,15 +935,39 @@ where
         loop {
             self.render(backend)?;
 
-            let key = backend.read_key()?;
+            let buffering = !self.typeahead_buffer.is_empty() || !self.number_buffer.is_empty();
+            let key = match buffering {
+                false => backend.read_key()?,
+                true => match backend.read_key_timeout(TYPEAHEAD_RESET)? {
+                    Some(key) => key,
+                    None => {
+                        self.typeahead_buffer.clear();
+                        self.number_buffer.clear();
+                        continue;
+                    }
+                },
+            };
+            // terminals unable to poll never time out, so each key starts over
+            if !matches!(key, Key::Char(..)) || !backend.can_poll() {
+                self.typeahead_buffer.clear();
+                self.number_buffer.clear();
+            }
 
             match key {
                 Key::Interrupt => interrupt_prompt!(),
+                Key::Cancel if self.vim_mode && self.filtering => self.filtering = false,
                 Key::Cancel => cancel_prompt!(backend, self.message),
-                Key::Submit => match self.has_answer_highlighted() {
-                    true => break,
-                    false => {}
-                },
+                Key::Submit => {
+                    if self.has_answer_highlighted() {
+                        break;
+                    }
+                }
+                Key::Click { row, .. } => {
+                    let index = backend.option_at_row(row);
+                    if index.map_or(false, |index| self.click_option(index)) {
+                        break;
+                    }
+                }
                 key => self.on_change(key),
             }
         }
@@ -443,22 +975,67 @@ where
         let final_answer = self.get_final_answer();
         let formatted = (self.formatter)(final_answer.as_ref());
 
-        finish_prompt_with_answer!(backend, self.message, &formatted, final_answer);
+        finish_prompt_with_answer!(
+            backend,
+            self.message,
+            &formatted,
+            final_answer,
+            self.keep_on_screen
+        );
     }
 }
 
 #[cfg(test)]
 #[cfg(feature = "crossterm")]
 mod test {
+    use super::SelectPrompt;
     use crate::{
+        error::InquireError,
         formatter::OptionFormatter,
         list_option::ListOption,
         terminal::crossterm::CrosstermTerminal,
-        ui::{Backend, RenderConfig},
-        Select,
+        ui::{Backend, Color, IndexPrefix, Key, KeyModifiers, RenderConfig, StyleSheet, Styled},
+        FilterMode, Select,
     };
     use crossterm::event::{KeyCode, KeyEvent};
 
+    macro_rules! text_to_events {
+        ($text:expr) => {{
+            $text.chars().map(KeyCode::Char)
+        }};
+    }
+
+    macro_rules! select_test {
+        ($name:ident,$input:expr,$output:expr,$prompt:expr) => {
+            select_test! {$name, $input, $output, $prompt, |_rendered| {}}
+        };
+
+        ($name:ident,$input:expr,$output:expr,$prompt:expr,|$rendered:ident| $check:block) => {
+            select_test! {$name, $input, $output, $prompt, RenderConfig::empty(), |$rendered| $check}
+        };
+
+        ($name:ident,$input:expr,$output:expr,$prompt:expr,$render_config:expr,|$rendered:ident| $check:block) => {
+            #[test]
+            fn $name() {
+                let read: Vec<KeyEvent> = $input.into_iter().map(KeyEvent::from).collect();
+                let mut read = read.into_iter();
+
+                let mut write: Vec<u8> = Vec::new();
+                {
+                    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+                    let mut backend = Backend::new(terminal, $render_config).unwrap();
+
+                    let ans = $prompt.prompt_with_backend(&mut backend).unwrap();
+
+                    assert_eq!($output, ans);
+                }
+
+                let $rendered = String::from_utf8(write).unwrap();
+                $check
+            }
+        };
+    }
+
     #[test]
     /// Tests that a closure that actually closes on a variable can be used
     /// as a Select formatter.
@@ -467,7 +1044,7 @@ mod test {
             .into_iter()
             .map(KeyEvent::from)
             .collect();
-        let mut read = read.iter();
+        let mut read = read.into_iter();
 
         let formatted = String::from("Thanks!");
         let formatter: OptionFormatter<i32> = &|_| formatted.clone();
@@ -500,7 +1077,7 @@ mod test {
         .map(|c| KeyEvent::from(*c))
         .collect();
 
-        let mut read = read.iter();
+        let mut read = read.into_iter();
 
         let options = vec![1, 2, 3];
 
@@ -531,7 +1108,7 @@ mod test {
         .map(|c| KeyEvent::from(*c))
         .collect();
 
-        let mut read = read.iter();
+        let mut read = read.into_iter();
 
         let options = vec![1, 2, 3];
 
@@ -545,4 +1122,785 @@ mod test {
 
         assert_eq!(ListOption::new(0, 1), ans);
     }
+
+    select_test!(
+        down_twice_and_select,
+        vec![KeyCode::Down, KeyCode::Down, KeyCode::Enter],
+        ListOption::new(2, "Strawberry"),
+        Select::new("Question", vec!["Banana", "Apple", "Strawberry", "Grapes"])
+    );
+
+    select_test!(
+        up_arrow_wraps_to_last_option,
+        vec![KeyCode::Up, KeyCode::Enter],
+        ListOption::new(3, "Grapes"),
+        Select::new("Question", vec!["Banana", "Apple", "Strawberry", "Grapes"])
+    );
+
+    select_test!(
+        down_arrow_wraps_to_first_option,
+        vec![KeyCode::Down, KeyCode::Down, KeyCode::Enter],
+        ListOption::new(0, "Banana"),
+        Select::new("Question", vec!["Banana", "Apple"])
+    );
+
+    select_test!(
+        navigation_past_the_first_page,
+        vec![KeyCode::Down; 5]
+            .into_iter()
+            .chain(Some(KeyCode::Enter)),
+        ListOption::new(5, 5),
+        Select::new("Question", (0..20).collect::<Vec<i32>>()).with_page_size(3)
+    );
+
+    fn cities() -> Vec<&'static str> {
+        vec![
+            "New York",
+            "Sacramento",
+            "Kansas",
+            "Mesa",
+            "Phoenix",
+            "Philadelphia",
+            "San Antonio",
+            "San Diego",
+            "Dallas",
+            "San Francisco",
+            "Austin",
+            "Jacksonville",
+        ]
+    }
+
+    select_test!(
+        filter_and_navigate_reduced_set,
+        vec![
+            KeyCode::Char('S'),
+            KeyCode::Char('a'),
+            KeyCode::Down,
+            KeyCode::Enter,
+        ],
+        ListOption::new(2, "Kansas"),
+        Select::new("Question", cities())
+    );
+
+    select_test!(
+        filter_clamps_highlighted_option,
+        vec![
+            KeyCode::End,
+            KeyCode::Char('m'),
+            KeyCode::Char('e'),
+            KeyCode::Char('s'),
+            KeyCode::Enter,
+        ],
+        ListOption::new(3, "Mesa"),
+        Select::new("Question", cities())
+    );
+
+    select_test!(
+        backspace_widens_filter,
+        vec![
+            KeyCode::Char('s'),
+            KeyCode::Char('a'),
+            KeyCode::Char('n'),
+            KeyCode::Char('x'),
+            KeyCode::Backspace,
+            KeyCode::Down,
+            KeyCode::Down,
+            KeyCode::Enter,
+        ],
+        ListOption::new(9, "San Francisco"),
+        Select::new("Question", cities())
+    );
+
+    select_test!(
+        custom_highlighted_option_prefix_is_rendered,
+        vec![KeyCode::Down, KeyCode::Enter],
+        ListOption::new(1, "Apple"),
+        Select::new("Question", vec!["Banana", "Apple", "Strawberry"]),
+        RenderConfig::empty().with_highlighted_option_prefix(Styled::new("->")),
+        |output| {
+            assert!(output.contains("-> Banana"));
+            assert!(output.contains("-> Apple"));
+            assert!(!output.contains("> Strawberry"));
+        }
+    );
+
+    select_test!(
+        duplicate_options_return_original_index,
+        vec![KeyCode::Down, KeyCode::Down, KeyCode::Enter],
+        ListOption::new(2, "Pear"),
+        Select::new("Question", vec!["Pear", "Apple", "Pear", "Apple"])
+    );
+
+    select_test!(
+        filtered_duplicate_options_return_original_index,
+        text_to_events!("app").chain(vec![KeyCode::Down, KeyCode::Enter]),
+        ListOption::new(3, "Apple"),
+        Select::new("Question", vec!["Pear", "Apple", "Pear", "Apple"])
+    );
+
+    select_test!(
+        starting_cursor_beyond_first_page_is_rendered,
+        vec![KeyCode::Enter],
+        ListOption::new(9, String::from("Option 09")),
+        Select::new(
+            "Question",
+            (0..12).map(|i| format!("Option {:02}", i)).collect()
+        )
+        .with_page_size(5)
+        .with_starting_cursor(9),
+        |output| {
+            assert!(output.contains("> Option 09"));
+            for i in 7..12 {
+                assert!(output.contains(&format!("Option {:02}", i)));
+            }
+            for i in 0..7 {
+                assert!(!output.contains(&format!("Option {:02}", i)));
+            }
+        }
+    );
+
+    select_test!(
+        page_indicator_is_rendered_on_long_lists,
+        vec![KeyCode::Down; 15]
+            .into_iter()
+            .chain(Some(KeyCode::Enter)),
+        ListOption::new(15, 16),
+        Select::new("Question", (1..=30).collect::<Vec<usize>>())
+            .with_page_size(10)
+            .with_page_indicator(true),
+        |output| {
+            assert!(output.contains("showing 1-10 of 30"));
+            assert!(output.contains("showing 11-20 of 30"));
+        }
+    );
+
+    select_test!(
+        page_indicator_is_not_rendered_on_short_lists,
+        vec![KeyCode::Enter],
+        ListOption::new(0, 1),
+        Select::new("Question", (1..=10).collect::<Vec<usize>>())
+            .with_page_size(10)
+            .with_page_indicator(true),
+        |output| {
+            assert!(!output.contains("showing"));
+        }
+    );
+
+    #[test]
+    fn no_matches_message_is_rendered_and_enter_does_not_submit() {
+        let read: Vec<KeyEvent> = text_to_events!("xyz")
+            .chain(vec![KeyCode::Enter, KeyCode::Esc])
+            .map(KeyEvent::from)
+            .collect();
+        let mut read = read.into_iter();
+
+        let options = vec!["Banana", "Apple", "Strawberry"];
+
+        let mut write: Vec<u8> = Vec::new();
+        {
+            let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+            let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+            let ans = Select::new("Question", options)
+                .with_no_matches_message("Nothing to see here")
+                .prompt_with_backend(&mut backend);
+
+            assert!(matches!(ans, Err(InquireError::OperationCanceled)));
+        }
+
+        let output = String::from_utf8(write).unwrap();
+        assert!(output.contains("Nothing to see here"));
+    }
+
+    select_test!(
+        option_formatter_is_applied_to_displayed_options,
+        vec![KeyCode::Down, KeyCode::Enter],
+        ListOption::new(1, "Apple"),
+        Select::new("Question", vec!["Banana", "Apple", "Strawberry"]).with_option_formatter(
+            &|option, selected| match selected {
+                true => format!("> {}", option.value),
+                false => format!("- {}", option.value),
+            }
+        ),
+        |output| {
+            let last_frame = output.rsplit("Question").nth(1).unwrap();
+            assert!(last_frame.contains("- Banana"));
+            assert!(!last_frame.contains("> Banana"));
+            assert!(last_frame.contains("> Apple"));
+            assert!(last_frame.contains("- Strawberry"));
+        }
+    );
+
+    const MIXED_OPTIONS: [&str; 5] = ["Café", "CAFETERIA", "cafe au lait", "CAFÉ NOIR", "Tea"];
+
+    fn matching_options(mode: FilterMode, query: &str) -> Vec<&'static str> {
+        let select = Select::new("Question", MIXED_OPTIONS.to_vec()).with_filter_mode(mode);
+
+        MIXED_OPTIONS
+            .iter()
+            .enumerate()
+            .filter(|(i, option)| (select.filter)(query, option, option, *i))
+            .map(|(_, option)| *option)
+            .collect()
+    }
+
+    #[test]
+    fn case_sensitive_filter_mode() {
+        assert_eq!(
+            vec!["CAFETERIA"],
+            matching_options(FilterMode::CaseSensitive, "CAFE")
+        );
+        assert_eq!(
+            vec!["Café"],
+            matching_options(FilterMode::CaseSensitive, "Caf")
+        );
+    }
+
+    #[test]
+    fn case_insensitive_filter_mode() {
+        assert_eq!(
+            vec!["CAFETERIA", "cafe au lait"],
+            matching_options(FilterMode::CaseInsensitive, "CAFE")
+        );
+        assert_eq!(
+            vec!["Café", "CAFÉ NOIR"],
+            matching_options(FilterMode::CaseInsensitive, "café")
+        );
+    }
+
+    #[test]
+    fn accent_insensitive_filter_mode() {
+        let all_cafes = vec!["Café", "CAFETERIA", "cafe au lait", "CAFÉ NOIR"];
+
+        assert_eq!(
+            all_cafes,
+            matching_options(FilterMode::AccentInsensitive, "CAFE")
+        );
+        assert_eq!(
+            all_cafes,
+            matching_options(FilterMode::AccentInsensitive, "café")
+        );
+    }
+
+    select_test!(
+        default_filter_ignores_case,
+        text_to_events!("CAFE").chain(vec![KeyCode::Down, KeyCode::Enter]),
+        ListOption::new(2, "cafe au lait"),
+        Select::new("Question", MIXED_OPTIONS.to_vec())
+    );
+
+    select_test!(
+        custom_filter_replaces_substring_filter,
+        vec![
+            KeyCode::Char('z'),
+            KeyCode::Down,
+            KeyCode::Down,
+            KeyCode::Enter,
+        ],
+        ListOption::new(4, "e"),
+        Select::new("Question", vec!["a", "b", "c", "d", "e"])
+            .with_filter(&|_, _, _, i| i % 2 == 0)
+    );
+
+    const SECTIONED_OPTIONS: [&str; 6] =
+        ["Fruits", "Apple", "Banana", "Vegetables", "Carrot", "Leek"];
+    const SECTION_HEADERS: [usize; 2] = [0, 3];
+
+    fn sectioned() -> Select<'static, &'static str> {
+        Select::new("Question", SECTIONED_OPTIONS.to_vec()).with_headers(&SECTION_HEADERS)
+    }
+
+    select_test!(
+        cursor_starts_past_leading_header,
+        vec![KeyCode::Enter],
+        ListOption::new(1, "Apple"),
+        sectioned()
+    );
+
+    select_test!(
+        moving_down_skips_headers,
+        vec![KeyCode::Down, KeyCode::Down, KeyCode::Enter],
+        ListOption::new(4, "Carrot"),
+        sectioned()
+    );
+
+    select_test!(
+        moving_up_skips_headers,
+        vec![KeyCode::Down, KeyCode::Down, KeyCode::Up, KeyCode::Enter],
+        ListOption::new(2, "Banana"),
+        sectioned()
+    );
+
+    select_test!(
+        moving_up_from_the_first_option_wraps_past_headers,
+        vec![KeyCode::Up, KeyCode::Enter],
+        ListOption::new(5, "Leek"),
+        sectioned()
+    );
+
+    select_test!(
+        home_skips_the_leading_header,
+        vec![KeyCode::End, KeyCode::Home, KeyCode::Enter],
+        ListOption::new(1, "Apple"),
+        sectioned()
+    );
+
+    #[test]
+    fn headers_are_never_selected() {
+        for key in [Key::Down(KeyModifiers::NONE), Key::Up(KeyModifiers::NONE)] {
+            let mut prompt = SelectPrompt::new(sectioned()).unwrap();
+
+            for presses in 1..12 {
+                prompt.on_change(key);
+
+                assert!(
+                    !SECTION_HEADERS.contains(&prompt.filtered_options[prompt.cursor_index]),
+                    "header selected after {} presses",
+                    presses
+                );
+            }
+        }
+    }
+
+    select_test!(
+        filtering_keeps_headers_of_matching_options,
+        vec![KeyCode::Char('e'), KeyCode::Char('e'), KeyCode::Enter],
+        ListOption::new(5, "Leek"),
+        sectioned()
+    );
+
+    #[test]
+    fn only_headers_is_invalid_configuration() {
+        let select = Select::new("Question", vec!["Fruits", "Vegetables"]).with_headers(&[0, 1]);
+
+        let prompt = SelectPrompt::new(select);
+
+        assert!(matches!(prompt, Err(InquireError::InvalidConfiguration(_))));
+    }
+
+    select_test!(
+        headers_are_rendered_without_cursor,
+        vec![KeyCode::Enter],
+        ListOption::new(1, "Apple"),
+        sectioned(),
+        |output| {
+            assert!(output.contains("  Fruits\r\n> Apple\r\n  Banana\r\n  Vegetables\r\n"));
+        }
+    );
+
+    fn with_disabled_middle() -> Select<'static, &'static str> {
+        Select::new("Question", vec!["a", "b", "c"]).with_disabled(&[1])
+    }
+
+    select_test!(
+        moving_down_jumps_over_disabled_option,
+        vec![KeyCode::Down, KeyCode::Enter],
+        ListOption::new(2, "c"),
+        with_disabled_middle()
+    );
+
+    select_test!(
+        moving_up_jumps_over_disabled_option,
+        vec![KeyCode::Down, KeyCode::Up, KeyCode::Enter],
+        ListOption::new(0, "a"),
+        with_disabled_middle()
+    );
+
+    fn with_disabled_last() -> Select<'static, String> {
+        Select::new(
+            "Question",
+            (0..20).map(|i| format!("Option {}", i)).collect(),
+        )
+        .with_disabled(&[19])
+    }
+
+    select_test!(
+        end_highlights_the_last_selectable_option,
+        vec![KeyCode::End, KeyCode::Enter],
+        ListOption::new(18, String::from("Option 18")),
+        with_disabled_last(),
+        |output| {
+            assert!(output.contains("> Option 18\r\n  Option 19\r\n"));
+        }
+    );
+
+    select_test!(
+        vim_mode_jumps_to_the_last_option,
+        vec![KeyCode::Char('G'), KeyCode::Enter],
+        ListOption::new(18, String::from("Option 18")),
+        with_disabled_last().with_vim_mode(true)
+    );
+
+    select_test!(
+        vim_mode_jumps_to_the_first_option,
+        vec![KeyCode::Char('G'), KeyCode::Char('g'), KeyCode::Enter],
+        ListOption::new(0, String::from("Option 0")),
+        with_disabled_last().with_vim_mode(true)
+    );
+
+    select_test!(
+        disabled_option_can_not_be_submitted,
+        vec![
+            KeyCode::Char('b'),
+            KeyCode::Enter,
+            KeyCode::Backspace,
+            KeyCode::Enter,
+        ],
+        ListOption::new(0, "a"),
+        with_disabled_middle()
+    );
+
+    #[test]
+    fn disabled_options_are_rendered_with_their_style_sheet() {
+        let mut read = std::iter::once(KeyEvent::from(KeyCode::Enter));
+
+        let mut write: Vec<u8> = Vec::new();
+        {
+            let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read).with_styles(true);
+            let render_config = RenderConfig::empty()
+                .with_disabled_option(StyleSheet::new().with_fg(Color::DarkGrey));
+            let mut backend = Backend::new(terminal, render_config).unwrap();
+
+            with_disabled_middle()
+                .prompt_with_backend(&mut backend)
+                .unwrap();
+        }
+        let output = String::from_utf8(write).unwrap();
+
+        assert!(output.contains("  \x1b[38;5;8mb\x1b[39m\r\n"));
+        assert!(output.contains("  c\r\n"));
+    }
+
+    select_test!(
+        vim_mode_navigates_with_j_and_k,
+        vec![
+            KeyCode::Char('j'),
+            KeyCode::Char('j'),
+            KeyCode::Char('k'),
+            KeyCode::Enter,
+        ],
+        ListOption::new(1, "Sacramento"),
+        Select::new("Question", cities()).with_vim_mode(true)
+    );
+
+    select_test!(
+        vim_mode_accepts_arrow_keys,
+        vec![KeyCode::Down, KeyCode::Down, KeyCode::Up, KeyCode::Enter],
+        ListOption::new(1, "Sacramento"),
+        Select::new("Question", cities()).with_vim_mode(true)
+    );
+
+    select_test!(
+        vim_mode_ignores_typing_while_navigating,
+        vec![KeyCode::Char('x'), KeyCode::Char('S'), KeyCode::Enter],
+        ListOption::new(0, "New York"),
+        Select::new("Question", cities()).with_vim_mode(true)
+    );
+
+    select_test!(
+        vim_mode_filters_after_slash,
+        vec![KeyCode::Char('/'), KeyCode::Char('j'), KeyCode::Enter],
+        ListOption::new(11, "Jacksonville"),
+        Select::new("Question", cities()).with_vim_mode(true)
+    );
+
+    select_test!(
+        vim_mode_esc_returns_to_navigation,
+        vec![
+            KeyCode::Char('/'),
+            KeyCode::Char('S'),
+            KeyCode::Char('a'),
+            KeyCode::Esc,
+            KeyCode::Char('j'),
+            KeyCode::Enter,
+        ],
+        ListOption::new(2, "Kansas"),
+        Select::new("Question", cities()).with_vim_mode(true)
+    );
+
+    select_test!(
+        typeahead_jumps_to_matching_option,
+        vec![KeyCode::Char('d'), KeyCode::Enter],
+        ListOption::new(8, "Dallas"),
+        Select::new("Question", cities()).with_typeahead(true)
+    );
+
+    select_test!(
+        typeahead_repeated_letter_cycles_through_matches,
+        vec![
+            KeyCode::Char('s'),
+            KeyCode::Char('s'),
+            KeyCode::Char('s'),
+            KeyCode::Enter,
+        ],
+        ListOption::new(7, "San Diego"),
+        Select::new("Question", cities()).with_typeahead(true)
+    );
+
+    select_test!(
+        typeahead_matches_characters_typed_together,
+        vec![
+            KeyCode::Char('p'),
+            KeyCode::Char('h'),
+            KeyCode::Char('i'),
+            KeyCode::Enter,
+        ],
+        ListOption::new(5, "Philadelphia"),
+        Select::new("Question", cities()).with_typeahead(true)
+    );
+
+    select_test!(
+        typeahead_buffer_is_reset_by_other_keys,
+        vec![
+            KeyCode::Char('s'),
+            KeyCode::Down,
+            KeyCode::Char('a'),
+            KeyCode::Enter,
+        ],
+        ListOption::new(10, "Austin"),
+        Select::new("Question", cities()).with_typeahead(true)
+    );
+
+    select_test!(
+        typeahead_does_not_filter,
+        vec![KeyCode::Char('x'), KeyCode::Down, KeyCode::Enter],
+        ListOption::new(1, "Sacramento"),
+        Select::new("Question", cities()).with_typeahead(true)
+    );
+
+    select_test!(
+        numbered_digit_jumps_to_option,
+        vec![KeyCode::Char('2'), KeyCode::Enter],
+        ListOption::new(1, "Sacramento"),
+        Select::new("Question", cities()).with_numbered(true),
+        |output| {
+            assert!(output.contains("2) Sacramento"));
+        }
+    );
+
+    select_test!(
+        numbered_options_use_the_index_prefix_of_the_render_config,
+        vec![KeyCode::Char('2'), KeyCode::Enter],
+        ListOption::new(1, "Sacramento"),
+        Select::new("Question", cities()).with_numbered(true),
+        RenderConfig::empty().with_option_index_prefix(IndexPrefix::ZeroPadded),
+        |output| {
+            assert!(output.contains("02) Sacramento"));
+        }
+    );
+
+    select_test!(
+        numbered_digits_typed_together_form_a_number,
+        vec![KeyCode::Char('1'), KeyCode::Char('2'), KeyCode::Enter],
+        ListOption::new(11, "Jacksonville"),
+        Select::new("Question", cities()).with_numbered(true)
+    );
+
+    select_test!(
+        numbered_out_of_range_number_starts_over,
+        vec![KeyCode::Char('1'), KeyCode::Char('5'), KeyCode::Enter],
+        ListOption::new(4, "Phoenix"),
+        Select::new("Question", cities()).with_numbered(true)
+    );
+
+    fn prompt_without_polling(
+        prompt: Select<'static, &'static str>,
+        keys: Vec<KeyCode>,
+    ) -> ListOption<&'static str> {
+        let read: Vec<KeyEvent> = keys.into_iter().map(KeyEvent::from).collect();
+        let mut read = read.into_iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read).with_polling(false);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        prompt.prompt_with_backend(&mut backend).unwrap()
+    }
+
+    #[test]
+    fn typeahead_starts_over_on_every_key_when_the_terminal_can_not_poll() {
+        let prompt = Select::new("Question", cities()).with_typeahead(true);
+        let keys = vec![KeyCode::Char('s'), KeyCode::Char('a'), KeyCode::Enter];
+
+        assert_eq!(
+            ListOption::new(10, "Austin"),
+            prompt_without_polling(prompt, keys)
+        );
+    }
+
+    #[test]
+    fn numbered_digits_are_read_on_their_own_when_the_terminal_can_not_poll() {
+        let prompt = Select::new("Question", cities()).with_numbered(true);
+        let keys = vec![KeyCode::Char('1'), KeyCode::Char('2'), KeyCode::Enter];
+
+        assert_eq!(
+            ListOption::new(1, "Sacramento"),
+            prompt_without_polling(prompt, keys)
+        );
+    }
+
+    #[derive(Debug, PartialEq, Eq)]
+    enum Fruit {
+        Banana,
+        Apple,
+        Strawberry,
+    }
+
+    impl std::fmt::Display for Fruit {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            let name = match self {
+                Fruit::Banana => "Banana",
+                Fruit::Apple => "Apple",
+                Fruit::Strawberry => "Strawberry",
+            };
+            write!(f, "{}", name)
+        }
+    }
+
+    select_test!(
+        typed_options_return_selected_value,
+        vec![KeyCode::Char('s'), KeyCode::Char('t'), KeyCode::Enter],
+        ListOption::new(2, Fruit::Strawberry),
+        Select::new(
+            "Question",
+            vec![Fruit::Banana, Fruit::Apple, Fruit::Strawberry]
+        )
+    );
+
+    select_test!(
+        keep_on_screen_leaves_options_above_the_answer,
+        vec![KeyCode::Down, KeyCode::Enter],
+        ListOption::new(1, "b"),
+        Select::new("Question", vec!["a", "b", "c"]).with_keep_on_screen(true),
+        |output| {
+            let (_, after_last_clear) = output.rsplit_once("\x1b[2K").unwrap();
+
+            assert!(after_last_clear.contains("  a\r\n> b\r\n  c\r\n"));
+            assert!(after_last_clear.contains("? Question b\r\n"));
+        }
+    );
+
+    select_test!(
+        options_are_cleared_on_submission_by_default,
+        vec![KeyCode::Down, KeyCode::Enter],
+        ListOption::new(1, "b"),
+        Select::new("Question", vec!["a", "b", "c"]),
+        |output| {
+            let (_, after_last_clear) = output.rsplit_once("\x1b[2K").unwrap();
+
+            assert!(!after_last_clear.contains("> b"));
+            assert!(after_last_clear.contains("? Question b\r\n"));
+        }
+    );
+
+    select_test!(
+        hint_of_the_highlighted_option_is_displayed,
+        vec![KeyCode::Down, KeyCode::Down, KeyCode::Enter],
+        ListOption::new(2, "pnpm"),
+        Select::new("Question", vec!["npm", "yarn", "pnpm"]).with_option_hints(&[
+            "Node's default",
+            "",
+            "Saves disk space"
+        ]),
+        |output| {
+            let frames: Vec<&str> = output.split("? Question").skip(1).collect();
+
+            assert!(frames[0].contains("[Node's default]"));
+            assert!(frames[1].contains(&format!(
+                "[{}]",
+                Select::<&str>::DEFAULT_HELP_MESSAGE.unwrap()
+            )));
+            assert!(frames[2].contains("[Saves disk space]"));
+        }
+    );
+
+    select_test!(
+        first_sorted_option_keeps_its_original_index,
+        vec![KeyCode::Home, KeyCode::Enter],
+        ListOption::new(2, "apple"),
+        Select::new("Question", vec!["pear", "Banana", "apple", "cherry"]).with_sorted(true)
+    );
+
+    select_test!(
+        sorted_options_keep_their_original_index,
+        vec![KeyCode::Home, KeyCode::Down, KeyCode::Enter],
+        ListOption::new(1, "Banana"),
+        Select::new("Question", vec!["pear", "Banana", "apple", "cherry"]).with_sorted(true)
+    );
+
+    select_test!(
+        cursor_starts_on_the_first_given_option_when_sorted,
+        vec![KeyCode::Enter],
+        ListOption::new(0, "pear"),
+        Select::new("Question", vec!["pear", "Banana", "apple", "cherry"]).with_sorted(true)
+    );
+
+    select_test!(
+        starting_cursor_of_sorted_options_is_an_original_index,
+        vec![KeyCode::Enter],
+        ListOption::new(3, "cherry"),
+        Select::new("Question", vec!["pear", "Banana", "apple", "cherry"])
+            .with_sorted(true)
+            .with_starting_cursor(3)
+    );
+
+    select_test!(
+        numbers_of_sorted_options_are_their_original_positions,
+        vec![KeyCode::Char('2'), KeyCode::Enter],
+        ListOption::new(1, "Banana"),
+        Select::new("Question", vec!["pear", "Banana", "apple", "cherry"])
+            .with_sorted(true)
+            .with_numbered(true),
+        |output| {
+            assert!(output.contains("  3) apple\r\n> 2) Banana\r\n  4) cherry\r\n  1) pear\r\n"));
+        }
+    );
+
+    select_test!(
+        sorted_options_are_sorted_within_header_sections,
+        vec![KeyCode::Down, KeyCode::Down, KeyCode::Enter],
+        ListOption::new(5, "bean"),
+        Select::new(
+            "Question",
+            vec!["Fruits", "pear", "apple", "Veggies", "leek", "bean"]
+        )
+        .with_headers(&[0, 3])
+        .with_sorted(true)
+    );
+
+    #[test]
+    fn clicking_an_option_highlights_it_and_clicking_again_submits_it() {
+        let select = Select::new("Question", vec!["a", "b", "c", "d"]).with_disabled(&[3]);
+        let mut prompt = SelectPrompt::new(select).unwrap();
+
+        assert!(!prompt.click_option(2));
+        assert_eq!(2, prompt.cursor_index);
+
+        assert!(!prompt.click_option(3));
+        assert_eq!(2, prompt.cursor_index);
+
+        assert!(prompt.click_option(2));
+        assert_eq!(ListOption::new(2, "c"), prompt.get_final_answer());
+    }
+
+    select_test!(
+        help_message_follows_the_options_by_default,
+        vec![KeyCode::Enter],
+        ListOption::new(0, "a"),
+        Select::new("Question", vec!["a", "b"]).with_help_message("Pick one"),
+        |output| {
+            assert!(output.contains("  b\r\n[Pick one]\r\n"));
+        }
+    );
+
+    select_test!(
+        help_message_separator_is_rendered_before_the_help_message,
+        vec![KeyCode::Enter],
+        ListOption::new(0, "a"),
+        Select::new("Question", vec!["a", "b"]).with_help_message("Pick one"),
+        RenderConfig::empty().with_help_message_separator(Styled::new("---")),
+        |output| {
+            assert!(output.contains("  b\r\n---\r\n[Pick one]\r\n"));
+        }
+    );
 }
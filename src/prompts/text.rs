@@ -1,19 +1,97 @@
-use std::cmp::min;
+use std::{
+    borrow::Cow,
+    cell::RefCell,
+    cmp::min,
+    fs::{File, OpenOptions},
+    io::{BufRead, BufReader, Write},
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use unicode_segmentation::UnicodeSegmentation;
+
+#[cfg(feature = "crossterm")]
+use crate::terminal::crossterm::CrosstermTerminal;
 
 use crate::{
-    autocompletion::{Autocomplete, NoAutoCompletion, Replacement},
-    config::{self, get_configuration},
+    autocompletion::{Autocomplete, NoAutoCompletion, Replacement, Suggestion},
+    config::{self, get_configuration, get_global_config},
     error::{InquireError, InquireResult},
     formatter::{StringFormatter, DEFAULT_STRING_FORMATTER},
     input::Input,
     list_option::ListOption,
-    terminal::get_default_terminal,
-    ui::{Backend, Key, KeyModifiers, RenderConfig, TextBackend},
+    prompts::custom_type::DEFAULT_HELP_MESSAGE_WITH_DEFAULT,
+    terminal::{
+        get_default_terminal,
+        replay::{KeyStreamTerminal, RecordingTerminal, ReplayTerminal},
+        Terminal,
+    },
+    type_aliases::{EventHook, SuggestionPreview, SuggestionTransform},
+    ui::{
+        Action, Backend, CustomTypeBackend, Key, KeyModifiers, Keymap, RenderConfig, TextBackend,
+    },
     utils::paginate,
-    validator::{ErrorMessage, StringValidator, Validation},
+    validator::{ErrorMessage, StringValidator, Validation, ValueRequiredValidator},
+    Confirm, CustomType,
 };
 
 const DEFAULT_HELP_MESSAGE_WITH_AC: &str = "↑↓ to move, tab to autocomplete, enter to submit";
+const DEFAULT_HELP_MESSAGE_MULTILINE: &str = "enter to add a new line, ctrl+d to submit";
+const CONFIRM_CANCEL_MESSAGE: &str = "Press Ctrl+C again to cancel";
+
+/// Period during which a second ctrl+c interrupts the prompt when
+/// [`Text::confirm_cancel`] is enabled.
+const CONFIRM_CANCEL_WINDOW: Duration = Duration::from_millis(2000);
+
+/// Frames of the spinner displayed while debounced suggestions are pending.
+const SPINNER_FRAMES: [&str; 4] = ["|", "/", "-", "\\"];
+const SPINNER_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Whitespace trimming applied to the text typed by the user in a [`Text`]
+/// prompt, before it is validated and returned.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TrimMode {
+    /// The text is kept as typed. This is the default mode.
+    None,
+
+    /// Leading and trailing whitespace is removed.
+    Trim,
+
+    /// Trailing whitespace is removed.
+    TrimEnd,
+
+    /// Leading whitespace is removed.
+    TrimStart,
+}
+
+impl TrimMode {
+    /// Returns the text trimmed according to this mode.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inquire::TrimMode;
+    ///
+    /// assert_eq!(" a ", TrimMode::None.apply(" a "));
+    /// assert_eq!("a", TrimMode::Trim.apply(" a "));
+    /// assert_eq!(" a", TrimMode::TrimEnd.apply(" a "));
+    /// assert_eq!("a ", TrimMode::TrimStart.apply(" a "));
+    /// ```
+    pub fn apply(self, text: &str) -> &str {
+        match self {
+            Self::None => text,
+            Self::Trim => text.trim(),
+            Self::TrimEnd => text.trim_end(),
+            Self::TrimStart => text.trim_start(),
+        }
+    }
+}
+
+impl Default for TrimMode {
+    fn default() -> Self {
+        Self::None
+    }
+}
 
 /// Standard text prompt that returns the user string input.
 ///
@@ -25,11 +103,23 @@ const DEFAULT_HELP_MESSAGE_WITH_AC: &str = "↑↓ to move, tab to autocomplete,
 /// - **Prompt message**: Main message when prompting the user for input, `"What is your name?"` in the example below.
 /// - **Help message**: Message displayed at the line below the prompt.
 /// - **Default value**: Default value returned when the user submits an empty response.
+///   - Pressing tab on an empty input also accepts it, unless suggestions are displayed.
 /// - **Initial value**: Initial value of the prompt's text input, in case you want to display the prompt with something already filled in.
 /// - **Placeholder**: Short hint that describes the expected value of the input.
+/// - **Max length**: Maximum number of characters, counted as grapheme clusters, that the user is able to type.
 /// - **Validators**: Custom validators to the user's input, displaying an error message if the input does not pass the requirements.
+/// - **Live validation**: Whether validators run on every change to the input, instead of only when the user submits it.
+/// - **Multiline**: Whether <kbd>enter</kbd> inserts a new line into the input, in which case the answer is submitted with <kbd>ctrl</kbd> + <kbd>d</kbd>.
+/// - **Event hook**: Function called on every key pressed by the user, along with the current text input.
+/// - **Timeout**: Period of inactivity after which the prompt is finished with its default value.
+/// - **History**: Previously submitted answers, recalled with the up and down arrows when no suggestions are displayed.
+/// - **History file**: File from which the history is loaded and to which submitted answers are appended.
+/// - **Confirmation**: Message of a yes/no question asked after the answer is submitted, returning to editing the input when declined.
+/// - **Audible errors**: Whether the terminal bell rings when a submission is rejected or a typed character is ignored.
+/// - **Keymap**: Key bindings of the prompt actions, such as moving through suggestions or submitting the answer.
 /// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
 /// - **Suggester**: Custom function that returns a list of input suggestions based on the current text input. See more on "Autocomplete" below.
+/// - **Common prefix completion**: Whether the autocompletion hotkey should first complete the input to the longest common prefix of all suggestions.
 ///
 /// ## Default behaviors
 ///
@@ -38,6 +128,7 @@ const DEFAULT_HELP_MESSAGE_WITH_AC: &str = "↑↓ to move, tab to autocomplete,
 /// - The input formatter just echoes back the given input.
 /// - No validators are called, accepting any sort of input including empty ones.
 /// - No default values or help messages.
+/// - No maximum input length.
 /// - No autocompletion features set-up.
 /// - Prompt messages are always required when instantiating via `new()`.
 ///
@@ -54,6 +145,8 @@ const DEFAULT_HELP_MESSAGE_WITH_AC: &str = "↑↓ to move, tab to autocomplete,
 ///
 /// Everytime `get_suggestions` is called, the method returns the list of paths that match the user input. When the user presses the autocompletion hotkey, the `FilePathCompleter` checks whether there is any path selected from the list, if there is, it decides to replace the current text input for it. The interesting piece of functionality is that if there isn't a path selected from the list, the `FilePathCompleter` calculates the longest common prefix amongst all scanned paths and updates the text input to an unambiguous new value. Similar to how terminals work when traversing paths.
 ///
+/// If you want this shell-like behavior for any autocompleter, call `with_common_prefix_completion(true)`. The autocompletion hotkey then first extends the text input to the longest common prefix of the current suggestions, and only once the input is already at that prefix the regular `get_completion` call is made.
+///
 /// # Example
 ///
 /// ```no_run
@@ -84,15 +177,43 @@ pub struct Text<'a> {
     /// Short hint that describes the expected value of the input.
     pub placeholder: Option<&'a str>,
 
+    /// Maximum length of the text input, counted in grapheme clusters.
+    ///
+    /// Once the limit is reached, further characters typed by the user are ignored.
+    pub max_length: Option<usize>,
+
     /// Help message to be presented to the user.
     pub help_message: Option<&'a str>,
 
+    /// Whether the default help messages, such as the hints at the
+    /// autocompletion keys and at the key accepting the default value, are
+    /// displayed when no help message is set.
+    ///
+    /// Defaults to the `help_messages` setting of the global [`Config`](crate::Config).
+    pub default_help_messages: bool,
+
     /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
     pub formatter: StringFormatter<'a>,
 
     /// Autocompleter responsible for handling suggestions and input completions.
+    ///
+    /// Errors returned by the autocompleter are displayed to the user, who can
+    /// keep typing to try again.
     pub autocompleter: Option<Box<dyn Autocomplete>>,
 
+    /// Function whose output is displayed below the suggestions, receiving the
+    /// value of the highlighted suggestion.
+    pub preview: Option<SuggestionPreview<'a>>,
+
+    /// Function applied to a suggestion picked by the user, whose output is
+    /// inserted into the input or submitted in place of the displayed value.
+    pub suggestion_transform: Option<SuggestionTransform<'a>>,
+
+    /// Period of inactivity to wait for, after the input is modified, before
+    /// requesting new suggestions from the autocompleter. The previous
+    /// suggestions are displayed in the meantime.
+    pub suggestion_debounce: Option<Duration>,
+
     /// Collection of validators to apply to the user input.
     ///
     /// Validators are executed in the order they are stored, stopping at and displaying to the user
@@ -101,9 +222,90 @@ pub struct Text<'a> {
     /// The possible error is displayed to the user one line above the prompt.
     pub validators: Vec<Box<dyn StringValidator>>,
 
+    /// Whitespace trimming applied to the typed text before it is validated
+    /// and returned.
+    pub trim: TrimMode,
+
+    /// Whether empty answers are rejected, as if by a [`ValueRequiredValidator`]
+    /// executed before any other validator.
+    ///
+    /// Note that an empty input is replaced by the default value, when one is set.
+    pub required: bool,
+
+    /// Whether the validators are executed on every change to the user input,
+    /// displaying the possible error continuously, instead of only when the
+    /// user submits the input.
+    ///
+    /// Submissions are still blocked while the input is invalid.
+    pub live_validation: bool,
+
+    /// Whether to ring the terminal bell when a submission is rejected by the
+    /// validators or a typed character is ignored due to the maximum length.
+    pub audible_errors: bool,
+
+    /// Maximum number of submissions rejected by the validators before the
+    /// prompt returns [`InquireError::TooManyAttempts`]. Unlimited by default.
+    pub max_attempts: Option<usize>,
+
+    /// Whether a ctrl+c press must be repeated shortly after the first one to
+    /// interrupt the prompt, avoiding the loss of the input on accidental presses.
+    pub confirm_cancel: bool,
+
+    /// Whether to clear the terminal before the prompt is first rendered, so
+    /// that previous output does not clutter the view. Ignored when the output
+    /// is not a terminal.
+    pub clear_screen: bool,
+
+    /// Whether the prompt runs in the alternate screen buffer of the terminal,
+    /// leaving the scrollback of the main screen untouched. Ignored when the
+    /// output is not a terminal.
+    pub alternate_screen: bool,
+
+    /// Key bindings of the prompt actions.
+    pub keymap: Keymap,
+
     /// Page size of the suggestions displayed to the user, when applicable.
     pub page_size: usize,
 
+    /// Maximum number of suggestions displayed to the user. When the
+    /// autocompleter returns more, the rest are hidden and their count is
+    /// displayed below the list.
+    pub max_suggestions: Option<usize>,
+
+    /// Whether the autocompletion hotkey first completes the text input to the
+    /// longest common prefix of the current suggestions, before calling the
+    /// autocompleter's `get_completion`.
+    pub common_prefix_completion: bool,
+
+    /// Whether the text input spans multiple lines. When enabled, the submit
+    /// key inserts a new line into the input and the answer is submitted
+    /// with <kbd>ctrl</kbd> + <kbd>d</kbd> instead.
+    pub multiline: bool,
+
+    /// Function called on every key pressed by the user, after the key was
+    /// handled, along with the current text input.
+    pub event_hook: Option<EventHook<'a>>,
+
+    /// Writer to which a line is logged for every key pressed by the user,
+    /// along with the resulting text input, e.g. to troubleshoot autocompleters.
+    pub debug_log: Option<&'a RefCell<dyn Write>>,
+
+    /// Period of inactivity after which the prompt returns its default value,
+    /// when one is set.
+    pub timeout: Option<Duration>,
+
+    /// Message of a [`Confirm`] prompt displayed after a valid answer is
+    /// submitted. When the user declines it, they return to editing the input.
+    pub confirmation: Option<&'a str>,
+
+    /// Previously submitted answers, from oldest to newest, recalled with the
+    /// cursor movement keys when no suggestions are displayed.
+    pub history: Vec<String>,
+
+    /// File whose lines are loaded into the history when the prompt starts,
+    /// and to which the submitted answer is appended.
+    pub history_file: Option<PathBuf>,
+
     /// RenderConfig to apply to the rendered interface.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -130,16 +332,41 @@ impl<'a> Text<'a> {
 
     /// Creates a [Text] with the provided message and default options.
     pub fn new(message: &'a str) -> Self {
+        let config = get_global_config();
+
         Self {
             message,
             placeholder: None,
+            max_length: None,
             initial_value: None,
             default: None,
             help_message: Self::DEFAULT_HELP_MESSAGE,
+            default_help_messages: config.help_messages,
             validators: Self::DEFAULT_VALIDATORS,
+            trim: TrimMode::default(),
+            required: false,
+            live_validation: false,
+            audible_errors: false,
+            max_attempts: None,
+            confirm_cancel: false,
+            clear_screen: false,
+            alternate_screen: false,
+            keymap: Keymap::default(),
             formatter: Self::DEFAULT_FORMATTER,
-            page_size: Self::DEFAULT_PAGE_SIZE,
+            page_size: config.page_size,
+            max_suggestions: None,
+            common_prefix_completion: false,
+            multiline: false,
+            event_hook: None,
+            debug_log: None,
+            timeout: None,
+            confirmation: None,
+            history: Vec::new(),
+            history_file: None,
             autocompleter: None,
+            preview: None,
+            suggestion_transform: None,
+            suggestion_debounce: None,
             render_config: get_configuration(),
         }
     }
@@ -172,6 +399,16 @@ impl<'a> Text<'a> {
         self
     }
 
+    /// Sets the maximum length of the text input, counted in grapheme clusters.
+    ///
+    /// Once the limit is reached, further characters typed by the user are ignored.
+    /// Text replacing the input, such as completions, recalled history entries or
+    /// a highlighted suggestion being submitted, is cut at it.
+    pub fn with_max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
     /// Sets a new autocompleter
     pub fn with_autocomplete<AC>(mut self, ac: AC) -> Self
     where
@@ -181,7 +418,39 @@ impl<'a> Text<'a> {
         self
     }
 
+    /// Sets a function previewing the highlighted suggestion, e.g. displaying
+    /// the first lines of a file. Its output is displayed below the suggestions
+    /// and updated as the user moves through them.
+    pub fn with_preview(mut self, preview: SuggestionPreview<'a>) -> Self {
+        self.preview = Some(preview);
+        self
+    }
+
+    /// Sets a function transforming the suggestions picked by the user, so
+    /// that the text inserted into the input when autocompleting, or submitted
+    /// while a suggestion is highlighted, differs from the displayed value,
+    /// e.g. a full path instead of a file name.
+    pub fn with_suggestion_transform(mut self, transform: SuggestionTransform<'a>) -> Self {
+        self.suggestion_transform = Some(transform);
+        self
+    }
+
+    /// Sets a debounce period for the autocompleter, which is then only asked
+    /// for suggestions once the user stops typing for the given duration,
+    /// instead of on every keystroke. Useful for slow autocompleters, e.g.
+    /// those querying a network service.
+    ///
+    /// Only the crossterm terminal back-end is able to detect inactivity, the
+    /// others request suggestions after every keystroke as well.
+    pub fn with_suggestion_debounce(mut self, debounce: Duration) -> Self {
+        self.suggestion_debounce = Some(debounce);
+        self
+    }
+
     /// Sets the formatter.
+    ///
+    /// The formatter only affects how the answer is displayed once the prompt
+    /// is finished, the value returned by the prompt is still the raw user input.
     pub fn with_formatter(mut self, formatter: StringFormatter<'a>) -> Self {
         self.formatter = formatter;
         self
@@ -193,6 +462,106 @@ impl<'a> Text<'a> {
         self
     }
 
+    /// Sets the maximum number of suggestions displayed to the user. Any
+    /// further suggestions returned by the autocompleter are hidden.
+    pub fn with_max_suggestions(mut self, max_suggestions: usize) -> Self {
+        self.max_suggestions = Some(max_suggestions);
+        self
+    }
+
+    /// Enables or disables the completion of the text input to the longest
+    /// common prefix of the current suggestions when the autocompletion hotkey
+    /// is pressed.
+    ///
+    /// When enabled and the text input is already at the common prefix, the
+    /// autocompleter's `get_completion` is called as usual, e.g. to insert
+    /// the highlighted suggestion.
+    pub fn with_common_prefix_completion(mut self, common_prefix_completion: bool) -> Self {
+        self.common_prefix_completion = common_prefix_completion;
+        self
+    }
+
+    /// Enables or disables the multiline mode, in which the submit key inserts
+    /// a new line into the text input and the answer is submitted with
+    /// <kbd>ctrl</kbd> + <kbd>d</kbd> instead.
+    pub fn with_multiline(mut self, multiline: bool) -> Self {
+        self.multiline = multiline;
+        self
+    }
+
+    /// Sets a function to be called on every key pressed by the user, after the
+    /// key was handled, along with the current text input.
+    ///
+    /// The hook is only an observer, it does not affect the prompt's answer.
+    pub fn with_event_hook(mut self, event_hook: EventHook<'a>) -> Self {
+        self.event_hook = Some(event_hook);
+        self
+    }
+
+    /// Sets a writer to which a line is logged for every key pressed by the
+    /// user, along with the resulting text input, such as `backspace -> "ab"`.
+    ///
+    /// The log is separate from the rendered interface, and errors while
+    /// writing to it are ignored.
+    pub fn with_debug_log(mut self, debug_log: &'a RefCell<dyn Write>) -> Self {
+        self.debug_log = Some(debug_log);
+        self
+    }
+
+    /// Sets a timeout after which, if the user has not pressed any key, the
+    /// prompt is finished with its default value. Any partial input is discarded.
+    ///
+    /// The default value is validated as any submission: when rejected, the
+    /// error is displayed and the prompt waits for the user indefinitely.
+    ///
+    /// Prompts without a default value wait for the user indefinitely.
+    ///
+    /// The default value submitted after the timeout is not confirmed, even
+    /// when a question is set with [`with_confirmation`](Self::with_confirmation),
+    /// as nobody is there to answer it.
+    ///
+    /// Only the crossterm terminal back-end is able to detect inactivity, the
+    /// others wait for the user indefinitely as well.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets a confirmation question, e.g. "Are you sure?", asked with a
+    /// [`Confirm`] prompt after a valid answer is submitted.
+    ///
+    /// The answer is only returned once confirmed. Otherwise, the user returns
+    /// to editing the text input. The default value submitted after a
+    /// [timeout](Self::with_timeout) skips the confirmation.
+    pub fn with_confirmation(mut self, message: &'a str) -> Self {
+        self.confirmation = Some(message);
+        self
+    }
+
+    /// Sets the previously submitted answers, from oldest to newest.
+    ///
+    /// When no suggestions are displayed, moving the cursor up loads the
+    /// previous entry of the history into the text input, and moving it down
+    /// loads the next one, eventually restoring the input typed by the user.
+    pub fn with_history(mut self, history: Vec<String>) -> Self {
+        self.history = history;
+        self
+    }
+
+    /// Sets a file to persist the history across sessions, like a shell does.
+    ///
+    /// When the prompt starts, each line of the file is appended to the history.
+    /// Once the user submits an answer, it is appended to the file, unless it
+    /// is equal to the file's last entry.
+    ///
+    /// Failures to read or write the file are ignored, only skipping the
+    /// persistence of the history. Answers spanning multiple lines are not
+    /// persisted either.
+    pub fn with_history_file<P: Into<PathBuf>>(mut self, path: P) -> Self {
+        self.history_file = Some(path.into());
+        self
+    }
+
     /// Adds a validator to the collection of validators. You might want to use this feature
     /// in case you need to require certain features from the user's answer, such as
     /// defining a limit of characters.
@@ -225,12 +594,85 @@ impl<'a> Text<'a> {
     /// The possible error is displayed to the user one line above the prompt.
     pub fn with_validators(mut self, validators: &[Box<dyn StringValidator>]) -> Self {
         for validator in validators {
-            #[allow(clippy::clone_double_ref)]
             self.validators.push(validator.clone());
         }
         self
     }
 
+    /// Sets the whitespace trimming applied to the typed text before it is
+    /// validated and returned, e.g. to discard the trailing new line of a paste.
+    ///
+    /// A text made empty by the trimming is replaced by the default value, when one is set.
+    pub fn with_trim(mut self, trim: TrimMode) -> Self {
+        self.trim = trim;
+        self
+    }
+
+    /// Enables or disables the rejection of empty answers, displaying the
+    /// "A response is required." error message instead.
+    ///
+    /// Note that an empty input is replaced by the default value, when one is set.
+    pub fn with_required(mut self, required: bool) -> Self {
+        self.required = required;
+        self
+    }
+
+    /// Enables or disables the execution of the validators on every change to
+    /// the user input, displaying the possible error continuously.
+    ///
+    /// Submissions are still blocked while the input is invalid.
+    pub fn with_live_validation(mut self, live_validation: bool) -> Self {
+        self.live_validation = live_validation;
+        self
+    }
+
+    /// Enables or disables the terminal bell when a submission is rejected by
+    /// the validators or a typed character is ignored due to the maximum length.
+    pub fn with_audible_errors(mut self, audible_errors: bool) -> Self {
+        self.audible_errors = audible_errors;
+        self
+    }
+
+    /// Sets the maximum number of submissions rejected by the validators,
+    /// after which the prompt returns [`InquireError::TooManyAttempts`]
+    /// instead of asking again.
+    pub fn with_max_attempts(mut self, max_attempts: usize) -> Self {
+        self.max_attempts = Some(max_attempts);
+        self
+    }
+
+    /// Enables or disables the confirmation of interruptions. When enabled,
+    /// the first ctrl+c press only displays a notice, and the prompt is
+    /// interrupted if it is pressed again shortly after. Any other key
+    /// press dismisses the notice.
+    pub fn with_confirm_cancel(mut self, confirm_cancel: bool) -> Self {
+        self.confirm_cancel = confirm_cancel;
+        self
+    }
+
+    /// Enables or disables clearing the terminal before the prompt is first
+    /// rendered. Output that is not a terminal is never cleared.
+    pub fn with_clear_screen(mut self, clear_screen: bool) -> Self {
+        self.clear_screen = clear_screen;
+        self
+    }
+
+    /// Enables or disables running the prompt in the alternate screen buffer,
+    /// as fullscreen applications do. The main screen is restored once the
+    /// prompt ends, whether it was answered, canceled or interrupted, so the
+    /// answer is not left in the scrollback. Output that is not a terminal
+    /// never switches screens.
+    pub fn with_alternate_screen(mut self, alternate_screen: bool) -> Self {
+        self.alternate_screen = alternate_screen;
+        self
+    }
+
+    /// Sets the key bindings of the prompt actions.
+    pub fn with_keymap(mut self, keymap: Keymap) -> Self {
+        self.keymap = keymap;
+        self
+    }
+
     /// Sets the provided color theme to this prompt.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -269,30 +711,273 @@ impl<'a> Text<'a> {
         self.prompt_with_backend(&mut backend)
     }
 
-    pub(crate) fn prompt_with_backend<B: TextBackend>(
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules, additionally
+    /// returning whether the answer is the default value.
+    ///
+    /// The flag is `true` only when the default value was used in place of
+    /// an empty submission, or after a timeout. It is `false` when the user
+    /// typed the answer themselves, even if it is equal to the default value.
+    pub fn prompt_with_default_flag(self) -> InquireResult<(String, bool)> {
+        let terminal = get_default_terminal()?;
+        let mut backend = Backend::new(terminal, self.render_config)?;
+        self.prompt_with_backend_and_default_flag(&mut backend)
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules, additionally
+    /// returning the keys pressed by the user, in order.
+    ///
+    /// Waits for a key that timed out, such as the ones of
+    /// [`with_timeout`](Text::with_timeout) or of a suggestion debounce, are
+    /// recorded as `None` among the keys.
+    ///
+    /// The keys can be fed to [`replay`](Text::replay) to reproduce the session
+    /// deterministically, e.g. in demos. They are returned even if the prompt
+    /// fails, such as when the user cancels it.
+    pub fn prompt_recording(self) -> (InquireResult<String>, Vec<Option<Key>>) {
+        match get_default_terminal() {
+            Ok(terminal) => self.prompt_recording_with_terminal(terminal),
+            Err(err) => (Err(err), vec![]),
+        }
+    }
+
+    /// Runs the prompt on keys previously recorded by
+    /// [`prompt_recording`](Text::prompt_recording) instead of the user's input,
+    /// rendering it as usual.
+    ///
+    /// Recorded timeouts are replayed right away, without waiting for their
+    /// duration. When the keys run out before the prompt is finished, an
+    /// `InquireError::IO` error with kind `UnexpectedEof` is returned.
+    pub fn replay(self, keys: &[Option<Key>]) -> InquireResult<String> {
+        let terminal = get_default_terminal()?;
+        self.replay_with_terminal(terminal, keys)
+    }
+
+    fn prompt_recording_with_terminal<T: Terminal>(
+        self,
+        terminal: T,
+    ) -> (InquireResult<String>, Vec<Option<Key>>) {
+        let mut keys = vec![];
+
+        let result = Backend::new(
+            RecordingTerminal::new(terminal, &mut keys),
+            self.render_config,
+        )
+        .map_err(InquireError::from)
+        .and_then(|mut backend| self.prompt_with_backend(&mut backend));
+
+        (result, keys)
+    }
+
+    fn replay_with_terminal<T: Terminal>(
+        self,
+        terminal: T,
+        keys: &[Option<Key>],
+    ) -> InquireResult<String> {
+        let mut backend = Backend::new(ReplayTerminal::new(terminal, keys), self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    /// Prompts the user for input over a terminal session managed by the caller,
+    /// rendering the prompt to `writer` and reading key presses from `keys`.
+    ///
+    /// This method is intended for applications that already own the terminal,
+    /// e.g. TUIs that want to ask a single question in the middle of their own
+    /// event loop. The caller is responsible for the terminal setup: unlike
+    /// [`prompt`](Text::prompt), it neither enables nor disables raw mode.
+    ///
+    /// When `keys` runs out before the prompt is finished, an
+    /// `InquireError::IO` error with kind `UnexpectedEof` is returned.
+    #[cfg(feature = "crossterm")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "crossterm")))]
+    pub fn prompt_with_io<W: std::io::Write, K: Into<Key>>(
+        self,
+        writer: &mut W,
+        keys: &mut dyn Iterator<Item = K>,
+    ) -> InquireResult<String> {
+        let mut keys = keys.map(K::into);
+        let mut no_events = std::iter::empty();
+        let terminal = KeyStreamTerminal::new(
+            CrosstermTerminal::new_with_io(writer, &mut no_events),
+            &mut keys,
+        );
+        let mut backend = Backend::new(terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    /// Runs the prompt on the key presses of `keys`, as
+    /// [`prompt_with_io`](Text::prompt_with_io) does, returning along with its
+    /// result the transcript of everything rendered in the meantime.
+    ///
+    /// The transcript contains every frame of the prompt, including the
+    /// terminal's cursor movement sequences, which makes it suitable for
+    /// snapshot tests of the rendered interface. Styles are not rendered,
+    /// unless forced with [`Config::with_force_color`](crate::config::Config::with_force_color).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use inquire::{
+    ///     scripted::{ScriptedAction, ScriptedEvents},
+    ///     Text,
+    /// };
+    ///
+    /// let script = vec![ScriptedAction::Type("Ferris".into()), ScriptedAction::Submit];
+    /// let mut events = ScriptedEvents::new(script.into_iter());
+    ///
+    /// let (name, transcript) = Text::new("What is your name?").prompt_with_transcript(&mut events);
+    ///
+    /// assert_eq!("Ferris", name.unwrap());
+    /// assert!(transcript.contains("? What is your name? Ferris"));
+    /// ```
+    #[cfg(feature = "crossterm")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "crossterm")))]
+    pub fn prompt_with_transcript<K: Into<Key>>(
+        self,
+        keys: &mut dyn Iterator<Item = K>,
+    ) -> (InquireResult<String>, String) {
+        let mut transcript: Vec<u8> = Vec::new();
+        let result = self.prompt_with_io(&mut transcript, keys);
+
+        (result, String::from_utf8_lossy(&transcript).into_owned())
+    }
+
+    pub(crate) fn prompt_with_backend<B: TextBackend + CustomTypeBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<String> {
+        self.prompt_with_backend_and_default_flag(backend)
+            .map(|(answer, _)| answer)
+    }
+
+    fn prompt_with_backend_and_default_flag<B: TextBackend + CustomTypeBackend>(
+        mut self,
+        backend: &mut B,
+    ) -> InquireResult<(String, bool)> {
+        let history_file = match self.history_file.take() {
+            Some(path) => {
+                let entries = read_history_file(&path);
+                let last_entry = entries.last().cloned();
+                self.history.extend(entries);
+                Some((path, last_entry))
+            }
+            None => None,
+        };
+
+        let alternate_screen = self.alternate_screen;
+        if alternate_screen {
+            backend.enter_alternate_screen()?;
+        }
+
+        let result = TextPrompt::from(self).prompt(backend);
+
+        if alternate_screen {
+            backend.leave_alternate_screen()?;
+        }
+
+        let (answer, used_default) = result?;
+
+        if let Some((path, last_entry)) = history_file {
+            if last_entry.as_deref() != Some(answer.as_str()) && !answer.contains('\n') {
+                // Persisting the history is best-effort, the answer is still valid.
+                let _ = append_to_history_file(&path, &answer);
+            }
+        }
+
+        Ok((answer, used_default))
+    }
+
+    /// Prompts for one more item of a list, rendering the items added so far
+    /// above the input. Submitting an empty input returns an empty string
+    /// without running the validators, signaling the end of the list.
+    pub(crate) fn prompt_list_item_with_backend<B: TextBackend + CustomTypeBackend>(
         self,
+        items: &[String],
         backend: &mut B,
     ) -> InquireResult<String> {
-        TextPrompt::from(self).prompt(backend)
+        let mut prompt = TextPrompt::from(self);
+        prompt.list_items = Some(items.to_vec());
+
+        prompt.prompt(backend).map(|(answer, _)| answer)
+    }
+}
+
+fn read_history_file(path: &Path) -> Vec<String> {
+    match File::open(path) {
+        Ok(file) => BufReader::new(file)
+            .lines()
+            .filter_map(|line| line.ok())
+            .filter(|line| !line.is_empty())
+            .collect(),
+        Err(_) => vec![],
     }
 }
 
+fn append_to_history_file(path: &Path, answer: &str) -> std::io::Result<()> {
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+
+    writeln!(file, "{}", answer)
+}
+
 struct TextPrompt<'a> {
     message: &'a str,
     default: Option<&'a str>,
     help_message: Option<&'a str>,
+    default_help_messages: bool,
     input: Input,
+    trim: TrimMode,
+    max_length: Option<usize>,
     formatter: StringFormatter<'a>,
     validators: Vec<Box<dyn StringValidator>>,
+    live_validation: bool,
+    live_validation_result: Option<Validation>,
+    audible_errors: bool,
+    ring_bell: bool,
+    max_attempts: Option<usize>,
+    failed_attempts: usize,
+    clear_screen: bool,
+    show_help: bool,
+    confirm_cancel: bool,
+    cancel_pending: bool,
+    keymap: Keymap,
     error: Option<ErrorMessage>,
+    warning: Option<ErrorMessage>,
     autocompleter: Box<dyn Autocomplete>,
-    suggested_options: Vec<String>,
+    autocompleter_error: Option<ErrorMessage>,
+    preview: Option<SuggestionPreview<'a>>,
+    suggestion_transform: Option<SuggestionTransform<'a>>,
+    suggestion_debounce: Option<Duration>,
+    can_poll: bool,
+    suggestions_stale: bool,
+    stale_for: Duration,
+    spinner_frame: usize,
+    suggested_options: Vec<Suggestion>,
+    max_suggestions: Option<usize>,
+    hidden_suggestions: usize,
     cursor_index: usize,
     page_size: usize,
+    common_prefix_completion: bool,
+    multiline: bool,
+    event_hook: Option<EventHook<'a>>,
+    debug_log: Option<&'a RefCell<dyn Write>>,
+    timeout: Option<Duration>,
+    confirmation: Option<&'a str>,
+    history: Vec<String>,
+    history_index: Option<usize>,
+    history_draft: String,
+    history_search: Option<String>,
+    history_search_match: Option<usize>,
+    list_items: Option<Vec<String>>,
 }
 
 impl<'a> From<Text<'a>> for TextPrompt<'a> {
     fn from(so: Text<'a>) -> Self {
+        let mut validators = so.validators;
+        if so.required {
+            validators.insert(0, Box::new(ValueRequiredValidator::default()));
+        }
+
         let input = Input::new_with(so.initial_value.unwrap_or_default());
         let input = if let Some(placeholder) = so.placeholder {
             input.with_placeholder(placeholder)
@@ -304,16 +989,53 @@ impl<'a> From<Text<'a>> for TextPrompt<'a> {
             message: so.message,
             default: so.default,
             help_message: so.help_message,
+            default_help_messages: so.default_help_messages,
             formatter: so.formatter,
             autocompleter: so
                 .autocompleter
-                .unwrap_or_else(|| Box::new(NoAutoCompletion::default())),
+                .unwrap_or_else(|| Box::new(NoAutoCompletion)),
+            autocompleter_error: None,
+            preview: so.preview,
+            suggestion_transform: so.suggestion_transform,
+            suggestion_debounce: so.suggestion_debounce,
+            can_poll: false,
+            suggestions_stale: false,
+            stale_for: Duration::from_millis(0),
+            spinner_frame: 0,
             input,
+            trim: so.trim,
+            max_length: so.max_length,
             error: None,
+            warning: None,
             cursor_index: 0,
             page_size: so.page_size,
+            common_prefix_completion: so.common_prefix_completion,
+            multiline: so.multiline,
+            event_hook: so.event_hook,
+            debug_log: so.debug_log,
+            timeout: so.timeout,
+            confirmation: so.confirmation,
+            history: so.history,
+            history_index: None,
+            history_draft: String::new(),
+            history_search: None,
+            history_search_match: None,
+            list_items: None,
             suggested_options: vec![],
-            validators: so.validators,
+            max_suggestions: so.max_suggestions,
+            hidden_suggestions: 0,
+            validators,
+            live_validation: so.live_validation,
+            live_validation_result: None,
+            audible_errors: so.audible_errors,
+            ring_bell: false,
+            max_attempts: so.max_attempts,
+            failed_attempts: 0,
+            clear_screen: so.clear_screen,
+            show_help: false,
+            confirm_cancel: so.confirm_cancel,
+            cancel_pending: false,
+            keymap: so.keymap,
         }
     }
 }
@@ -325,30 +1047,88 @@ impl<'a> From<&'a str> for Text<'a> {
 }
 
 impl<'a> TextPrompt<'a> {
-    fn update_suggestions(&mut self) -> InquireResult<()> {
-        self.suggested_options = self.autocompleter.get_suggestions(self.input.content())?;
+    fn update_suggestions(&mut self) {
+        let suggestions = self
+            .autocompleter
+            .get_described_suggestions(self.input.content());
+
+        self.suggested_options = match suggestions {
+            Ok(suggestions) => {
+                self.autocompleter_error = None;
+                suggestions
+            }
+            Err(err) => {
+                self.autocompleter_error = Some(ErrorMessage::Custom(err.to_string()));
+                vec![]
+            }
+        };
+
+        let max_suggestions = self.max_suggestions.unwrap_or(usize::MAX);
+        self.hidden_suggestions = self.suggested_options.len().saturating_sub(max_suggestions);
+        self.suggested_options.truncate(max_suggestions);
         self.cursor_index = 0;
+        self.suggestions_stale = false;
+    }
 
-        Ok(())
+    /// Updates the suggestions after the input was modified, or postpones it
+    /// until the user is idle when a debounce period is set. In the meantime,
+    /// the previous suggestions are still displayed, but not highlighted.
+    ///
+    /// Inactivity can only be detected by terminals able to poll for keys,
+    /// the others update the suggestions right away.
+    fn request_suggestions(&mut self) {
+        if self.suggestion_debounce.is_some() && self.can_poll {
+            self.cursor_index = 0;
+            self.suggestions_stale = true;
+            self.stale_for = Duration::from_millis(0);
+        } else {
+            self.update_suggestions();
+        }
+    }
+
+    /// Updates postponed suggestions right away, e.g. when the user wants to
+    /// navigate them before the debounce period is over.
+    fn flush_stale_suggestions(&mut self) {
+        if self.suggestions_stale {
+            self.update_suggestions();
+        }
     }
 
     fn get_highlighted_suggestion(&self) -> Option<&str> {
         if self.cursor_index > 0 {
             let index = self.cursor_index - 1;
-            let suggestion = self.suggested_options.get(index).unwrap().as_ref();
-            Some(suggestion)
+            let suggestion = self.suggested_options.get(index).unwrap();
+            Some(&suggestion.value)
         } else {
             None
         }
     }
 
+    /// Highlighted suggestion, as transformed to be inserted or submitted.
+    fn get_picked_suggestion(&self) -> Option<Cow<'_, str>> {
+        let suggestion = self.get_highlighted_suggestion()?;
+
+        Some(match self.suggestion_transform {
+            Some(transform) => Cow::Owned(transform(suggestion)),
+            None => Cow::Borrowed(suggestion),
+        })
+    }
+
     fn move_cursor_up(&mut self, qty: usize) -> bool {
+        if self.suggested_options.is_empty() {
+            return self.recall_previous_entry();
+        }
+
         self.cursor_index = self.cursor_index.saturating_sub(qty);
 
         false
     }
 
     fn move_cursor_down(&mut self, qty: usize) -> bool {
+        if self.suggested_options.is_empty() {
+            return self.recall_next_entry();
+        }
+
         self.cursor_index = min(
             self.cursor_index.saturating_add(qty),
             self.suggested_options.len(),
@@ -357,67 +1137,329 @@ impl<'a> TextPrompt<'a> {
         false
     }
 
-    fn handle_tab_key(&mut self) -> InquireResult<bool> {
-        let suggestion = self.get_highlighted_suggestion().map(|s| s.to_owned());
-        match self
-            .autocompleter
-            .get_completion(self.input.content(), suggestion)?
-        {
-            Replacement::Some(value) => {
-                self.input = Input::new_with(&value);
-                Ok(true)
+    fn recall_previous_entry(&mut self) -> bool {
+        let index = match self.history_index {
+            Some(0) => return false,
+            Some(index) => index - 1,
+            None if self.history.is_empty() => return false,
+            None => {
+                self.history_draft = self.input.content().to_owned();
+                self.history.len() - 1
             }
-            Replacement::None => Ok(false),
-        }
-    }
-
-    fn on_change(&mut self, key: Key) -> InquireResult<()> {
-        let dirty = match key {
-            Key::Up(KeyModifiers::NONE) => self.move_cursor_up(1),
-            Key::PageUp => self.move_cursor_up(self.page_size),
+        };
 
-            Key::Down(KeyModifiers::NONE) => self.move_cursor_down(1),
-            Key::PageDown => self.move_cursor_down(self.page_size),
+        self.history_index = Some(index);
+        self.replace_input(&self.history[index].clone());
 
-            Key::Tab => self.handle_tab_key()?,
+        true
+    }
 
-            key => self.input.handle_key(key),
+    fn recall_next_entry(&mut self) -> bool {
+        let index = match self.history_index {
+            Some(index) => index + 1,
+            None => return false,
         };
 
-        if dirty {
-            self.update_suggestions()?;
+        if index < self.history.len() {
+            self.history_index = Some(index);
+            self.replace_input(&self.history[index].clone());
+        } else {
+            self.history_index = None;
+            self.replace_input(&self.history_draft.clone());
         }
 
-        Ok(())
+        true
     }
 
-    fn get_current_answer(&self) -> &str {
-        // If there is a highlighted suggestion, assume user wanted it as
-        // the answer.
-        if let Some(suggestion) = self.get_highlighted_suggestion() {
-            return suggestion;
-        }
+    /// Index of the most recent history entry before `end` containing the
+    /// query of the history search.
+    fn find_history_match(&self, end: usize) -> Option<usize> {
+        let query = self.history_search.as_deref().filter(|q| !q.is_empty())?;
 
-        // Empty input with default values override any validators.
-        if self.input.content().is_empty() {
-            if let Some(val) = self.default {
-                return val;
+        self.history[..end]
+            .iter()
+            .rposition(|entry| entry.contains(query))
+    }
+
+    /// Processes a key press while searching the history, as in the
+    /// reverse-i-search mode of shells.
+    fn on_history_search_key(&mut self, key: Key) {
+        let query = match &mut self.history_search {
+            Some(query) => query,
+            None => return,
+        };
+
+        match (key, self.keymap.action(key)) {
+            (_, Some(Action::SearchHistory)) => {
+                let end = self.history_search_match.unwrap_or(self.history.len());
+                if let Some(index) = self.find_history_match(end) {
+                    self.history_search_match = Some(index);
+                }
+            }
+            (_, Some(Action::Submit)) => {
+                if let Some(index) = self.history_search_match {
+                    if self.history_index.is_none() {
+                        self.history_draft = self.input.content().to_owned();
+                    }
+                    self.history_index = Some(index);
+                    self.replace_input(&self.history[index].clone());
+                    self.request_suggestions();
+                }
+                self.history_search = None;
+            }
+            (_, Some(Action::Cancel)) => self.history_search = None,
+            (Key::Backspace, _) => {
+                query.pop();
+                self.history_search_match = self.find_history_match(self.history.len());
             }
+            (Key::Char(c, m), _) if !m.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) => {
+                query.push(c);
+                let end = self
+                    .history_search_match
+                    .map_or(self.history.len(), |index| index + 1);
+                self.history_search_match = self.find_history_match(end);
+            }
+            _ => {}
         }
 
-        self.input.content()
+        if self.history_search.is_none() {
+            self.history_search_match = None;
+        }
     }
 
+    fn common_prefix_of_suggestions(&self) -> Option<String> {
+        let (first, rest) = self.suggested_options.split_first()?;
+
+        let mut prefix_len = first.value.len();
+        for suggestion in rest {
+            prefix_len = first.value[..prefix_len]
+                .grapheme_indices(true)
+                .zip(suggestion.value.graphemes(true))
+                .find(|((_, a), b)| a != b)
+                .map_or_else(
+                    || min(prefix_len, suggestion.value.len()),
+                    |((idx, _), _)| idx,
+                );
+        }
+
+        Some(first.value[..prefix_len].to_owned())
+    }
+
+    fn complete_common_prefix(&mut self) -> bool {
+        let prefix = match self.common_prefix_of_suggestions() {
+            Some(prefix)
+                if prefix.len() > self.input.content().len()
+                    && prefix.starts_with(self.input.content()) =>
+            {
+                prefix
+            }
+            _ => return false,
+        };
+
+        let prefix = self.cut_to_max_length(&prefix);
+        if prefix.len() <= self.input.content().len() {
+            return false;
+        }
+
+        self.input = Input::new_with(prefix);
+        true
+    }
+
+    fn handle_tab_key(&mut self) -> bool {
+        self.flush_stale_suggestions();
+
+        if self.common_prefix_completion
+            && self.get_highlighted_suggestion().is_none()
+            && self.complete_common_prefix()
+        {
+            return true;
+        }
+
+        let suggestion = self.get_picked_suggestion().map(Cow::into_owned);
+        match self
+            .autocompleter
+            .get_completion(self.input.content(), suggestion)
+        {
+            Ok(Replacement::Some(value)) => {
+                self.replace_input(&value);
+                true
+            }
+            Ok(Replacement::None) => false,
+            Err(err) => {
+                self.autocompleter_error = Some(ErrorMessage::Custom(err.to_string()));
+                false
+            }
+        }
+    }
+
+    /// Cuts `content` at the maximum length, as the characters typed past it
+    /// are ignored, ringing the bell when anything is cut.
+    fn cut_to_max_length(&mut self, content: &str) -> String {
+        match self.max_length {
+            Some(max_length) if content.graphemes(true).count() > max_length => {
+                self.ring_bell = self.audible_errors;
+                content.graphemes(true).take(max_length).collect()
+            }
+            _ => content.to_owned(),
+        }
+    }
+
+    /// Replaces the whole input with `content`, cut at the maximum length.
+    ///
+    /// The cached result of the live validation no longer applies, so the
+    /// next submission runs the validators again unless it is updated.
+    fn replace_input(&mut self, content: &str) {
+        let content = self.cut_to_max_length(content);
+        self.input = Input::new_with(content);
+        self.live_validation_result = None;
+    }
+
+    fn handle_input_key(&mut self, key: Key) -> bool {
+        match self.max_length {
+            Some(max_length) => {
+                let previous = self.input.clone();
+                let dirty = self.input.handle_key(key);
+
+                if self.input.length() > max_length {
+                    self.input = previous;
+                    self.ring_bell = self.audible_errors;
+                    false
+                } else {
+                    dirty
+                }
+            }
+            None => self.input.handle_key(key),
+        }
+    }
+
+    fn on_change(&mut self, key: Key) -> InquireResult<()> {
+        let dirty = match self.keymap.action(key) {
+            Some(Action::MoveUp) => {
+                self.flush_stale_suggestions();
+                self.move_cursor_up(1)
+            }
+            Some(Action::MoveDown) => {
+                self.flush_stale_suggestions();
+                self.move_cursor_down(1)
+            }
+            Some(Action::Complete) => self.handle_tab_key(),
+            Some(Action::DeleteWord) => self.input.delete_previous_word(),
+            Some(Action::ToggleHelp) => {
+                self.show_help = !self.show_help;
+                false
+            }
+            Some(Action::SearchHistory) => {
+                self.history_search = Some(String::new());
+                false
+            }
+            Some(Action::Submit) | Some(Action::Cancel) => false,
+
+            None => match key {
+                Key::PageUp => self.move_cursor_up(self.page_size),
+                Key::PageDown => self.move_cursor_down(self.page_size),
+
+                key => self.handle_input_key(key),
+            },
+        };
+
+        if dirty {
+            self.request_suggestions();
+        }
+
+        self.update_live_validation()
+    }
+
+    /// Runs the validators on the current answer when validating live,
+    /// displaying and caching their result for the next submission.
+    fn update_live_validation(&mut self) -> InquireResult<()> {
+        if self.live_validation {
+            let validation = self.validate_current_answer()?;
+
+            self.error = match &validation {
+                Validation::Invalid(msg) => Some(msg.clone()),
+                _ => None,
+            };
+            self.warning = match &validation {
+                Validation::Warning(msg) => Some(msg.clone()),
+                _ => None,
+            };
+            self.live_validation_result = Some(validation);
+        }
+
+        Ok(())
+    }
+
+    /// Whether the autocompletion key accepts the default value instead, which
+    /// is the case when the input is empty and there are no suggestions to
+    /// complete it with.
+    fn can_accept_default(&self) -> bool {
+        self.default.is_some()
+            && self.input.is_empty()
+            && self.suggested_options.is_empty()
+            && !self.suggestions_stale
+    }
+
+    /// Help message displayed when none is set, hinting at the keys relevant
+    /// to the current state of the prompt.
+    fn default_help_message(&self, has_suggestions: bool) -> Option<&'static str> {
+        if !self.default_help_messages {
+            None
+        } else if has_suggestions {
+            Some(DEFAULT_HELP_MESSAGE_WITH_AC)
+        } else if self.can_accept_default() {
+            Some(DEFAULT_HELP_MESSAGE_WITH_DEFAULT)
+        } else if self.multiline {
+            Some(DEFAULT_HELP_MESSAGE_MULTILINE)
+        } else {
+            None
+        }
+    }
+
+    fn get_current_answer(&self) -> Cow<'_, str> {
+        // If there is a highlighted suggestion, assume user wanted it as
+        // the answer.
+        if let Some(suggestion) = self.get_picked_suggestion() {
+            return suggestion;
+        }
+
+        let content = self.trim.apply(self.input.content());
+
+        // Empty input with default values override any validators.
+        if content.is_empty() {
+            if let Some(val) = self.default {
+                return Cow::Borrowed(val);
+            }
+        }
+
+        Cow::Borrowed(content)
+    }
+
+    /// Runs the validators on the current answer, stopping at the first
+    /// error. Otherwise, the first warning is returned, if any.
     fn validate_current_answer(&self) -> InquireResult<Validation> {
+        let mut result = Validation::Valid;
+
         for validator in &self.validators {
-            match validator.validate(self.get_current_answer()) {
+            match validator.validate(&self.get_current_answer()) {
                 Ok(Validation::Valid) => {}
                 Ok(Validation::Invalid(msg)) => return Ok(Validation::Invalid(msg)),
+                Ok(Validation::Warning(msg)) => {
+                    if result == Validation::Valid {
+                        result = Validation::Warning(msg);
+                    }
+                }
                 Err(err) => return Err(InquireError::Custom(err)),
             }
         }
 
-        Ok(Validation::Valid)
+        Ok(result)
+    }
+
+    fn submission_validation(&self) -> InquireResult<Validation> {
+        match &self.live_validation_result {
+            Some(validation) => Ok(validation.clone()),
+            None => self.validate_current_answer(),
+        }
     }
 
     fn render<B: TextBackend>(&mut self, backend: &mut B) -> InquireResult<()> {
@@ -425,8 +1467,25 @@ impl<'a> TextPrompt<'a> {
 
         backend.frame_setup()?;
 
+        if self.ring_bell {
+            backend.ring_bell()?;
+            self.ring_bell = false;
+        }
+
+        if let Some(items) = &self.list_items {
+            for item in items {
+                backend.render_list_item(item)?;
+            }
+        }
+
+        if let Some(err) = &self.autocompleter_error {
+            backend.render_error_message(err)?;
+        }
+
         if let Some(err) = &self.error {
             backend.render_error_message(err)?;
+        } else if let Some(warning) = &self.warning {
+            backend.render_warning_message(warning)?;
         }
 
         backend.render_prompt(prompt, self.default, &self.input)?;
@@ -435,8 +1494,8 @@ impl<'a> TextPrompt<'a> {
             .suggested_options
             .iter()
             .enumerate()
-            .map(|(i, val)| ListOption::new(i, val.as_ref()))
-            .collect::<Vec<ListOption<&str>>>();
+            .map(|(i, val)| ListOption::new(i, val))
+            .collect::<Vec<ListOption<&Suggestion>>>();
 
         let list_index = self.cursor_index.saturating_sub(1);
         let mut page = paginate(self.page_size, &choices, list_index);
@@ -448,10 +1507,33 @@ impl<'a> TextPrompt<'a> {
 
         backend.render_suggestions(page)?;
 
-        if let Some(message) = self.help_message {
+        if self.hidden_suggestions > 0 {
+            backend.render_hidden_suggestions(self.hidden_suggestions)?;
+        }
+
+        if let (Some(preview), Some(suggestion)) = (self.preview, self.get_highlighted_suggestion())
+        {
+            backend.render_preview(&preview(suggestion))?;
+        }
+
+        if let Some(query) = &self.history_search {
+            let (label, entry) = match self.history_search_match {
+                Some(index) => ("reverse-i-search", self.history[index].as_str()),
+                None if query.is_empty() => ("reverse-i-search", ""),
+                None => ("failing reverse-i-search", ""),
+            };
+            backend.render_help_message(&format!("({})`{}': {}", label, query, entry))?;
+        } else if self.cancel_pending {
+            backend.render_help_message(CONFIRM_CANCEL_MESSAGE)?;
+        } else if self.show_help {
+            backend.render_help_panel(&self.keymap.help_lines())?;
+        } else if self.suggestions_stale && self.can_poll {
+            let frame = SPINNER_FRAMES[self.spinner_frame % SPINNER_FRAMES.len()];
+            backend.render_help_message(&format!("{} Loading suggestions...", frame))?;
+        } else if let Some(message) = self.help_message {
+            backend.render_help_message(message)?;
+        } else if let Some(message) = self.default_help_message(!choices.is_empty()) {
             backend.render_help_message(message)?;
-        } else if !choices.is_empty() {
-            backend.render_help_message(DEFAULT_HELP_MESSAGE_WITH_AC)?;
         }
 
         backend.frame_finish()?;
@@ -459,45 +1541,247 @@ impl<'a> TextPrompt<'a> {
         Ok(())
     }
 
-    fn prompt<B: TextBackend>(mut self, backend: &mut B) -> InquireResult<String> {
+    /// Processes a single key press, returning the final answer once the user
+    /// submits a valid one.
+    ///
+    /// This is independent of how keys are read, so that the prompt's state
+    /// machine is not tied to the blocking loop below.
+    fn handle_key(&mut self, key: Key) -> InquireResult<Option<String>> {
+        let cancel_pending = self.cancel_pending;
+        self.cancel_pending = false;
+
+        match key {
+            Key::Interrupt if self.confirm_cancel && !cancel_pending => self.cancel_pending = true,
+            Key::Interrupt => interrupt_prompt!(),
+            key if self.history_search.is_some() => {
+                self.on_history_search_key(key);
+                self.update_live_validation()?;
+            }
+            Key::Char('d', m) if self.multiline && m.contains(KeyModifiers::CONTROL) => {
+                return self.submit()
+            }
+            key => match self.keymap.action(key) {
+                Some(Action::Cancel) => return Err(InquireError::OperationCanceled),
+                Some(Action::Submit) if self.multiline => {
+                    self.on_change(Key::Char('\n', KeyModifiers::NONE))?
+                }
+                Some(Action::Submit) => return self.submit(),
+                Some(Action::Complete) if self.can_accept_default() => return self.submit(),
+                _ => self.on_change(key)?,
+            },
+        }
+
+        Ok(None)
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<String>> {
+        // a highlighted suggestion past the maximum length is not submitted,
+        // but completed to the input it is cut to, for the user to review
+        let picked = self.get_picked_suggestion().map(Cow::into_owned);
+        if let Some(suggestion) = picked {
+            let cut = self.cut_to_max_length(&suggestion);
+            if cut.len() < suggestion.len() {
+                self.replace_input(&cut);
+                self.cursor_index = 0;
+                self.request_suggestions();
+                return Ok(None);
+            }
+        }
+
+        if self.list_items.is_some() && self.get_current_answer().is_empty() {
+            return Ok(Some(String::new()));
+        }
+
+        match self.submission_validation()? {
+            Validation::Valid | Validation::Warning(_) => {
+                return Ok(Some(self.get_current_answer().into_owned()))
+            }
+            Validation::Invalid(msg) => {
+                self.failed_attempts += 1;
+                if let Some(max_attempts) = self.max_attempts {
+                    if self.failed_attempts >= max_attempts {
+                        return Err(InquireError::TooManyAttempts);
+                    }
+                }
+
+                self.error = Some(msg);
+                self.ring_bell = self.audible_errors;
+            }
+        }
+
+        Ok(None)
+    }
+
+    fn confirm<B: TextBackend + CustomTypeBackend>(
+        &self,
+        backend: &mut B,
+        message: &str,
+        answer: &str,
+    ) -> InquireResult<bool> {
+        let formatted = (self.formatter)(answer);
+
+        backend.frame_setup()?;
+        backend.render_prompt_with_answer(self.message, &formatted)?;
+        backend.frame_finish()?;
+
+        // keeps the answer on screen, above the confirmation question
+        backend.frame_setup_below()?;
+        let confirmed = CustomType::from(Confirm::new(message)).prompt_with_backend(backend)?;
+
+        // keeps the declined confirmation on screen, above the prompt being
+        // edited again
+        if !confirmed {
+            backend.frame_setup_below()?;
+        }
+
+        Ok(confirmed)
+    }
+
+    /// Returns whether the current answer is the default value, substituted
+    /// for an empty input.
+    fn is_default_answer(&self) -> bool {
+        self.default.is_some()
+            && self.trim.apply(self.input.content()).is_empty()
+            && self.get_highlighted_suggestion().is_none()
+    }
+
+    fn prompt<B: TextBackend + CustomTypeBackend>(
+        mut self,
+        backend: &mut B,
+    ) -> InquireResult<(String, bool)> {
         let final_answer: String;
-        self.update_suggestions()?;
+        let used_default: bool;
+        self.can_poll = backend.can_poll();
+        self.update_suggestions();
+
+        if self.clear_screen {
+            backend.clear_screen()?;
+        }
 
         loop {
             self.render(backend)?;
 
-            let key = backend.read_key()?;
+            let pending = Some(CONFIRM_CANCEL_WINDOW).filter(|_| self.cancel_pending);
+            let debounce = self.suggestion_debounce.filter(|_| self.suggestions_stale);
+            let key = match (pending, debounce, self.timeout, self.default) {
+                (Some(window), _, _, _) => match backend.read_key_timeout(window)? {
+                    Some(key) => key,
+                    None => {
+                        self.cancel_pending = false;
+                        continue;
+                    }
+                },
+                (None, Some(debounce), _, _) => {
+                    let wait = min(SPINNER_INTERVAL, debounce.saturating_sub(self.stale_for));
+                    match backend.read_key_timeout(wait)? {
+                        Some(key) => key,
+                        None => {
+                            self.stale_for += wait;
+                            if self.stale_for >= debounce {
+                                self.update_suggestions();
+                            } else {
+                                self.spinner_frame = self.spinner_frame.wrapping_add(1);
+                            }
+                            continue;
+                        }
+                    }
+                }
+                (None, None, Some(timeout), Some(_)) => {
+                    match backend.read_key_timeout(timeout)? {
+                        Some(key) => key,
+                        None => {
+                            // discards the partial input, submitting the default
+                            // instead without confirmation
+                            self.input.clear();
+                            self.cursor_index = 0;
+                            self.live_validation_result = None;
+
+                            match self.submit()? {
+                                Some(answer) => {
+                                    final_answer = answer;
+                                    used_default = true;
+                                    break;
+                                }
+                                None => {
+                                    // the rejected default would be submitted again on
+                                    // every timeout, so the user is awaited instead
+                                    self.timeout = None;
+                                    continue;
+                                }
+                            }
+                        }
+                    }
+                }
+                _ => backend.read_key()?,
+            };
+            let result = self.handle_key(key);
+
+            if let Some(event_hook) = self.event_hook {
+                event_hook(&key, self.input.content());
+            }
+
+            if let Some(debug_log) = self.debug_log {
+                let _ = writeln!(
+                    debug_log.borrow_mut(),
+                    "{} -> {:?}",
+                    key,
+                    self.input.content()
+                );
+            }
 
-            match key {
-                Key::Interrupt => interrupt_prompt!(),
-                Key::Cancel => cancel_prompt!(backend, self.message),
-                Key::Submit => match self.validate_current_answer()? {
-                    Validation::Valid => {
-                        final_answer = self.get_current_answer().to_owned();
+            match result {
+                Ok(Some(answer)) => match self.confirmation {
+                    Some(message) => {
+                        if self.confirm(backend, message, &answer)? {
+                            return Ok((answer, self.is_default_answer()));
+                        }
+                    }
+                    None => {
+                        used_default = self.is_default_answer();
+                        final_answer = answer;
                         break;
                     }
-                    Validation::Invalid(msg) => self.error = Some(msg),
                 },
-                key => self.on_change(key)?,
+                Ok(None) => {}
+                Err(InquireError::OperationCanceled) => cancel_prompt!(backend, self.message),
+                Err(err) => return Err(err),
             }
         }
 
         let formatted = (self.formatter)(&final_answer);
 
-        finish_prompt_with_answer!(backend, self.message, &formatted, final_answer);
+        finish_prompt_with_answer!(
+            backend,
+            self.message,
+            &formatted,
+            (final_answer, used_default)
+        );
     }
 }
 
 #[cfg(test)]
 #[cfg(feature = "crossterm")]
 mod test {
-    use super::Text;
+    use std::{
+        cell::{Cell, RefCell},
+        path::PathBuf,
+        rc::Rc,
+        time::Duration,
+    };
+
+    use super::{Text, TrimMode, DEFAULT_HELP_MESSAGE_MULTILINE, DEFAULT_HELP_MESSAGE_WITH_AC};
     use crate::{
-        terminal::crossterm::CrosstermTerminal,
-        ui::{Backend, RenderConfig},
-        validator::{ErrorMessage, Validation},
+        autocompletion::{Autocomplete, Replacement, Suggestion},
+        error::InquireError,
+        scripted::{ScriptedAction, ScriptedEvents},
+        terminal::crossterm::{rendered_screen, CrosstermTerminal},
+        ui::{self, Action, AnswerLayout, Backend, Keymap, RenderConfig, Styled},
+        validator::{
+            ErrorMessage, StringValidator, Validation, ValidationError, ValueRequiredValidator,
+        },
+        CustomUserError,
     };
-    use crossterm::event::{KeyCode, KeyEvent};
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
     fn default<'a>() -> Text<'a> {
         Text::new("Question?")
@@ -518,7 +1802,7 @@ mod test {
             #[test]
             fn $name() {
                 let read: Vec<KeyEvent> = $input.into_iter().map(KeyEvent::from).collect();
-                let mut read = read.iter();
+                let mut read = read.into_iter();
 
                 let mut write: Vec<u8> = Vec::new();
 
@@ -564,6 +1848,25 @@ mod test {
         "normal input"
     );
 
+    #[test]
+    fn scripted_input_and_correction() {
+        let script = vec![
+            ScriptedAction::Type("anor".into()),
+            ScriptedAction::Backspace,
+            ScriptedAction::Backspace,
+            ScriptedAction::Backspace,
+            ScriptedAction::Backspace,
+            ScriptedAction::Type("normal input".into()),
+            ScriptedAction::Submit,
+        ];
+        let mut events = ScriptedEvents::new(script.into_iter());
+        let mut write: Vec<u8> = Vec::new();
+
+        let ans = default().prompt_with_io(&mut write, &mut events).unwrap();
+
+        assert_eq!("normal input", ans);
+    }
+
     text_test!(
         input_and_excessive_correction,
         {
@@ -586,6 +1889,134 @@ mod test {
         "normal input"
     );
 
+    text_test!(
+        insert_in_the_middle_after_left_arrow,
+        {
+            let mut events = vec![];
+            events.append(&mut text_to_events!("helo").collect());
+            events.push(KeyCode::Left);
+            events.push(KeyCode::Char('l'));
+            events.push(KeyCode::Enter);
+            events
+        },
+        "hello"
+    );
+
+    text_test!(
+        right_arrow_after_left_arrow,
+        {
+            let mut events = vec![];
+            events.append(&mut text_to_events!("ac").collect());
+            events.push(KeyCode::Left);
+            events.push(KeyCode::Left);
+            events.push(KeyCode::Right);
+            events.push(KeyCode::Char('b'));
+            events.push(KeyCode::Enter);
+            events
+        },
+        "abc"
+    );
+
+    text_test!(
+        delete_forward_in_the_middle,
+        {
+            let mut events = vec![];
+            events.append(&mut text_to_events!("abcd").collect());
+            events.push(KeyCode::Left);
+            events.push(KeyCode::Left);
+            events.push(KeyCode::Delete);
+            events.push(KeyCode::Enter);
+            events
+        },
+        "abd"
+    );
+
+    text_test!(
+        backspace_in_the_middle_with_emoji,
+        {
+            let mut events = vec![];
+            events.append(&mut text_to_events!("🧘🏻‍♂️🌍🍞").collect());
+            events.push(KeyCode::Left);
+            events.push(KeyCode::Backspace);
+            events.push(KeyCode::Enter);
+            events
+        },
+        "🧘🏻‍♂️🍞"
+    );
+
+    text_test!(
+        home_and_end_keys,
+        {
+            let mut events = vec![];
+            events.append(&mut text_to_events!("world").map(KeyEvent::from).collect());
+            events.push(KeyEvent::from(KeyCode::Home));
+            events.append(&mut text_to_events!("hello ").map(KeyEvent::from).collect());
+            events.push(KeyEvent::from(KeyCode::End));
+            events.push(KeyEvent::from(KeyCode::Char('!')));
+            events.push(KeyEvent::from(KeyCode::Enter));
+            events
+        },
+        "hello world!"
+    );
+
+    text_test!(
+        ctrl_arrows_move_by_word,
+        {
+            let mut events = vec![];
+            events.append(&mut text_to_events!("hello world").map(KeyEvent::from).collect());
+            events.push(KeyEvent::new(KeyCode::Left, KeyModifiers::CONTROL));
+            events.append(&mut text_to_events!("big ").map(KeyEvent::from).collect());
+            events.push(KeyEvent::new(KeyCode::Left, KeyModifiers::CONTROL));
+            events.push(KeyEvent::new(KeyCode::Left, KeyModifiers::CONTROL));
+            events.push(KeyEvent::new(KeyCode::Right, KeyModifiers::CONTROL));
+            events.push(KeyEvent::from(KeyCode::Char(',')));
+            events.push(KeyEvent::from(KeyCode::Enter));
+            events
+        },
+        "hello, big world"
+    );
+
+    text_test!(
+        alt_b_and_alt_f_move_by_word,
+        {
+            let mut events = vec![];
+            events.append(&mut text_to_events!("one three").map(KeyEvent::from).collect());
+            events.push(KeyEvent::new(KeyCode::Char('b'), KeyModifiers::ALT));
+            events.push(KeyEvent::new(KeyCode::Char('b'), KeyModifiers::ALT));
+            events.push(KeyEvent::new(KeyCode::Char('f'), KeyModifiers::ALT));
+            events.append(&mut text_to_events!(" two").map(KeyEvent::from).collect());
+            events.push(KeyEvent::from(KeyCode::Enter));
+            events
+        },
+        "one two three"
+    );
+
+    text_test!(
+        max_length_ignores_extra_characters,
+        {
+            let mut events = vec![];
+            events.append(&mut text_to_events!("abcdefgh").collect());
+            events.push(KeyCode::Enter);
+            events
+        },
+        "abcde",
+        Text::new("").with_max_length(5)
+    );
+
+    text_test!(
+        max_length_allows_editing_after_limit,
+        {
+            let mut events = vec![];
+            events.append(&mut text_to_events!("a🌍cdefg").collect());
+            events.push(KeyCode::Backspace);
+            events.append(&mut text_to_events!("xy").collect());
+            events.push(KeyCode::Enter);
+            events
+        },
+        "a🌍cdx",
+        Text::new("").with_max_length(5)
+    );
+
     text_test!(
         input_correction_after_validation,
         {
@@ -607,4 +2038,1933 @@ mod test {
             _ => Ok(Validation::Invalid(ErrorMessage::Default)),
         })
     );
+
+    #[derive(Clone)]
+    struct CommandCompleter;
+
+    impl Autocomplete for CommandCompleter {
+        fn get_suggestions(&mut self, input: &str) -> Result<Vec<String>, CustomUserError> {
+            Ok(self
+                .get_described_suggestions(input)?
+                .into_iter()
+                .map(|s| s.value)
+                .collect())
+        }
+
+        fn get_described_suggestions(
+            &mut self,
+            input: &str,
+        ) -> Result<Vec<Suggestion>, CustomUserError> {
+            Ok(vec![
+                Suggestion::new("checkout").with_description("Switch branches"),
+                Suggestion::new("commit").with_description("Record changes"),
+                Suggestion::new("clone"),
+            ]
+            .into_iter()
+            .filter(|s| s.value.starts_with(input))
+            .collect())
+        }
+
+        fn get_completion(
+            &mut self,
+            _: &str,
+            highlighted_suggestion: Option<String>,
+        ) -> Result<Replacement, CustomUserError> {
+            Ok(highlighted_suggestion)
+        }
+    }
+
+    #[test]
+    fn suggestion_descriptions_are_rendered_but_not_completed() {
+        let mut read: Vec<KeyEvent> = text_to_events!("co").map(KeyEvent::from).collect();
+        read.push(KeyEvent::from(KeyCode::Down));
+        read.push(KeyEvent::from(KeyCode::Tab));
+        read.push(KeyEvent::from(KeyCode::Enter));
+        let mut read = read.into_iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        {
+            let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+            let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+            let ans = Text::new("Command?")
+                .with_autocomplete(CommandCompleter)
+                .prompt_with_backend(&mut backend)
+                .unwrap();
+
+            assert_eq!("commit", ans);
+        }
+
+        let output = String::from_utf8(write).unwrap();
+        assert!(output.contains("commit Record changes"));
+    }
+
+    fn git_suggester(input: &str) -> Result<Vec<String>, CustomUserError> {
+        Ok(["git status", "git stash", "git commit"]
+            .iter()
+            .filter(|s| s.starts_with(input))
+            .map(|s| String::from(*s))
+            .collect())
+    }
+
+    text_test!(
+        tab_completes_common_prefix,
+        vec![KeyCode::Char('g'), KeyCode::Tab, KeyCode::Enter],
+        "git ",
+        default()
+            .with_autocomplete(&git_suggester)
+            .with_common_prefix_completion(true)
+    );
+
+    text_test!(
+        tab_at_common_prefix_completes_highlighted_suggestion,
+        {
+            let mut events = vec![];
+            events.append(&mut text_to_events!("git st").collect());
+            events.push(KeyCode::Tab);
+            events.push(KeyCode::Tab);
+            events.push(KeyCode::Down);
+            events.push(KeyCode::Down);
+            events.push(KeyCode::Tab);
+            events.push(KeyCode::Enter);
+            events
+        },
+        "git stash",
+        default()
+            .with_autocomplete(&git_suggester)
+            .with_common_prefix_completion(true)
+    );
+
+    text_test!(
+        common_prefix_completion_is_cut_at_max_length,
+        vec![KeyCode::Char('g'), KeyCode::Tab, KeyCode::Enter],
+        "gi",
+        default()
+            .with_autocomplete(&git_suggester)
+            .with_common_prefix_completion(true)
+            .with_max_length(2)
+    );
+
+    #[test]
+    fn audible_errors_ring_bell_on_cut_common_prefix_completion() {
+        let events = vec![KeyCode::Char('g'), KeyCode::Tab, KeyCode::Enter]
+            .into_iter()
+            .map(KeyEvent::from)
+            .collect();
+        let prompt = default()
+            .with_autocomplete(&git_suggester)
+            .with_common_prefix_completion(true)
+            .with_max_length(2)
+            .with_audible_errors(true);
+
+        let output = rendered_output(prompt, events);
+
+        assert_eq!(1, output.matches('\x07').count());
+    }
+
+    fn max_length_answer_and_output(prompt: Text<'_>, events: Vec<KeyEvent>) -> (String, String) {
+        answer_and_output(prompt.with_max_length(2).with_audible_errors(true), events)
+    }
+
+    #[test]
+    fn autocompleter_replacement_is_cut_at_max_length() {
+        let events = vec![KeyCode::Down, KeyCode::Tab, KeyCode::Enter]
+            .into_iter()
+            .map(KeyEvent::from)
+            .collect();
+        let prompt = default().with_autocomplete(&git_suggester);
+
+        let (ans, output) = max_length_answer_and_output(prompt, events);
+
+        assert_eq!("gi", ans);
+        assert_eq!(1, output.matches('\x07').count());
+        assert!(output.contains("Question? gi\r\n"));
+    }
+
+    #[test]
+    fn submitted_suggestion_is_cut_at_max_length() {
+        let events = vec![KeyCode::Down, KeyCode::Enter, KeyCode::Enter]
+            .into_iter()
+            .map(KeyEvent::from)
+            .collect();
+        let prompt = default().with_autocomplete(&git_suggester);
+
+        let (ans, output) = max_length_answer_and_output(prompt, events);
+
+        assert_eq!("gi", ans);
+        assert_eq!(1, output.matches('\x07').count());
+        assert!(output.contains("Question? gi\r\n"));
+    }
+
+    #[test]
+    fn recalled_history_entry_is_cut_at_max_length() {
+        let events = vec![KeyCode::Up, KeyCode::Enter]
+            .into_iter()
+            .map(KeyEvent::from)
+            .collect();
+        let prompt = default().with_history(command_history());
+
+        let (ans, output) = max_length_answer_and_output(prompt, events);
+
+        assert_eq!("ca", ans);
+        assert_eq!(1, output.matches('\x07').count());
+        assert!(output.contains("Question? ca\r\n"));
+    }
+
+    #[test]
+    fn history_search_match_is_cut_at_max_length() {
+        let mut events = vec![ctrl_r()];
+        events.extend(text_to_events!("stat").map(KeyEvent::from));
+        events.push(KeyEvent::from(KeyCode::Enter));
+        events.push(KeyEvent::from(KeyCode::Enter));
+        let prompt = default().with_history(command_history());
+
+        let (ans, output) = max_length_answer_and_output(prompt, events);
+
+        assert_eq!("gi", ans);
+        assert_eq!(1, output.matches('\x07').count());
+        assert!(output.contains("Question? gi\r\n"));
+    }
+
+    text_test!(
+        tab_without_common_prefix_completion_keeps_input,
+        vec![KeyCode::Char('g'), KeyCode::Tab, KeyCode::Enter],
+        "g",
+        default().with_autocomplete(&git_suggester)
+    );
+
+    fn min_length_validator(calls: Rc<Cell<usize>>) -> impl StringValidator {
+        move |ans: &str| {
+            calls.set(calls.get() + 1);
+            match ans.len() {
+                len if len >= 3 => Ok(Validation::Valid),
+                _ => Ok(Validation::Invalid("Too short".into())),
+            }
+        }
+    }
+
+    #[test]
+    fn max_attempts_fails_after_rejected_submissions() {
+        let calls = Rc::new(Cell::new(0));
+        let mut read = text_to_events!("a\nb\nabc\n").map(KeyEvent::from);
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+        let ans = default()
+            .with_validator(min_length_validator(calls.clone()))
+            .with_max_attempts(2)
+            .prompt_with_backend(&mut backend);
+
+        assert!(matches!(ans, Err(InquireError::TooManyAttempts)));
+        assert_eq!(2, calls.get());
+    }
+
+    text_test!(
+        max_attempts_allows_valid_submission_before_the_limit,
+        text_to_events!("a\nbc\n"),
+        "abc",
+        default()
+            .with_validator(min_length_validator(Rc::default()))
+            .with_max_attempts(2)
+    );
+
+    #[test]
+    fn live_validation_renders_error_before_submit() {
+        let read: Vec<KeyEvent> = text_to_events!("abc\n").map(KeyEvent::from).collect();
+        let mut read = read.into_iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        {
+            let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+            let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+            let ans = default()
+                .with_validator(min_length_validator(Rc::new(Cell::new(0))))
+                .with_live_validation(true)
+                .prompt_with_backend(&mut backend)
+                .unwrap();
+
+            assert_eq!("abc", ans);
+        }
+
+        assert!(String::from_utf8(write).unwrap().contains("Too short"));
+    }
+
+    #[test]
+    fn live_validation_blocks_invalid_submission() {
+        let mut read: Vec<KeyEvent> = text_to_events!("ab\n").map(KeyEvent::from).collect();
+        read.push(KeyEvent::from(KeyCode::Char('c')));
+        read.push(KeyEvent::from(KeyCode::Enter));
+        let mut read = read.into_iter();
+
+        let calls = Rc::new(Cell::new(0));
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+        let ans = default()
+            .with_validator(min_length_validator(calls.clone()))
+            .with_live_validation(true)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!("abc", ans);
+        // one validation per keystroke, submissions reuse the latest result
+        assert_eq!(3, calls.get());
+    }
+
+    fn live_validated_history_prompt<'a>() -> Text<'a> {
+        default()
+            .with_validator(min_length_validator(Rc::default()))
+            .with_live_validation(true)
+            .with_history(vec![String::from("xyz"), String::from("ab")])
+    }
+
+    #[test]
+    fn live_validation_rejects_recalled_invalid_entry() {
+        let mut events: Vec<KeyEvent> = text_to_events!("abcd").map(KeyEvent::from).collect();
+        events.push(KeyEvent::from(KeyCode::Up));
+        events.extend(text_to_events!("\nc\n").map(KeyEvent::from));
+
+        let (ans, output) = answer_and_output(live_validated_history_prompt(), events);
+
+        assert_eq!("abc", ans);
+        assert!(output.contains("Too short"));
+    }
+
+    #[test]
+    fn live_validation_rejects_invalid_history_search_match() {
+        let mut events: Vec<KeyEvent> = text_to_events!("abcd").map(KeyEvent::from).collect();
+        events.push(ctrl_r());
+        events.extend(text_to_events!("ab\n\nc\n").map(KeyEvent::from));
+
+        let (ans, output) = answer_and_output(live_validated_history_prompt(), events);
+
+        assert_eq!("abc", ans);
+        assert!(output.contains("Too short"));
+    }
+
+    #[test]
+    fn first_failing_validator_error_is_displayed() {
+        let mut read: Vec<KeyEvent> = text_to_events!("ab\n").map(KeyEvent::from).collect();
+        read.append(&mut text_to_events!("c\n").map(KeyEvent::from).collect());
+        read.append(&mut text_to_events!("1\n").map(KeyEvent::from).collect());
+        let mut read = read.into_iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        {
+            let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+            let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+            let ans = default()
+                .with_validator(|ans: &str| match ans.len() {
+                    len if len >= 3 => Ok(Validation::Valid),
+                    _ => Ok(Validation::Invalid("Too short".into())),
+                })
+                .with_validator(|ans: &str| match ans.chars().any(|c| c.is_ascii_digit()) {
+                    true => Ok(Validation::Valid),
+                    false => Ok(Validation::Invalid("Needs a digit".into())),
+                })
+                .prompt_with_backend(&mut backend)
+                .unwrap();
+
+            assert_eq!("abc1", ans);
+        }
+
+        let output = String::from_utf8(write).unwrap();
+        let too_short = output
+            .find("Too short")
+            .expect("first error was not rendered");
+        let needs_digit = output
+            .find("Needs a digit")
+            .expect("second error was not rendered");
+
+        assert!(too_short < needs_digit);
+    }
+
+    #[test]
+    fn ctrl_c_interrupts_prompt() {
+        let mut read: Vec<KeyEvent> = text_to_events!("abc").map(KeyEvent::from).collect();
+        read.push(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL));
+        let mut read = read.into_iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+        let ans = default().prompt_with_backend(&mut backend);
+
+        assert!(matches!(ans, Err(InquireError::OperationInterrupted)));
+    }
+
+    #[test]
+    fn esc_cancels_prompt() {
+        let mut read: Vec<KeyEvent> = text_to_events!("abc").map(KeyEvent::from).collect();
+        read.push(KeyEvent::from(KeyCode::Esc));
+        let mut read = read.into_iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        {
+            let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+            let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+            let ans = default().prompt_with_backend(&mut backend);
+
+            assert!(matches!(ans, Err(InquireError::OperationCanceled)));
+        }
+
+        let output = String::from_utf8(write).unwrap();
+        assert!(output.contains("Question? <canceled>"));
+    }
+
+    text_test!(
+        custom_keymap_navigation,
+        vec![
+            KeyCode::Char('g'),
+            KeyCode::Char('j'),
+            KeyCode::Char('j'),
+            KeyCode::Char('k'),
+            KeyCode::Enter,
+        ],
+        "git status",
+        default().with_autocomplete(&git_suggester).with_keymap(
+            Keymap::default()
+                .with_binding(ui::Key::Char('j', ui::KeyModifiers::NONE), Action::MoveDown)
+                .with_binding(ui::Key::Char('k', ui::KeyModifiers::NONE), Action::MoveUp)
+        )
+    );
+
+    text_test!(
+        vim_keymap_navigation_and_completion,
+        vec![
+            KeyEvent::from(KeyCode::Char('g')),
+            KeyEvent::new(KeyCode::Char('n'), KeyModifiers::CONTROL),
+            KeyEvent::new(KeyCode::Char('n'), KeyModifiers::CONTROL),
+            KeyEvent::new(KeyCode::Char('y'), KeyModifiers::CONTROL),
+            KeyEvent::from(KeyCode::Enter),
+        ],
+        "git stash",
+        default()
+            .with_autocomplete(&git_suggester)
+            .with_keymap(Keymap::vim())
+    );
+
+    text_test!(
+        remapped_submit_key,
+        vec![
+            KeyCode::Char('a'),
+            KeyCode::Enter,
+            KeyCode::Char('b'),
+            KeyCode::Tab,
+        ],
+        "ab",
+        default().with_keymap(
+            Keymap::default()
+                .with_binding(ui::Key::Submit, Action::Complete)
+                .with_binding(ui::Key::Tab, Action::Submit)
+        )
+    );
+
+    text_test!(
+        initial_value_is_submitted_without_edits,
+        vec![KeyCode::Enter],
+        "prefilled",
+        default().with_initial_value("prefilled")
+    );
+
+    text_test!(
+        initial_value_is_editable_from_the_end,
+        vec![KeyCode::Char('!'), KeyCode::Enter],
+        "prefilled!",
+        default().with_initial_value("prefilled")
+    );
+
+    text_test!(
+        initial_value_triggers_suggestions,
+        vec![KeyCode::Down, KeyCode::Down, KeyCode::Enter],
+        "git stash",
+        default()
+            .with_initial_value("git st")
+            .with_autocomplete(&git_suggester)
+    );
+
+    #[test]
+    fn placeholder_is_rendered_only_while_input_is_empty() {
+        let read: Vec<KeyEvent> = text_to_events!("a\n").map(KeyEvent::from).collect();
+        let mut read = read.into_iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        {
+            let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+            let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+            let ans = default()
+                .with_placeholder("Type something")
+                .prompt_with_backend(&mut backend)
+                .unwrap();
+
+            assert_eq!("a", ans);
+        }
+
+        let output = String::from_utf8(write).unwrap();
+        assert_eq!(1, output.matches("Type something").count());
+        assert!(output.find("Type something") < output.find("Question? a"));
+    }
+
+    #[test]
+    fn formatter_only_affects_displayed_answer() {
+        let read: Vec<KeyEvent> = text_to_events!("ferris\n").map(KeyEvent::from).collect();
+        let mut read = read.into_iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        {
+            let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+            let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+            let ans = default()
+                .with_formatter(&|ans| ans.to_uppercase())
+                .prompt_with_backend(&mut backend)
+                .unwrap();
+
+            assert_eq!("ferris", ans);
+        }
+
+        let output = String::from_utf8(write).unwrap();
+        assert!(output.contains("Question? FERRIS"));
+    }
+
+    #[test]
+    fn confirm_cancel_ignores_single_ctrl_c() {
+        let ctrl_c = KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL);
+        let mut events: Vec<KeyEvent> = text_to_events!("ab").map(KeyEvent::from).collect();
+        events.push(ctrl_c);
+        events.extend(text_to_events!("c\n").map(KeyEvent::from));
+
+        let (ans, output) = answer_and_output(default().with_confirm_cancel(true), events);
+
+        assert_eq!("abc", ans);
+        assert!(output.contains("Press Ctrl+C again to cancel"));
+    }
+
+    #[test]
+    fn confirm_cancel_interrupts_on_second_ctrl_c() {
+        let ctrl_c = KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL);
+        let mut read = vec![ctrl_c, KeyEvent::from(KeyCode::Char('a')), ctrl_c, ctrl_c].into_iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+        let ans = default()
+            .with_confirm_cancel(true)
+            .prompt_with_backend(&mut backend);
+
+        assert!(matches!(ans, Err(InquireError::OperationInterrupted)));
+    }
+
+    fn rendered_output<'a>(prompt: Text<'a>, events: Vec<KeyEvent>) -> String {
+        answer_and_output(prompt, events).1
+    }
+
+    fn answer_and_output<'a>(prompt: Text<'a>, events: Vec<KeyEvent>) -> (String, String) {
+        let mut read = events.into_iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let ans = {
+            let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+            let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+            prompt.prompt_with_backend(&mut backend).unwrap()
+        };
+
+        (ans, String::from_utf8(write).unwrap())
+    }
+
+    #[test]
+    fn audible_errors_ring_bell_on_rejected_submission() {
+        let events = text_to_events!("\na\n").map(KeyEvent::from).collect();
+        let prompt = default()
+            .with_validator(ValueRequiredValidator::default())
+            .with_audible_errors(true);
+
+        let output = rendered_output(prompt, events);
+
+        assert_eq!(1, output.matches('\x07').count());
+    }
+
+    #[test]
+    fn audible_errors_ring_bell_on_blocked_keystroke() {
+        let events = text_to_events!("abc\n").map(KeyEvent::from).collect();
+        let prompt = default().with_max_length(2).with_audible_errors(true);
+
+        let output = rendered_output(prompt, events);
+
+        assert_eq!(1, output.matches('\x07').count());
+    }
+
+    #[test]
+    fn audible_errors_are_disabled_by_default() {
+        let events = text_to_events!("\nabc\n").map(KeyEvent::from).collect();
+        let prompt = default()
+            .with_validator(ValueRequiredValidator::default())
+            .with_max_length(2);
+
+        let output = rendered_output(prompt, events);
+
+        assert!(!output.contains('\x07'));
+    }
+
+    #[test]
+    fn clear_screen_is_skipped_on_custom_io() {
+        let events = text_to_events!("abc\n").map(KeyEvent::from).collect();
+        let prompt = default().with_clear_screen(true);
+
+        let output = rendered_output(prompt, events);
+
+        assert!(!output.contains("\x1b[2J"));
+        assert!(!output.contains("\x1b[1;1H"));
+        assert!(output.contains("abc"));
+    }
+
+    #[test]
+    fn alternate_screen_is_skipped_on_custom_io() {
+        let events = text_to_events!("abc\n").map(KeyEvent::from).collect();
+        let prompt = default().with_alternate_screen(true);
+
+        let output = rendered_output(prompt, events);
+
+        assert!(!output.contains("\x1b[?1049h"));
+        assert!(!output.contains("\x1b[?1049l"));
+        assert!(output.contains("abc"));
+    }
+
+    #[test]
+    fn alternate_screen_is_left_after_submission() {
+        let mut read = text_to_events!("abc\n").map(KeyEvent::from);
+
+        let mut write: Vec<u8> = Vec::new();
+        {
+            let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read).with_tty(true);
+            let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+            let prompt = default().with_alternate_screen(true);
+            assert_eq!("abc", prompt.prompt_with_backend(&mut backend).unwrap());
+        }
+        let output = String::from_utf8(write).unwrap();
+
+        assert_eq!(1, output.matches("\x1b[?1049h").count());
+        assert_eq!(1, output.matches("\x1b[?1049l").count());
+        assert!(output.find("\x1b[?1049h") < output.find("Question"));
+        assert!(output.rfind("\x1b[?1049l") > output.rfind("abc"));
+    }
+
+    #[test]
+    fn alternate_screen_is_left_after_cancellation() {
+        let mut read = vec![KeyEvent::from(KeyCode::Esc)].into_iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let ans = {
+            let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read).with_tty(true);
+            let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+            default()
+                .with_alternate_screen(true)
+                .prompt_with_backend(&mut backend)
+        };
+        let output = String::from_utf8(write).unwrap();
+
+        assert!(matches!(ans, Err(InquireError::OperationCanceled)));
+        assert_eq!(1, output.matches("\x1b[?1049h").count());
+        assert_eq!(1, output.matches("\x1b[?1049l").count());
+        assert!(output.find("\x1b[?1049h") < output.find("Question"));
+        assert!(output.rfind("\x1b[?1049l") > output.rfind("Question"));
+    }
+
+    fn ctrl_d() -> KeyEvent {
+        KeyEvent::new(KeyCode::Char('d'), KeyModifiers::CONTROL)
+    }
+
+    text_test!(
+        multiline_input_submitted_with_ctrl_d,
+        {
+            let mut events: Vec<KeyEvent> = text_to_events!("first\nsecond")
+                .map(KeyEvent::from)
+                .collect();
+            events.push(ctrl_d());
+            events
+        },
+        "first\nsecond",
+        default().with_multiline(true)
+    );
+
+    text_test!(
+        multiline_backspace_joins_lines,
+        {
+            let mut events: Vec<KeyEvent> = text_to_events!("ab\n").map(KeyEvent::from).collect();
+            events.push(KeyEvent::from(KeyCode::Backspace));
+            events.push(KeyEvent::from(KeyCode::Char('c')));
+            events.push(ctrl_d());
+            events
+        },
+        "abc",
+        default().with_multiline(true)
+    );
+
+    text_test!(
+        multiline_enter_splits_line_at_cursor,
+        vec![
+            KeyEvent::from(KeyCode::Char('a')),
+            KeyEvent::from(KeyCode::Char('b')),
+            KeyEvent::from(KeyCode::Left),
+            KeyEvent::from(KeyCode::Enter),
+            ctrl_d(),
+        ],
+        "a\nb",
+        default().with_multiline(true)
+    );
+
+    fn history() -> Vec<String> {
+        vec![String::from("first"), String::from("second")]
+    }
+
+    text_test!(
+        up_recalls_last_history_entry,
+        vec![KeyEvent::from(KeyCode::Up), KeyEvent::from(KeyCode::Enter)],
+        "second",
+        default().with_history(history())
+    );
+
+    text_test!(
+        up_stops_at_first_history_entry,
+        vec![
+            KeyEvent::from(KeyCode::Up),
+            KeyEvent::from(KeyCode::Up),
+            KeyEvent::from(KeyCode::Up),
+            KeyEvent::from(KeyCode::Enter),
+        ],
+        "first",
+        default().with_history(history())
+    );
+
+    text_test!(
+        down_past_history_restores_typed_input,
+        {
+            let mut events: Vec<KeyEvent> = text_to_events!("abc").map(KeyEvent::from).collect();
+            events.push(KeyEvent::from(KeyCode::Up));
+            events.push(KeyEvent::from(KeyCode::Up));
+            events.push(KeyEvent::from(KeyCode::Down));
+            events.push(KeyEvent::from(KeyCode::Down));
+            events.push(KeyEvent::from(KeyCode::Enter));
+            events
+        },
+        "abc",
+        default().with_history(history())
+    );
+
+    text_test!(
+        recalled_history_entry_can_be_edited,
+        vec![
+            KeyEvent::from(KeyCode::Up),
+            KeyEvent::from(KeyCode::Backspace),
+            KeyEvent::from(KeyCode::Char('!')),
+            KeyEvent::from(KeyCode::Enter),
+        ],
+        "secon!",
+        default().with_history(history())
+    );
+
+    fn ctrl_r() -> KeyEvent {
+        KeyEvent::new(KeyCode::Char('r'), KeyModifiers::CONTROL)
+    }
+
+    fn command_history() -> Vec<String> {
+        vec![
+            String::from("cargo build"),
+            String::from("git status"),
+            String::from("cargo test"),
+        ]
+    }
+
+    text_test!(
+        history_search_loads_matching_entry,
+        {
+            let mut events = vec![ctrl_r()];
+            events.extend(text_to_events!("stat").map(KeyEvent::from));
+            events.push(KeyEvent::from(KeyCode::Enter));
+            events.push(KeyEvent::from(KeyCode::Enter));
+            events
+        },
+        "git status",
+        default().with_history(command_history())
+    );
+
+    text_test!(
+        history_search_repeated_moves_to_older_match,
+        {
+            let mut events = vec![ctrl_r()];
+            events.extend(text_to_events!("cargo").map(KeyEvent::from));
+            events.push(ctrl_r());
+            events.push(KeyEvent::from(KeyCode::Enter));
+            events.push(KeyEvent::from(KeyCode::Enter));
+            events
+        },
+        "cargo build",
+        default().with_history(command_history())
+    );
+
+    text_test!(
+        history_search_backspace_edits_query,
+        {
+            let mut events = vec![ctrl_r()];
+            events.extend(text_to_events!("statx").map(KeyEvent::from));
+            events.push(KeyEvent::from(KeyCode::Backspace));
+            events.push(KeyEvent::from(KeyCode::Enter));
+            events.push(KeyEvent::from(KeyCode::Enter));
+            events
+        },
+        "git status",
+        default().with_history(command_history())
+    );
+
+    text_test!(
+        history_search_cancel_keeps_input,
+        {
+            let mut events: Vec<KeyEvent> = text_to_events!("abc").map(KeyEvent::from).collect();
+            events.push(ctrl_r());
+            events.extend(text_to_events!("cargo").map(KeyEvent::from));
+            events.push(KeyEvent::from(KeyCode::Esc));
+            events.push(KeyEvent::from(KeyCode::Enter));
+            events
+        },
+        "abc",
+        default().with_history(command_history())
+    );
+
+    #[test]
+    fn history_search_displays_match() {
+        let mut events = vec![ctrl_r()];
+        events.extend(text_to_events!("test").map(KeyEvent::from));
+        events.push(KeyEvent::from(KeyCode::Enter));
+        events.push(KeyEvent::from(KeyCode::Enter));
+
+        let output = rendered_output(default().with_history(command_history()), events);
+
+        assert!(output.contains("(reverse-i-search)`test': cargo test"));
+    }
+
+    fn history_file(name: &str, content: &str) -> PathBuf {
+        let path =
+            std::env::temp_dir().join(format!("inquire-{}-{}.history", name, std::process::id()));
+        std::fs::write(&path, content).unwrap();
+
+        path
+    }
+
+    #[test]
+    fn history_file_is_loaded_and_appended() {
+        let path = history_file("appended", "first\nsecond\n");
+        let mut events = vec![KeyEvent::from(KeyCode::Up)]
+            .into_iter()
+            .chain(text_to_events!("!\n").map(KeyEvent::from));
+        let mut write: Vec<u8> = Vec::new();
+
+        let ans = default()
+            .with_history_file(&path)
+            .prompt_with_io(&mut write, &mut events);
+        let content = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!("second!", ans.unwrap());
+        assert_eq!("first\nsecond\nsecond!\n", content);
+    }
+
+    #[test]
+    fn history_file_skips_consecutive_duplicates() {
+        let path = history_file("duplicates", "first\nsecond\n");
+        let mut events =
+            vec![KeyEvent::from(KeyCode::Up), KeyEvent::from(KeyCode::Enter)].into_iter();
+        let mut write: Vec<u8> = Vec::new();
+
+        let ans = default()
+            .with_history_file(&path)
+            .prompt_with_io(&mut write, &mut events);
+        let content = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!("second", ans.unwrap());
+        assert_eq!("first\nsecond\n", content);
+    }
+
+    #[test]
+    fn inaccessible_history_file_is_ignored() {
+        let path = std::env::temp_dir()
+            .join("inquire-missing-directory")
+            .join("history");
+        let mut events = text_to_events!("abc\n").map(KeyEvent::from);
+        let mut write: Vec<u8> = Vec::new();
+
+        let ans = default()
+            .with_history_file(&path)
+            .prompt_with_io(&mut write, &mut events);
+
+        assert_eq!("abc", ans.unwrap());
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn event_hook_is_called_on_every_key() {
+        let mut read: Vec<KeyEvent> = text_to_events!("abd").map(KeyEvent::from).collect();
+        read.push(KeyEvent::from(KeyCode::Backspace));
+        read.push(KeyEvent::from(KeyCode::Char('c')));
+        read.push(KeyEvent::from(KeyCode::Enter));
+        let mut read = read.into_iter();
+
+        let keystrokes = Cell::new(0);
+        let backspaces = Cell::new(0);
+        let last_input = RefCell::new(String::new());
+        let hook = |key: &ui::Key, input: &str| {
+            keystrokes.set(keystrokes.get() + 1);
+            if let ui::Key::Backspace = key {
+                backspaces.set(backspaces.get() + 1);
+            }
+            *last_input.borrow_mut() = input.to_owned();
+        };
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+        let ans = default()
+            .with_event_hook(&hook)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!("abc", ans);
+        assert_eq!(6, keystrokes.get());
+        assert_eq!(1, backspaces.get());
+        assert_eq!("abc", *last_input.borrow());
+    }
+
+    #[test]
+    fn debug_log_records_every_key() {
+        let mut read: Vec<KeyEvent> = text_to_events!("ab").map(KeyEvent::from).collect();
+        read.push(KeyEvent::from(KeyCode::Backspace));
+        read.push(KeyEvent::from(KeyCode::Enter));
+        let mut read = read.into_iter();
+
+        let log = RefCell::new(Vec::new());
+
+        let mut write: Vec<u8> = Vec::new();
+        {
+            let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+            let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+            let ans = default()
+                .with_debug_log(&log)
+                .prompt_with_backend(&mut backend)
+                .unwrap();
+
+            assert_eq!("a", ans);
+        }
+
+        assert_eq!(
+            "a -> \"a\"\nb -> \"ab\"\nbackspace -> \"a\"\nenter -> \"a\"\n",
+            String::from_utf8(log.into_inner()).unwrap()
+        );
+        assert!(!String::from_utf8(write).unwrap().contains("->"));
+    }
+
+    fn rendered_answer(render_config: RenderConfig) -> String {
+        let read: Vec<KeyEvent> = text_to_events!("Ferris\n").map(KeyEvent::from).collect();
+        let mut read = read.into_iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        {
+            let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+            let mut backend = Backend::new(terminal, render_config).unwrap();
+
+            let ans = default().prompt_with_backend(&mut backend).unwrap();
+
+            assert_eq!("Ferris", ans);
+        }
+
+        let output = String::from_utf8(write).unwrap();
+        let answer_start = output.rfind("? Question?").unwrap();
+
+        output[answer_start..].to_owned()
+    }
+
+    #[test]
+    fn inline_answer_layout_renders_a_single_line() {
+        let answer = rendered_answer(RenderConfig::empty());
+
+        assert!(answer.starts_with("? Question? Ferris\r\n"));
+    }
+
+    #[test]
+    fn below_answer_layout_renders_answer_on_next_line() {
+        let render_config = RenderConfig::empty().with_answer_layout(AnswerLayout::Below);
+
+        let answer = rendered_answer(render_config);
+
+        assert!(answer.starts_with("? Question?\r\nFerris\r\n"));
+    }
+
+    #[test]
+    fn custom_prompt_prefixes_are_rendered() {
+        let read: Vec<KeyEvent> = text_to_events!("Ferris\n").map(KeyEvent::from).collect();
+        let mut read = read.into_iter();
+
+        let render_config = RenderConfig::empty()
+            .with_prompt_prefix(Styled::new("🦀"))
+            .with_answered_prompt_prefix(Styled::new("✔"));
+
+        let mut write: Vec<u8> = Vec::new();
+        {
+            let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+            let mut backend = Backend::new(terminal, render_config).unwrap();
+
+            let ans = default().prompt_with_backend(&mut backend).unwrap();
+
+            assert_eq!("Ferris", ans);
+        }
+
+        let output = String::from_utf8(write).unwrap();
+        let prompt = output
+            .find("🦀 Question?")
+            .expect("prompt prefix was not rendered");
+        let answer = output
+            .find("✔ Question? Ferris")
+            .expect("answered prompt prefix was not rendered");
+
+        assert!(prompt < answer);
+        assert!(!output.contains("? Question?"));
+    }
+
+    #[test]
+    fn prompt_with_caller_provided_io() {
+        let mut events = text_to_events!("first\nsecond\n").map(KeyEvent::from);
+        let mut write: Vec<u8> = Vec::new();
+
+        let first = Text::new("First?")
+            .prompt_with_io(&mut write, &mut events)
+            .unwrap();
+        let second = Text::new("Second?")
+            .prompt_with_io(&mut write, &mut events)
+            .unwrap();
+
+        assert_eq!("first", first);
+        assert_eq!("second", second);
+
+        let output = String::from_utf8(write).unwrap();
+        assert!(output.contains("First? first"));
+        assert!(output.contains("Second? second"));
+    }
+
+    #[test]
+    fn prompt_with_io_reads_inquire_keys() {
+        let mut keys = vec![
+            ui::Key::Char('o', ui::KeyModifiers::NONE),
+            ui::Key::Char('k', ui::KeyModifiers::NONE),
+            ui::Key::Submit,
+        ]
+        .into_iter();
+        let mut write: Vec<u8> = Vec::new();
+
+        let ans = default().prompt_with_io(&mut write, &mut keys).unwrap();
+
+        assert_eq!("ok", ans);
+    }
+
+    #[test]
+    fn prompt_with_io_fails_when_events_run_out() {
+        let mut events = text_to_events!("abc").map(KeyEvent::from);
+        let mut write: Vec<u8> = Vec::new();
+
+        let ans = default().prompt_with_io(&mut write, &mut events);
+
+        match ans {
+            Err(InquireError::IO(err)) => {
+                assert_eq!(std::io::ErrorKind::UnexpectedEof, err.kind())
+            }
+            _ => panic!("unexpected result: {:?}", ans),
+        }
+    }
+
+    #[test]
+    fn recorded_session_replays_to_the_same_answer() {
+        let mut events = text_to_events!("Ferrsi")
+            .chain(vec![KeyCode::Backspace, KeyCode::Backspace])
+            .chain(text_to_events!("is\n"))
+            .map(KeyEvent::from);
+        let mut recorded_output: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut recorded_output, &mut events);
+
+        let (recorded, keys) = default().prompt_recording_with_terminal(terminal);
+
+        assert_eq!("Ferris", recorded.unwrap());
+        assert_eq!(Some(ui::Key::Backspace), keys[6]);
+        assert_eq!(Some(&Some(ui::Key::Submit)), keys.last());
+
+        let mut no_events = std::iter::empty();
+        let mut replayed_output: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut replayed_output, &mut no_events);
+
+        let replayed = default().replay_with_terminal(terminal, &keys);
+
+        assert_eq!("Ferris", replayed.unwrap());
+        assert_eq!(recorded_output, replayed_output);
+    }
+
+    #[test]
+    fn recorded_timeouts_are_replayed() {
+        let mut events = text_to_events!("abc").map(KeyEvent::from);
+        let mut recorded_output: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut recorded_output, &mut events);
+
+        let prompt = default()
+            .with_default("kiosk")
+            .with_timeout(Duration::from_millis(10));
+        let (recorded, keys) = prompt.clone().prompt_recording_with_terminal(terminal);
+
+        assert_eq!("kiosk", recorded.unwrap());
+        assert_eq!(Some(&None), keys.last());
+
+        let mut no_events = std::iter::empty();
+        let mut replayed_output: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut replayed_output, &mut no_events);
+
+        let replayed = prompt.replay_with_terminal(terminal, &keys);
+
+        assert_eq!("kiosk", replayed.unwrap());
+        assert_eq!(recorded_output, replayed_output);
+    }
+
+    #[test]
+    fn replay_fails_when_recorded_keys_run_out() {
+        let mut no_events = std::iter::empty();
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut no_events);
+
+        let keys = [Some(ui::Key::Char('a', ui::KeyModifiers::NONE))];
+        let ans = default().replay_with_terminal(terminal, &keys);
+
+        match ans {
+            Err(InquireError::IO(err)) => {
+                assert_eq!(std::io::ErrorKind::UnexpectedEof, err.kind())
+            }
+            _ => panic!("unexpected result: {:?}", ans),
+        }
+    }
+
+    #[test]
+    fn caller_provided_io_renders_no_styles() {
+        let mut read = text_to_events!("Ferris\n").map(KeyEvent::from);
+        let mut write: Vec<u8> = Vec::new();
+
+        let prompt = default().with_render_config(RenderConfig::default_colored());
+        let ans = prompt.prompt_with_io(&mut write, &mut read).unwrap();
+        let output = String::from_utf8(write).unwrap();
+
+        assert_eq!("Ferris", ans);
+
+        // the escape sequences left move the cursor and clear lines, none of
+        // them sets colors or attributes
+        let mut sequence_ends = output
+            .split('\x1b')
+            .skip(1)
+            .filter_map(|sequence| sequence.chars().find(|c| c.is_ascii_alphabetic()));
+        assert!(sequence_ends.all(|end| end != 'm'));
+    }
+
+    #[test]
+    fn timeout_returns_default_when_no_input_arrives() {
+        let mut events = std::iter::empty::<ui::Key>();
+        let mut write: Vec<u8> = Vec::new();
+
+        let ans = default()
+            .with_default("kiosk")
+            .with_timeout(Duration::from_millis(10))
+            .prompt_with_io(&mut write, &mut events);
+
+        assert_eq!("kiosk", ans.unwrap());
+    }
+
+    #[test]
+    fn timeout_discards_partial_input() {
+        let mut events = text_to_events!("abc").map(KeyEvent::from);
+        let mut write: Vec<u8> = Vec::new();
+
+        let ans = default()
+            .with_default("kiosk")
+            .with_timeout(Duration::from_millis(10))
+            .prompt_with_io(&mut write, &mut events);
+
+        assert_eq!("kiosk", ans.unwrap());
+    }
+
+    #[test]
+    fn timeout_does_not_affect_active_users() {
+        let mut events = text_to_events!("abc\n").map(KeyEvent::from);
+        let mut write: Vec<u8> = Vec::new();
+
+        let ans = default()
+            .with_default("kiosk")
+            .with_timeout(Duration::from_millis(10))
+            .prompt_with_io(&mut write, &mut events);
+
+        assert_eq!("abc", ans.unwrap());
+    }
+
+    #[test]
+    fn timeout_validates_the_default() {
+        let calls = Rc::new(Cell::new(0));
+        let mut events = std::iter::empty::<ui::Key>();
+        let mut write: Vec<u8> = Vec::new();
+
+        let ans = default()
+            .with_default("ab")
+            .with_timeout(Duration::from_millis(10))
+            .with_validator(min_length_validator(calls.clone()))
+            .with_max_attempts(1)
+            .prompt_with_io(&mut write, &mut events);
+
+        assert!(matches!(ans, Err(InquireError::TooManyAttempts)));
+        assert_eq!(1, calls.get());
+    }
+
+    #[test]
+    fn timeout_waits_for_input_after_rejecting_the_default() {
+        let calls = Rc::new(Cell::new(0));
+        let mut events = std::iter::empty::<ui::Key>();
+        let mut write: Vec<u8> = Vec::new();
+
+        let ans = default()
+            .with_default("ab")
+            .with_timeout(Duration::from_millis(10))
+            .with_validator(min_length_validator(calls.clone()))
+            .with_max_attempts(2)
+            .prompt_with_io(&mut write, &mut events);
+
+        // the prompt reads keys again, instead of failing after another timeout
+        match ans {
+            Err(InquireError::IO(err)) => {
+                assert_eq!(std::io::ErrorKind::UnexpectedEof, err.kind())
+            }
+            _ => panic!("unexpected result: {:?}", ans),
+        }
+        assert_eq!(1, calls.get());
+        assert!(String::from_utf8(write).unwrap().contains("Too short"));
+    }
+
+    #[test]
+    fn timeout_skips_the_confirmation() {
+        let mut events = std::iter::empty::<ui::Key>();
+        let mut write: Vec<u8> = Vec::new();
+
+        let ans = default()
+            .with_default("kiosk")
+            .with_timeout(Duration::from_millis(10))
+            .with_confirmation("Are you sure?")
+            .prompt_with_io(&mut write, &mut events);
+
+        assert_eq!("kiosk", ans.unwrap());
+        assert!(!String::from_utf8(write).unwrap().contains("Are you sure?"));
+    }
+
+    #[test]
+    fn timeout_without_default_waits_for_input() {
+        let mut events = text_to_events!("abc").map(KeyEvent::from);
+        let mut write: Vec<u8> = Vec::new();
+
+        let ans = default()
+            .with_timeout(Duration::from_millis(10))
+            .prompt_with_io(&mut write, &mut events);
+
+        assert!(matches!(ans, Err(InquireError::IO(_))));
+    }
+
+    #[test]
+    fn declined_confirmation_returns_to_editing() {
+        let mut events = text_to_events!("abc\nn\n")
+            .chain(vec![KeyCode::Backspace])
+            .chain(text_to_events!("d\ny\n"))
+            .map(KeyEvent::from);
+        let mut write: Vec<u8> = Vec::new();
+
+        let ans = default()
+            .with_confirmation("Are you sure?")
+            .prompt_with_io(&mut write, &mut events);
+
+        assert_eq!("abd", ans.unwrap());
+
+        let output = String::from_utf8(write).unwrap();
+        assert_eq!(
+            vec![
+                "> Question? abc",
+                "> Are you sure? No",
+                "> Question? abd",
+                "> Are you sure? Yes",
+            ],
+            rendered_screen(&output)
+        );
+    }
+
+    #[test]
+    fn confirmation_is_not_asked_for_invalid_answers() {
+        let mut events = text_to_events!("\nabc\ny\n").map(KeyEvent::from);
+        let mut write: Vec<u8> = Vec::new();
+
+        let ans = default()
+            .with_validator(ValueRequiredValidator::default())
+            .with_confirmation("Are you sure?")
+            .prompt_with_io(&mut write, &mut events);
+
+        assert_eq!("abc", ans.unwrap());
+
+        let output = String::from_utf8(write).unwrap();
+        assert_eq!(
+            vec!["> Question? abc", "> Are you sure? Yes"],
+            rendered_screen(&output)
+        );
+    }
+
+    fn answer_with_default_flag(prompt: Text<'_>, events: Vec<KeyEvent>) -> (String, bool) {
+        let mut read = events.into_iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+        prompt
+            .prompt_with_backend_and_default_flag(&mut backend)
+            .unwrap()
+    }
+
+    #[test]
+    fn default_flag_is_set_when_default_is_used() {
+        let events = text_to_events!("\n").map(KeyEvent::from).collect();
+        let ans = answer_with_default_flag(default().with_default("foo"), events);
+
+        assert_eq!((String::from("foo"), true), ans);
+    }
+
+    #[test]
+    fn default_flag_is_unset_when_default_is_typed() {
+        let events = text_to_events!("foo\n").map(KeyEvent::from).collect();
+        let ans = answer_with_default_flag(default().with_default("foo"), events);
+
+        assert_eq!((String::from("foo"), false), ans);
+    }
+
+    #[test]
+    fn default_flag_is_unset_without_default() {
+        let events = text_to_events!("\n").map(KeyEvent::from).collect();
+        let ans = answer_with_default_flag(default(), events);
+
+        assert_eq!((String::new(), false), ans);
+    }
+
+    #[test]
+    fn help_panel_lists_key_bindings() {
+        let events = vec![
+            KeyEvent::from(KeyCode::F(1)),
+            KeyEvent::from(KeyCode::Enter),
+        ];
+
+        let output = rendered_output(default(), events);
+
+        assert!(output.contains("up: move the highlighted suggestion up\r\n"));
+        assert!(output.contains("tab: autocomplete\r\n"));
+        assert!(output.contains("enter: submit\r\n"));
+        assert!(output.contains("F1: toggle this help\r\n"));
+    }
+
+    #[test]
+    fn help_panel_reflects_keymap() {
+        let events = vec![
+            KeyEvent::from(KeyCode::F(1)),
+            KeyEvent::from(KeyCode::Enter),
+        ];
+        let prompt = default().with_keymap(Keymap::vim());
+
+        let output = rendered_output(prompt, events);
+
+        assert!(output.contains("up, ctrl+p: move the highlighted suggestion up\r\n"));
+        assert!(output.contains("tab, ctrl+y: autocomplete\r\n"));
+    }
+
+    #[test]
+    fn help_panel_is_dismissed_with_the_same_key() {
+        let events = vec![
+            KeyEvent::from(KeyCode::F(1)),
+            KeyEvent::from(KeyCode::Char('a')),
+            KeyEvent::from(KeyCode::F(1)),
+            KeyEvent::from(KeyCode::Char('b')),
+            KeyEvent::from(KeyCode::Enter),
+        ];
+
+        let output = rendered_output(default(), events);
+
+        assert_eq!(2, output.matches("enter: submit").count());
+    }
+
+    fn temp_email_warning(ans: &str) -> Result<Validation, CustomUserError> {
+        match ans.ends_with("@mailinator.com") {
+            true => Ok(Validation::Warning(
+                "This looks like a temporary email".into(),
+            )),
+            false => Ok(Validation::Valid),
+        }
+    }
+
+    #[test]
+    fn warnings_are_rendered_and_do_not_block_submission() {
+        let read: Vec<KeyEvent> = text_to_events!("me@mailinator.com\n")
+            .map(KeyEvent::from)
+            .collect();
+        let mut read = read.into_iter();
+        let prompt = default()
+            .with_validator(temp_email_warning)
+            .with_live_validation(true);
+
+        let mut write: Vec<u8> = Vec::new();
+        {
+            let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+            let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+            let ans = prompt.prompt_with_backend(&mut backend).unwrap();
+            assert_eq!("me@mailinator.com", ans);
+        }
+
+        let output = String::from_utf8(write).unwrap();
+        assert!(output.contains("! This looks like a temporary email\r\n"));
+        assert!(!output.contains("# "));
+    }
+
+    #[test]
+    fn errors_take_precedence_over_warnings() {
+        let events = text_to_events!("me@mailinator.com\n1\n")
+            .map(KeyEvent::from)
+            .collect();
+        let prompt = default()
+            .with_validator(temp_email_warning)
+            .with_validator(|ans: &str| match ans.ends_with('1') {
+                true => Ok(Validation::Valid),
+                false => Ok(Validation::Invalid("Missing the lucky number".into())),
+            });
+
+        let output = rendered_output(prompt, events);
+
+        assert!(output.contains("# Missing the lucky number\r\n"));
+    }
+
+    #[test]
+    fn autocompleter_errors_are_rendered_without_ending_the_prompt() {
+        let events = text_to_events!("ab\t\n").map(KeyEvent::from).collect();
+        let prompt = default().with_autocomplete(|input: &str| match input {
+            "" => Ok(vec![]),
+            _ => Err("Suggestion service unavailable".into()),
+        });
+
+        let output = rendered_output(prompt, events);
+
+        assert!(output.contains("# Suggestion service unavailable\r\n"));
+    }
+
+    fn counting_autocompleter(
+        calls: Rc<Cell<usize>>,
+    ) -> impl Fn(&str) -> Result<Vec<String>, CustomUserError> + Clone {
+        move |input: &str| {
+            calls.set(calls.get() + 1);
+            Ok(vec![format!("{}1", input), format!("{}2", input)])
+        }
+    }
+
+    #[test]
+    fn debounced_suggestions_are_not_requested_on_every_keystroke() {
+        let calls = Rc::new(Cell::new(0));
+        let events = text_to_events!("abc\n").map(KeyEvent::from).collect();
+        let prompt = default()
+            .with_autocomplete(counting_autocompleter(calls.clone()))
+            .with_suggestion_debounce(Duration::from_secs(60));
+
+        rendered_output(prompt, events);
+
+        // only the initial request, as keys arrive faster than the debounce period
+        assert_eq!(1, calls.get());
+    }
+
+    #[test]
+    fn debounced_suggestions_are_requested_before_navigating() {
+        let calls = Rc::new(Cell::new(0));
+        let mut events: Vec<KeyEvent> = text_to_events!("abc").map(KeyEvent::from).collect();
+        events.push(KeyEvent::from(KeyCode::Down));
+        events.push(KeyEvent::from(KeyCode::Enter));
+        let prompt = default()
+            .with_autocomplete(counting_autocompleter(calls.clone()))
+            .with_suggestion_debounce(Duration::from_secs(60));
+
+        let mut read = events.into_iter();
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = prompt.prompt_with_backend(&mut backend).unwrap();
+
+        assert_eq!("abc1", ans);
+        assert_eq!(2, calls.get());
+    }
+
+    #[test]
+    fn suggestions_without_debounce_are_requested_on_every_keystroke() {
+        let calls = Rc::new(Cell::new(0));
+        let events = text_to_events!("abc\n").map(KeyEvent::from).collect();
+        let prompt = default().with_autocomplete(counting_autocompleter(calls.clone()));
+
+        rendered_output(prompt, events);
+
+        assert_eq!(4, calls.get());
+    }
+
+    #[test]
+    fn debounced_suggestions_are_requested_on_every_keystroke_without_polling() {
+        let calls = Rc::new(Cell::new(0));
+        let mut read = text_to_events!("abc\n").map(KeyEvent::from);
+        let prompt = default()
+            .with_autocomplete(counting_autocompleter(calls.clone()))
+            .with_suggestion_debounce(Duration::from_secs(60));
+
+        let mut write: Vec<u8> = Vec::new();
+        {
+            let terminal =
+                CrosstermTerminal::new_with_io(&mut write, &mut read).with_polling(false);
+            let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+            prompt.prompt_with_backend(&mut backend).unwrap();
+        }
+
+        // terminals unable to detect inactivity can not defer the requests
+        assert_eq!(4, calls.get());
+        let output = String::from_utf8(write).unwrap();
+        assert!(output.contains("abc1\r\n"));
+    }
+
+    #[test]
+    fn previous_suggestions_are_displayed_while_debouncing() {
+        let calls = Rc::new(Cell::new(0));
+        let events = text_to_events!("abc\n").map(KeyEvent::from).collect();
+        let prompt = default()
+            .with_autocomplete(counting_autocompleter(calls.clone()))
+            .with_suggestion_debounce(Duration::from_secs(60));
+
+        let output = rendered_output(prompt, events);
+
+        // the suggestions for the empty input, rendered on the initial frame
+        // and after each of the three keystrokes
+        assert_eq!(4, output.matches("  1\r\n").count());
+        assert!(!output.contains("abc1"));
+        assert!(calls.get() < 3);
+    }
+
+    #[test]
+    fn spinner_is_displayed_while_debouncing() {
+        let calls = Rc::new(Cell::new(0));
+        let mut events = text_to_events!("a\n").map(KeyEvent::from);
+        let prompt = default()
+            .with_autocomplete(counting_autocompleter(calls.clone()))
+            .with_suggestion_debounce(Duration::from_secs(60));
+
+        let (ans, transcript) = prompt.prompt_with_transcript(&mut events);
+
+        assert_eq!("a", ans.unwrap());
+        assert_eq!(1, transcript.matches("| Loading suggestions...").count());
+    }
+
+    #[test]
+    fn spinner_is_animated_until_suggestions_arrive() {
+        let calls = Rc::new(Cell::new(0));
+        // once the events run out, reads time out right away, as if the user
+        // was idle until the end of the debounce period
+        let mut events = text_to_events!("a").map(KeyEvent::from);
+        let prompt = default()
+            .with_autocomplete(counting_autocompleter(calls.clone()))
+            .with_suggestion_debounce(Duration::from_millis(1000));
+
+        let (_, transcript) = prompt.prompt_with_transcript(&mut events);
+
+        for frame in &["|", "/", "-", "\\"] {
+            let line = format!("{} Loading suggestions...", frame);
+            assert!(transcript.contains(&line));
+        }
+        assert_eq!(2, calls.get());
+        let last_frame = transcript.rsplit("? Question?").next().unwrap();
+        assert!(last_frame.contains("a1\r\n  a2\r\n"));
+        assert!(!last_frame.contains("Loading suggestions"));
+    }
+
+    #[test]
+    fn spinner_is_not_displayed_without_debounce() {
+        let events = text_to_events!("a\n").map(KeyEvent::from).collect();
+        let prompt = default().with_autocomplete(counting_autocompleter(Rc::default()));
+
+        let output = rendered_output(prompt, events);
+
+        assert!(!output.contains("Loading suggestions"));
+    }
+
+    #[test]
+    fn spinner_is_not_displayed_without_polling() {
+        let mut read = text_to_events!("abc\n").map(KeyEvent::from);
+        let prompt = default()
+            .with_autocomplete(counting_autocompleter(Rc::default()))
+            .with_suggestion_debounce(Duration::from_secs(60));
+
+        let mut write: Vec<u8> = Vec::new();
+        {
+            let terminal =
+                CrosstermTerminal::new_with_io(&mut write, &mut read).with_polling(false);
+            let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+            prompt.prompt_with_backend(&mut backend).unwrap();
+        }
+
+        let output = String::from_utf8(write).unwrap();
+        assert!(!output.contains("Loading suggestions"));
+    }
+
+    fn file_suggestions(_: &str) -> Result<Vec<String>, CustomUserError> {
+        Ok(vec![String::from("foo"), String::from("bar")])
+    }
+
+    #[test]
+    fn suggestion_transform_is_applied_on_completion() {
+        let events = vec![
+            KeyEvent::from(KeyCode::Down),
+            KeyEvent::from(KeyCode::Tab),
+            KeyEvent::from(KeyCode::Enter),
+        ];
+        let prompt = default()
+            .with_autocomplete(file_suggestions)
+            .with_suggestion_transform(&|suggestion| format!("/abs/{}", suggestion));
+
+        let (ans, output) = answer_and_output(prompt, events);
+
+        assert_eq!("/abs/foo", ans);
+        assert!(output.contains("> foo\r\n"));
+    }
+
+    text_test!(
+        suggestion_transform_is_applied_on_submission,
+        vec![
+            KeyEvent::from(KeyCode::Down),
+            KeyEvent::from(KeyCode::Enter)
+        ],
+        "/abs/foo",
+        default()
+            .with_autocomplete(file_suggestions)
+            .with_suggestion_transform(&|suggestion| format!("/abs/{}", suggestion))
+    );
+
+    fn hundred_suggestions(input: &str) -> Result<Vec<String>, CustomUserError> {
+        Ok((1..=100).map(|i| format!("{}{}", input, i)).collect())
+    }
+
+    #[test]
+    fn suggestions_beyond_the_maximum_are_hidden() {
+        let events = vec![KeyEvent::from(KeyCode::Enter)];
+        let prompt = default()
+            .with_autocomplete(hundred_suggestions)
+            .with_max_suggestions(5);
+
+        let output = rendered_output(prompt, events);
+
+        assert!(output.contains("  5\r\n...and 95 more\r\n"));
+        assert!(!output.contains("  6\r\n"));
+    }
+
+    #[test]
+    fn cursor_stops_at_the_last_displayed_suggestion() {
+        let mut events = vec![KeyEvent::from(KeyCode::PageDown); 3];
+        events.push(KeyEvent::from(KeyCode::Enter));
+        let prompt = default()
+            .with_autocomplete(hundred_suggestions)
+            .with_max_suggestions(5);
+
+        let mut read = events.into_iter();
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = prompt.prompt_with_backend(&mut backend).unwrap();
+
+        assert_eq!("5", ans);
+    }
+
+    #[test]
+    fn preview_follows_the_highlighted_suggestion() {
+        let events = vec![
+            KeyEvent::from(KeyCode::Down),
+            KeyEvent::from(KeyCode::Down),
+            KeyEvent::from(KeyCode::Enter),
+        ];
+        let prompt = default()
+            .with_autocomplete(|_: &str| Ok(vec!["a".to_owned(), "b".to_owned()]))
+            .with_preview(&|suggestion| format!("Preview of {}\nSecond line", suggestion));
+
+        let output = rendered_output(prompt, events);
+
+        assert_eq!(1, output.matches("Preview of a\r\nSecond line\r\n").count());
+        assert_eq!(1, output.matches("Preview of b\r\nSecond line\r\n").count());
+    }
+
+    #[test]
+    fn preview_is_not_rendered_without_highlighted_suggestion() {
+        let events = text_to_events!("a\n").map(KeyEvent::from).collect();
+        let prompt = default()
+            .with_autocomplete(|_: &str| Ok(vec!["a".to_owned(), "b".to_owned()]))
+            .with_preview(&|suggestion| format!("Preview of {}", suggestion));
+
+        let output = rendered_output(prompt, events);
+
+        assert!(!output.contains("Preview of"));
+    }
+
+    #[test]
+    fn transcript_contains_every_frame() {
+        let mut events = text_to_events!("hi\n").map(KeyEvent::from);
+
+        let (ans, transcript) = Text::new("Greeting?")
+            .with_render_config(RenderConfig::empty())
+            .prompt_with_transcript(&mut events);
+
+        assert_eq!("hi", ans.unwrap());
+        // three frames while typing, plus the final answer
+        assert_eq!(4, transcript.matches("? Greeting? ").count());
+        assert!(transcript.contains("? Greeting? h \r\n"));
+        assert!(transcript.contains("? Greeting? hi\r\n"));
+    }
+
+    #[test]
+    fn transcript_is_recorded_from_inquire_keys() {
+        let mut keys = vec![
+            ui::Key::Char('h', ui::KeyModifiers::NONE),
+            ui::Key::Char('i', ui::KeyModifiers::NONE),
+            ui::Key::Submit,
+        ]
+        .into_iter();
+
+        let (ans, transcript) = Text::new("Greeting?").prompt_with_transcript(&mut keys);
+
+        assert_eq!("hi", ans.unwrap());
+        assert!(transcript.contains("? Greeting? hi"));
+    }
+
+    #[test]
+    fn transcript_is_returned_when_the_prompt_fails() {
+        let mut events = text_to_events!("hi").map(KeyEvent::from);
+
+        let (ans, transcript) = Text::new("Greeting?").prompt_with_transcript(&mut events);
+
+        assert!(matches!(ans, Err(InquireError::IO(_))));
+        assert!(transcript.contains("? Greeting? hi"));
+    }
+
+    #[test]
+    fn required_prompt_rejects_empty_submission() {
+        let events = text_to_events!("\nhi\n").map(KeyEvent::from).collect();
+        let prompt = default().with_required(true);
+
+        let output = rendered_output(prompt, events);
+
+        assert!(output.contains("# A response is required.\r\n"));
+        assert!(output.contains("? Question? hi\r\n"));
+    }
+
+    text_test!(
+        required_prompt_accepts_default_value,
+        vec![KeyCode::Enter],
+        "Default",
+        Text::new("Question?")
+            .with_required(true)
+            .with_default("Default")
+    );
+
+    text_test!(
+        no_trimming_by_default,
+        text_to_events!("  hi  \n"),
+        "  hi  "
+    );
+
+    text_test!(
+        trim_removes_surrounding_whitespace,
+        text_to_events!("  hi  \n"),
+        "hi",
+        Text::new("Question?").with_trim(TrimMode::Trim)
+    );
+
+    text_test!(
+        trim_end_removes_trailing_whitespace,
+        text_to_events!("  hi  \n"),
+        "  hi",
+        Text::new("Question?").with_trim(TrimMode::TrimEnd)
+    );
+
+    text_test!(
+        trim_start_removes_leading_whitespace,
+        text_to_events!("  hi  \n"),
+        "hi  ",
+        Text::new("Question?").with_trim(TrimMode::TrimStart)
+    );
+
+    text_test!(
+        input_emptied_by_trimming_is_replaced_by_default,
+        text_to_events!("   \n"),
+        "Default",
+        Text::new("Question?")
+            .with_trim(TrimMode::Trim)
+            .with_default("Default")
+    );
+
+    #[test]
+    fn validators_receive_trimmed_answer() {
+        let events = text_to_events!("  hi  \n").map(KeyEvent::from).collect();
+        let prompt = default()
+            .with_trim(TrimMode::Trim)
+            .with_validator(|ans: &str| match ans {
+                "hi" => Ok(Validation::Valid),
+                _ => Ok(Validation::Invalid("Untrimmed".into())),
+            });
+
+        let output = rendered_output(prompt, events);
+
+        assert!(!output.contains("Untrimmed"));
+    }
+
+    #[test]
+    fn indented_prompts_indent_every_rendered_line() {
+        let read: Vec<KeyEvent> = text_to_events!("\na\n").map(KeyEvent::from).collect();
+        let mut read = read.into_iter();
+
+        let prompt = default()
+            .with_help_message("Type something")
+            .with_validator(ValueRequiredValidator::default())
+            .with_autocomplete(hundred_suggestions)
+            .with_max_suggestions(3);
+
+        let mut write: Vec<u8> = Vec::new();
+        {
+            let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+            let render_config = RenderConfig::empty().with_indent(4);
+            let mut backend = Backend::new(terminal, render_config).unwrap();
+
+            prompt.prompt_with_backend(&mut backend).unwrap();
+        }
+
+        // drop the escape sequences moving the cursor and clearing lines
+        let output = String::from_utf8(write).unwrap();
+        let mut visible = String::new();
+        let mut chars = output.chars();
+        while let Some(c) = chars.next() {
+            match c {
+                '\x1b' => {
+                    chars.find(|c| c.is_ascii_alphabetic());
+                }
+                c => visible.push(c),
+            }
+        }
+
+        let lines: Vec<&str> = visible.split("\r\n").filter(|l| !l.is_empty()).collect();
+
+        assert!(lines.iter().any(|l| l.contains("? Question? a")));
+        assert!(lines.iter().any(|l| l.contains("a1")));
+        assert!(lines.iter().any(|l| l.contains("[Type something]")));
+        assert!(lines.iter().any(|l| l.contains("A response is required.")));
+        for line in lines {
+            assert!(line.starts_with("    "), "line not indented: {:?}", line);
+        }
+    }
+
+    #[test]
+    fn coded_validation_errors_render_their_message() {
+        let events = text_to_events!("a\nb\n").map(KeyEvent::from).collect();
+        let prompt = default().with_validator(|ans: &str| match ans {
+            "a" => Ok(Validation::Invalid(
+                ValidationError::new("reserved", "This name is reserved").into(),
+            )),
+            _ => Ok(Validation::Valid),
+        });
+
+        let output = rendered_output(prompt, events);
+
+        assert!(output.contains("# This name is reserved\r\n"));
+    }
+
+    text_test!(
+        tab_accepts_default_on_empty_input,
+        vec![KeyCode::Tab],
+        "Default",
+        default().with_default("Default")
+    );
+
+    text_test!(
+        tab_completes_suggestions_instead_of_accepting_default,
+        vec![KeyCode::Tab, KeyCode::Down, KeyCode::Tab, KeyCode::Enter],
+        "git status",
+        default()
+            .with_default("Default")
+            .with_autocomplete(&git_suggester)
+    );
+
+    text_test!(
+        tab_does_not_accept_default_over_typed_input,
+        vec![KeyCode::Char('a'), KeyCode::Tab, KeyCode::Enter],
+        "a",
+        default().with_default("Default")
+    );
+
+    #[test]
+    fn accept_default_key_is_hinted() {
+        let events = vec![KeyEvent::from(KeyCode::Tab)];
+        let prompt = default().with_default("Default");
+
+        let output = rendered_output(prompt, events);
+
+        assert!(output.contains("(Default)  \r\n[tab to accept the default, enter to submit]"));
+    }
+
+    #[test]
+    fn accept_default_key_is_not_hinted_without_default_help_messages() {
+        let events = vec![KeyEvent::from(KeyCode::Tab)];
+        let prompt = Text {
+            default_help_messages: false,
+            ..default().with_default("Default")
+        };
+
+        let output = rendered_output(prompt, events);
+
+        assert!(!output.contains("tab to accept the default"));
+    }
+
+    #[test]
+    fn autocompletion_and_multiline_keys_are_not_hinted_without_default_help_messages() {
+        let prompt = Text {
+            default_help_messages: false,
+            ..default().with_autocomplete(&git_suggester)
+        };
+        let output = rendered_output(prompt, vec![KeyEvent::from(KeyCode::Enter)]);
+
+        assert!(!output.contains(DEFAULT_HELP_MESSAGE_WITH_AC));
+
+        let prompt = Text {
+            default_help_messages: false,
+            ..default().with_multiline(true)
+        };
+        let output = rendered_output(prompt, vec![ctrl_d()]);
+
+        assert!(!output.contains(DEFAULT_HELP_MESSAGE_MULTILINE));
+    }
 }
@@ -0,0 +1,316 @@
+use crate::{
+    config::get_configuration,
+    error::{InquireError, InquireResult},
+    formatter::StringListFormatter,
+    terminal::get_default_terminal,
+    ui::{Backend, CustomTypeBackend, RenderConfig, TextBackend},
+    validator::StringValidator,
+    Text,
+};
+
+/// Prompt to collect a list of text items from the user, one at a time.
+///
+/// This prompt is a wrapper around [`Text`] prompts: the user types an item and
+/// presses enter to add it to the list, the items added so far being displayed
+/// above the input. Submitting an empty line finishes the list.
+///
+/// TextList prompts provide several options of configuration:
+///
+/// - **Prompt message**: Required when creating the prompt.
+/// - **Placeholder**: Short hint that describes the expected value of each item.
+/// - **Help message**: Message displayed at the line below the prompt.
+/// - **Formatter**: Custom formatter in case you need to pre-process the items before showing them as the final answer.
+///   - By default, the items are joined by commas.
+/// - **Validators**: Custom validators applied to each item, displaying an error message if it does not pass the requirements.
+///   - The empty line that finishes the list is never validated.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::TextList;
+///
+/// let tags = TextList::new("Which tags should be applied?")
+///     .with_help_message("Press enter on an empty line to finish")
+///     .prompt();
+///
+/// match tags {
+///     Ok(tags) => println!("Applying {} tags", tags.len()),
+///     Err(_) => println!("An error happened when asking for the tags, try again later."),
+/// }
+/// ```
+#[derive(Clone)]
+pub struct TextList<'a> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// Short hint that describes the expected value of each item.
+    pub placeholder: Option<&'a str>,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Function that formats the items and presents them to the user as the final rendering of the prompt.
+    pub formatter: StringListFormatter<'a>,
+
+    /// Collection of validators to apply to each item.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub validators: Vec<Box<dyn StringValidator>>,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still suport NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig,
+}
+
+impl<'a> TextList<'a> {
+    /// Default formatter, joining the items by commas.
+    ///
+    /// ```
+    /// use inquire::TextList;
+    ///
+    /// let formatter = TextList::DEFAULT_FORMATTER;
+    /// let items = vec![String::from("rust"), String::from("cli")];
+    /// assert_eq!(String::from("rust, cli"), formatter(&items));
+    /// ```
+    pub const DEFAULT_FORMATTER: StringListFormatter<'a> = &|items| items.join(", ");
+
+    /// Default validators added to the [TextList] prompt, none.
+    pub const DEFAULT_VALIDATORS: Vec<Box<dyn StringValidator>> = vec![];
+
+    /// Creates a [TextList] with the provided message and default configuration values.
+    pub fn new(message: &'a str) -> Self {
+        Self {
+            message,
+            placeholder: None,
+            help_message: None,
+            formatter: Self::DEFAULT_FORMATTER,
+            validators: Self::DEFAULT_VALIDATORS,
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets the placeholder.
+    pub fn with_placeholder(mut self, placeholder: &'a str) -> Self {
+        self.placeholder = Some(placeholder);
+        self
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Sets the formatter.
+    pub fn with_formatter(mut self, formatter: StringListFormatter<'a>) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    /// Adds a validator to the collection of validators, applied to each item.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    pub fn with_validator<V>(mut self, validator: V) -> Self
+    where
+        V: StringValidator + 'static,
+    {
+        self.validators.push(Box::new(validator));
+        self
+    }
+
+    /// Adds the validators to the collection of validators in the order they are given.
+    pub fn with_validators(mut self, validators: &[Box<dyn StringValidator>]) -> Self {
+        for validator in validators {
+            self.validators.push(validator.clone());
+        }
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still suport NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<Vec<String>>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    pub fn prompt(self) -> InquireResult<Vec<String>> {
+        let terminal = get_default_terminal()?;
+        let mut backend = Backend::new(terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    pub(crate) fn prompt_with_backend<B: TextBackend + CustomTypeBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<Vec<String>> {
+        let message = self.message;
+        let formatter = self.formatter;
+        let text = Text::from(self);
+
+        let mut items: Vec<String> = vec![];
+        loop {
+            let item = text
+                .clone()
+                .prompt_list_item_with_backend(&items, backend)?;
+            if item.is_empty() {
+                break;
+            }
+            items.push(item);
+        }
+
+        let formatted = formatter(&items);
+
+        finish_prompt_with_answer!(backend, message, &formatted, items);
+    }
+}
+
+impl<'a> From<&'a str> for TextList<'a> {
+    fn from(val: &'a str) -> Self {
+        TextList::new(val)
+    }
+}
+
+impl<'a> From<TextList<'a>> for Text<'a> {
+    fn from(tl: TextList<'a>) -> Self {
+        Self {
+            placeholder: tl.placeholder,
+            help_message: tl.help_message,
+            validators: tl.validators,
+            render_config: tl.render_config,
+            ..Text::new(tl.message)
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "crossterm")]
+mod test {
+    use super::TextList;
+    use crate::{
+        error::InquireError,
+        terminal::crossterm::CrosstermTerminal,
+        ui::{Backend, RenderConfig},
+        validator::Validation,
+    };
+    use crossterm::event::{KeyCode, KeyEvent};
+
+    macro_rules! text_to_events {
+        ($text:expr) => {{
+            $text.chars().map(|c| match c {
+                '\n' => KeyCode::Enter,
+                '\x1b' => KeyCode::Esc,
+                '\x08' => KeyCode::Backspace,
+                c => KeyCode::Char(c),
+            })
+        }};
+    }
+
+    macro_rules! text_list_test {
+        ($name:ident,$input:expr,$output:expr,$prompt:expr) => {
+            text_list_test! {$name, $input, $output, $prompt, |_rendered| {}}
+        };
+
+        ($name:ident,$input:expr,$output:expr,$prompt:expr,|$rendered:ident| $check:block) => {
+            #[test]
+            fn $name() {
+                let read: Vec<KeyEvent> = $input.into_iter().map(KeyEvent::from).collect();
+                let mut read = read.into_iter();
+
+                let mut write: Vec<u8> = Vec::new();
+                {
+                    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+                    let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+                    let ans = $prompt.prompt_with_backend(&mut backend).unwrap();
+
+                    assert_eq!($output, ans);
+                }
+
+                let $rendered = String::from_utf8(write).unwrap();
+                $check
+            }
+        };
+    }
+
+    text_list_test!(
+        empty_line_finishes_the_list,
+        text_to_events!("rust\ncli\ntui\n\n"),
+        vec!["rust", "cli", "tui"],
+        TextList::new("Tags"),
+        |output| {
+            assert!(output.ends_with("? Tags rust, cli, tui\r\n\u{1b}[?25l\u{1b}[?25h"));
+        }
+    );
+
+    text_list_test!(
+        added_items_are_displayed_above_the_input,
+        text_to_events!("rust\ncli\n\n"),
+        vec!["rust", "cli"],
+        TextList::new("Tags"),
+        |output| {
+            assert!(output.contains("  - rust\r\n  - cli\r\n? Tags"));
+        }
+    );
+
+    text_list_test!(
+        validators_are_applied_to_each_item,
+        text_to_events!("a b\n\x08\x08\x08ab\n\n"),
+        vec!["ab"],
+        TextList::new("Tags").with_validator(|item: &str| match item.contains(' ') {
+            true => Ok(Validation::Invalid("Tags can not contain spaces".into())),
+            false => Ok(Validation::Valid),
+        }),
+        |output| {
+            assert!(output.contains("Tags can not contain spaces"));
+        }
+    );
+
+    #[test]
+    fn cancelling_discards_the_items() {
+        let read: Vec<KeyEvent> = text_to_events!("rust\n\x1b").map(KeyEvent::from).collect();
+        let mut read = read.into_iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = TextList::new("Tags").prompt_with_backend(&mut backend);
+
+        assert!(matches!(ans, Err(InquireError::OperationCanceled)));
+    }
+}
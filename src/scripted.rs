@@ -0,0 +1,422 @@
+//! Scripted input for driving prompts programmatically, e.g. in automated
+//! tests or CI environments where no user is available.
+//!
+//! Instead of crafting the raw key events a prompt reads, an [`InputSource`]
+//! yields high-level [`ScriptedAction`]s, which [`ScriptedEvents`] translates
+//! into the key presses bound to them by default. The resulting events can be
+//! fed to prompts through methods such as [`Text::prompt_with_io`], or
+//! [`Text::prompt_with_transcript`] to also capture the rendered interface.
+//!
+//! [`Text::prompt_with_io`]: crate::Text::prompt_with_io
+//! [`Text::prompt_with_transcript`]: crate::Text::prompt_with_transcript
+//!
+//! # Example
+//!
+//! ```
+//! use inquire::{
+//!     scripted::{ScriptedAction, ScriptedEvents},
+//!     Text,
+//! };
+//!
+//! let script = vec![ScriptedAction::Type("Ferris".into()), ScriptedAction::Submit];
+//! let mut events = ScriptedEvents::new(script.into_iter());
+//! let mut output: Vec<u8> = Vec::new();
+//!
+//! let name = Text::new("What is your name?").prompt_with_io(&mut output, &mut events);
+//!
+//! assert_eq!("Ferris", name.unwrap());
+//! ```
+//!
+//! With the `test-utils` feature, a [`Script`] can also drive a whole sequence
+//! of prompts sharing one rendering session, each prompt consuming the inputs
+//! queued for it in order.
+
+use std::collections::VecDeque;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+#[cfg(any(test, feature = "test-utils"))]
+use std::fmt::Display;
+
+#[cfg(any(test, feature = "test-utils"))]
+use crate::{
+    error::InquireResult,
+    list_option::ListOption,
+    terminal::crossterm::CrosstermTerminal,
+    ui::{Backend, CommonBackend, RenderConfig},
+    Confirm, CustomType, LazySelect, MultiSelect, Password, Select, Text, TextList,
+};
+
+/// High-level action performed on a prompt, as if by the user.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ScriptedAction {
+    /// Types the given text, one character at a time.
+    Type(String),
+
+    /// Submits the current answer, as if by pressing enter.
+    Submit,
+
+    /// Cancels the prompt, as if by pressing esc.
+    Cancel,
+
+    /// Interrupts the prompt, as if by pressing ctrl+c.
+    Interrupt,
+
+    /// Moves the cursor one row up.
+    MoveUp,
+
+    /// Moves the cursor one row down.
+    MoveDown,
+
+    /// Requests the autocompletion of the current input, as if by pressing tab.
+    Complete,
+
+    /// Deletes the character to the left of the cursor.
+    Backspace,
+
+    /// Toggles the highlighted option of a multi-selection, as if by pressing space.
+    Toggle,
+
+    /// Presses an arbitrary key, for interactions not covered by the other actions.
+    Key(KeyEvent),
+}
+
+impl ScriptedAction {
+    fn to_events(&self) -> Vec<KeyEvent> {
+        let code = match self {
+            Self::Type(text) => {
+                return text
+                    .chars()
+                    .map(KeyCode::Char)
+                    .map(KeyEvent::from)
+                    .collect()
+            }
+            Self::Interrupt => {
+                return vec![KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL)]
+            }
+            Self::Key(event) => return vec![*event],
+            Self::Submit => KeyCode::Enter,
+            Self::Cancel => KeyCode::Esc,
+            Self::MoveUp => KeyCode::Up,
+            Self::MoveDown => KeyCode::Down,
+            Self::Complete => KeyCode::Tab,
+            Self::Backspace => KeyCode::Backspace,
+            Self::Toggle => KeyCode::Char(' '),
+        };
+
+        vec![KeyEvent::from(code)]
+    }
+}
+
+/// Source of scripted actions to be performed on a prompt.
+///
+/// Any iterator of [`ScriptedAction`]s is an input source.
+pub trait InputSource {
+    /// Returns the next action to be performed, or `None` when the script has ended.
+    fn next_action(&mut self) -> Option<ScriptedAction>;
+}
+
+impl<I> InputSource for I
+where
+    I: Iterator<Item = ScriptedAction>,
+{
+    fn next_action(&mut self) -> Option<ScriptedAction> {
+        self.next()
+    }
+}
+
+/// Iterator of the key events corresponding to the actions of an [`InputSource`].
+pub struct ScriptedEvents<S: InputSource> {
+    source: S,
+    pending: VecDeque<KeyEvent>,
+}
+
+impl<S: InputSource> ScriptedEvents<S> {
+    /// Creates the key events of the actions yielded by `source`.
+    pub fn new(source: S) -> Self {
+        Self {
+            source,
+            pending: VecDeque::new(),
+        }
+    }
+}
+
+impl<S: InputSource> Iterator for ScriptedEvents<S> {
+    type Item = KeyEvent;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.pending.is_empty() {
+            let action = self.source.next_action()?;
+            self.pending.extend(action.to_events());
+        }
+
+        self.pending.pop_front()
+    }
+}
+
+/// Builder of the inputs of a sequence of prompts, run against a single
+/// scripted terminal.
+///
+/// The queued actions are not split per prompt: each prompt reads keys until it
+/// is finished, and the next one resumes from the following action. All prompts
+/// are rendered with the same [`RenderConfig`], [`RenderConfig::empty()`] by
+/// default, regardless of the render config set on each of them.
+///
+/// # Example
+///
+/// ```
+/// use inquire::{scripted::Script, Confirm, Text};
+///
+/// let (answers, transcript) = Script::new()
+///     .type_text("Ferris")
+///     .submit()
+///     .type_text("y")
+///     .submit()
+///     .run(|session| {
+///         let name = session.prompt(Text::new("What is your name?"))?;
+///         let confirmed = session.prompt(Confirm::new("Is that right?"))?;
+///
+///         Ok((name, confirmed))
+///     });
+///
+/// assert_eq!((String::from("Ferris"), true), answers.unwrap());
+/// assert!(transcript.contains("? Is that right? Yes"));
+/// ```
+#[cfg(any(test, feature = "test-utils"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "test-utils")))]
+#[derive(Clone, Debug)]
+pub struct Script {
+    actions: Vec<ScriptedAction>,
+    render_config: RenderConfig,
+}
+
+#[cfg(any(test, feature = "test-utils"))]
+impl Script {
+    /// Creates an empty script.
+    pub fn new() -> Self {
+        Self {
+            actions: vec![],
+            render_config: RenderConfig::empty(),
+        }
+    }
+
+    /// Queues an action.
+    pub fn action(mut self, action: ScriptedAction) -> Self {
+        self.actions.push(action);
+        self
+    }
+
+    /// Queues the typing of the given text.
+    pub fn type_text<S: Into<String>>(self, text: S) -> Self {
+        self.action(ScriptedAction::Type(text.into()))
+    }
+
+    /// Queues the submission of the current answer.
+    pub fn submit(self) -> Self {
+        self.action(ScriptedAction::Submit)
+    }
+
+    /// Queues the cancellation of the current prompt.
+    pub fn cancel(self) -> Self {
+        self.action(ScriptedAction::Cancel)
+    }
+
+    /// Queues a cursor movement one row up.
+    pub fn move_up(self) -> Self {
+        self.action(ScriptedAction::MoveUp)
+    }
+
+    /// Queues a cursor movement one row down.
+    pub fn move_down(self) -> Self {
+        self.action(ScriptedAction::MoveDown)
+    }
+
+    /// Queues the toggling of the highlighted option.
+    pub fn toggle(self) -> Self {
+        self.action(ScriptedAction::Toggle)
+    }
+
+    /// Queues the press of an arbitrary key.
+    pub fn key(self, event: KeyEvent) -> Self {
+        self.action(ScriptedAction::Key(event))
+    }
+
+    /// Sets the render config shared by all prompts of the session.
+    pub fn with_render_config(mut self, render_config: RenderConfig) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Runs `prompts` against a terminal fed with the queued actions, returning
+    /// along with its result the transcript of everything rendered.
+    ///
+    /// When the actions run out before a prompt is finished, the prompt fails
+    /// with an `InquireError::IO` error with kind `UnexpectedEof`.
+    pub fn run<F, R>(self, prompts: F) -> (InquireResult<R>, String)
+    where
+        F: FnOnce(&mut ScriptedSession<'_>) -> InquireResult<R>,
+    {
+        let mut events = ScriptedEvents::new(self.actions.into_iter());
+        let mut transcript: Vec<u8> = Vec::new();
+
+        let result = {
+            let terminal = CrosstermTerminal::new_with_io(&mut transcript, &mut events);
+
+            match Backend::new(terminal, self.render_config) {
+                Ok(backend) => prompts(&mut ScriptedSession { backend }),
+                Err(err) => Err(err.into()),
+            }
+        };
+
+        (result, String::from_utf8_lossy(&transcript).into_owned())
+    }
+}
+
+#[cfg(any(test, feature = "test-utils"))]
+impl Default for Script {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Rendering session shared by the prompts run by [`Script::run`].
+#[cfg(any(test, feature = "test-utils"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "test-utils")))]
+pub struct ScriptedSession<'a> {
+    backend: Backend<CrosstermTerminal<'a>>,
+}
+
+#[cfg(any(test, feature = "test-utils"))]
+impl<'a> ScriptedSession<'a> {
+    /// Runs `prompt` on the next queued actions, returning its answer.
+    pub fn prompt<P: ScriptedPrompt>(&mut self, prompt: P) -> InquireResult<P::Output> {
+        let answer = prompt.prompt_scripted(self)?;
+
+        // keeps the answer on screen, above the next prompt of the session
+        self.backend.frame_setup_below()?;
+
+        Ok(answer)
+    }
+}
+
+/// Prompt that can be run in a [`ScriptedSession`].
+#[cfg(any(test, feature = "test-utils"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "test-utils")))]
+pub trait ScriptedPrompt {
+    /// Answer returned by the prompt.
+    type Output;
+
+    /// Runs the prompt on the next queued actions of `session`.
+    fn prompt_scripted(self, session: &mut ScriptedSession<'_>) -> InquireResult<Self::Output>;
+}
+
+#[cfg(any(test, feature = "test-utils"))]
+macro_rules! scripted_prompt {
+    ($prompt:ty, $output:ty $(, $bound:ident)?) => {
+        impl<'a $(, T: $bound)?> ScriptedPrompt for $prompt {
+            type Output = $output;
+
+            fn prompt_scripted(
+                self,
+                session: &mut ScriptedSession<'_>,
+            ) -> InquireResult<Self::Output> {
+                self.prompt_with_backend(&mut session.backend)
+            }
+        }
+    };
+}
+
+#[cfg(any(test, feature = "test-utils"))]
+scripted_prompt!(Text<'a>, String);
+#[cfg(any(test, feature = "test-utils"))]
+scripted_prompt!(TextList<'a>, Vec<String>);
+#[cfg(any(test, feature = "test-utils"))]
+scripted_prompt!(Password<'a>, String);
+#[cfg(any(test, feature = "test-utils"))]
+scripted_prompt!(Confirm<'a>, bool);
+#[cfg(any(test, feature = "test-utils"))]
+scripted_prompt!(CustomType<'a, T>, T, Clone);
+#[cfg(any(test, feature = "test-utils"))]
+scripted_prompt!(Select<'a, T>, ListOption<T>, Display);
+#[cfg(any(test, feature = "test-utils"))]
+scripted_prompt!(LazySelect<'a, T>, ListOption<T>, Display);
+#[cfg(any(test, feature = "test-utils"))]
+scripted_prompt!(MultiSelect<'a, T>, Vec<ListOption<T>>, Display);
+
+#[cfg(test)]
+mod test {
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    use super::{Script, ScriptedAction, ScriptedEvents};
+    use crate::{error::InquireError, terminal::crossterm::rendered_screen, Confirm, Text};
+
+    #[test]
+    fn actions_are_translated_to_default_keys() {
+        let script = vec![
+            ScriptedAction::Type("ab".into()),
+            ScriptedAction::Type(String::new()),
+            ScriptedAction::MoveDown,
+            ScriptedAction::Interrupt,
+        ];
+
+        let events: Vec<KeyEvent> = ScriptedEvents::new(script.into_iter()).collect();
+
+        assert_eq!(
+            vec![
+                KeyEvent::from(KeyCode::Char('a')),
+                KeyEvent::from(KeyCode::Char('b')),
+                KeyEvent::from(KeyCode::Down),
+                KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL),
+            ],
+            events
+        );
+    }
+
+    #[test]
+    fn script_answers_a_sequence_of_prompts() {
+        let (answers, transcript) = Script::new()
+            .type_text("Ferris")
+            .submit()
+            .type_text("n")
+            .submit()
+            .run(|session| {
+                let name = session.prompt(Text::new("What is your name?"))?;
+                let confirmed = session.prompt(Confirm::new("Is that right?"))?;
+
+                Ok((name, confirmed))
+            });
+
+        assert_eq!((String::from("Ferris"), false), answers.unwrap());
+        assert!(transcript.contains("? What is your name? Ferris\r\n"));
+        assert!(transcript.contains("? Is that right? No\r\n"));
+    }
+
+    #[test]
+    fn script_keeps_previous_answers_on_screen() {
+        let (answers, transcript) = Script::new()
+            .type_text("Ferris")
+            .submit()
+            .type_text("y")
+            .submit()
+            .run(|session| {
+                session.prompt(Text::new("What is your name?"))?;
+                session.prompt(Confirm::new("Is that right?"))
+            });
+
+        assert!(answers.unwrap());
+        assert_eq!(
+            vec!["? What is your name? Ferris", "? Is that right? Yes"],
+            rendered_screen(&transcript)
+        );
+    }
+
+    #[test]
+    fn script_running_out_fails_the_pending_prompt() {
+        let (answers, _) = Script::new().type_text("Ferris").submit().run(|session| {
+            session.prompt(Text::new("What is your name?"))?;
+            session.prompt(Confirm::new("Is that right?"))
+        });
+
+        assert!(matches!(answers, Err(InquireError::IO(_))));
+    }
+}
@@ -75,6 +75,45 @@ impl Terminal for ConsoleTerminal {
         self.term.clear_line()
     }
 
+    fn clear_screen(&mut self) -> Result<()> {
+        match self.term.is_term() {
+            true => self.term.clear_screen(),
+            false => Ok(()),
+        }
+    }
+
+    fn cursor_position(&mut self) -> Result<(u16, u16)> {
+        Err(std::io::Error::new(
+            std::io::ErrorKind::Unsupported,
+            "cursor position is not supported",
+        ))
+    }
+
+    // console does not report mouse events
+    fn enable_mouse(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn disable_mouse(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    // console has no support for the alternate screen, so the sequences
+    // are written directly
+    fn enter_alternate_screen(&mut self) -> Result<()> {
+        match self.term.is_term() {
+            true => self.term.write_str("\x1b[?1049h"),
+            false => Ok(()),
+        }
+    }
+
+    fn leave_alternate_screen(&mut self) -> Result<()> {
+        match self.term.is_term() {
+            true => self.term.write_str("\x1b[?1049l"),
+            false => Ok(()),
+        }
+    }
+
     fn cursor_hide(&mut self) -> Result<()> {
         self.term.hide_cursor()
     }
@@ -1,15 +1,20 @@
-use std::io::{stdout, Result, Stdout, Write};
+use std::{
+    io::{stdout, Result, Stdout, Write},
+    time::{Duration, Instant},
+};
 
 use crossterm::{
     cursor,
-    event::{self, KeyCode, KeyEvent, KeyModifiers},
+    event::{self, KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind},
     queue,
     style::{Attribute, Color, Print, SetAttribute, SetBackgroundColor, SetForegroundColor},
     terminal::{self, enable_raw_mode, ClearType},
+    tty::IsTty,
     Command,
 };
 
 use crate::{
+    config::get_global_config,
     error::{InquireError, InquireResult},
     ui::{Attributes, Key, Styled},
 };
@@ -20,16 +25,18 @@ enum IO<'a> {
     Std {
         w: Stdout,
     },
-    #[allow(unused)]
     Custom {
-        r: &'a mut dyn Iterator<Item = &'a KeyEvent>,
-        w: &'a mut (dyn Write),
+        r: &'a mut dyn Iterator<Item = KeyEvent>,
+        w: &'a mut dyn Write,
     },
 }
 
 pub struct CrosstermTerminal<'a> {
     io: IO<'a>,
     in_memory_content: String,
+    tty: bool,
+    styled: bool,
+    polling: bool,
 }
 
 impl<'a> CrosstermTerminal<'a> {
@@ -39,19 +46,28 @@ impl<'a> CrosstermTerminal<'a> {
             _ => InquireError::from(e),
         })?;
 
+        let w = stdout();
+        let tty = w.is_tty();
+
         Ok(Self {
-            io: IO::Std { w: stdout() },
+            io: IO::Std { w },
             in_memory_content: String::with_capacity(INITIAL_IN_MEMORY_CAPACITY),
+            tty,
+            styled: tty || get_global_config().force_color,
+            polling: true,
         })
     }
 
-    /// # Errors
+    /// Creates a terminal that writes to `writer` and reads key events from
+    /// `reader`, without touching the raw mode of the underlying terminal.
     ///
-    /// Will return `std::io::Error` if it fails to get terminal size
-    #[cfg(test)]
+    /// Since `writer` is not known to be a terminal, colors and text attributes
+    /// are not rendered unless forced via the global `Config`. The escape
+    /// sequences moving the cursor, clearing lines and hiding the cursor are
+    /// still written.
     pub fn new_with_io<W: 'a + Write>(
         writer: &'a mut W,
-        reader: &'a mut dyn Iterator<Item = &'a KeyEvent>,
+        reader: &'a mut dyn Iterator<Item = KeyEvent>,
     ) -> Self {
         Self {
             io: IO::Custom {
@@ -59,9 +75,35 @@ impl<'a> CrosstermTerminal<'a> {
                 w: writer,
             },
             in_memory_content: String::with_capacity(INITIAL_IN_MEMORY_CAPACITY),
+            tty: false,
+            styled: get_global_config().force_color,
+            polling: true,
         }
     }
 
+    /// Sets whether the output is treated as a terminal, which clearing the
+    /// screen and switching to the alternate screen require.
+    #[cfg(test)]
+    pub fn with_tty(mut self, tty: bool) -> Self {
+        self.tty = tty;
+        self
+    }
+
+    /// Sets whether styles, such as colors, are rendered.
+    #[cfg(test)]
+    pub fn with_styles(mut self, styled: bool) -> Self {
+        self.styled = styled;
+        self
+    }
+
+    /// Sets whether the terminal polls for keys, behaving as terminals that
+    /// can only wait for keys indefinitely when disabled.
+    #[cfg(test)]
+    pub fn with_polling(mut self, polling: bool) -> Self {
+        self.polling = polling;
+        self
+    }
+
     fn get_writer(&mut self) -> &mut dyn Write {
         match &mut self.io {
             IO::Std { w } => w,
@@ -121,25 +163,78 @@ impl<'a> Terminal for CrosstermTerminal<'a> {
     fn read_key(&mut self) -> Result<Key> {
         loop {
             match &mut self.io {
-                IO::Std { w: _ } => {
-                    if let event::Event::Key(key_event) = event::read()? {
-                        return Ok(key_event.into());
+                IO::Std { w: _ } => match event::read()? {
+                    event::Event::Key(key_event) => return Ok(key_event.into()),
+                    event::Event::Mouse(mouse_event) => {
+                        if let Some(key) = key_from_mouse_event(mouse_event) {
+                            return Ok(key);
+                        }
                     }
-                }
+                    _ => {}
+                },
                 IO::Custom { r, w: _ } => {
-                    let key = r.next().expect("Custom stream of characters has ended");
-                    return Ok((*key).into());
+                    return match r.next() {
+                        Some(key) => Ok(key.into()),
+                        None => Err(std::io::Error::new(
+                            std::io::ErrorKind::UnexpectedEof,
+                            "Custom stream of characters has ended",
+                        )),
+                    };
+                }
+            }
+        }
+    }
+
+    fn read_key_timeout(&mut self, timeout: Duration) -> Result<Option<Key>> {
+        if !self.polling {
+            return self.read_key().map(Some);
+        }
+
+        let deadline = Instant::now() + timeout;
+
+        loop {
+            match &mut self.io {
+                IO::Std { w: _ } => {
+                    let remaining = deadline.saturating_duration_since(Instant::now());
+
+                    if !event::poll(remaining)? {
+                        return Ok(None);
+                    }
+                    match event::read()? {
+                        event::Event::Key(key_event) => return Ok(Some(key_event.into())),
+                        event::Event::Mouse(mouse_event) => {
+                            if let Some(key) = key_from_mouse_event(mouse_event) {
+                                return Ok(Some(key));
+                            }
+                        }
+                        _ => {}
+                    }
                 }
+                // A custom stream that has ended is never going to provide more
+                // keys, so it is treated as an idle user.
+                IO::Custom { r, w: _ } => return Ok(r.next().map(Key::from)),
             }
         }
     }
 
+    fn can_poll(&self) -> bool {
+        self.polling
+    }
+
     fn flush(&mut self) -> Result<()> {
         self.get_writer().flush()
     }
 
     fn get_size(&self) -> Result<super::TerminalSize> {
-        terminal::size().map(|(width, height)| super::TerminalSize { width, height })
+        match &self.io {
+            IO::Std { w: _ } => {
+                terminal::size().map(|(width, height)| super::TerminalSize { width, height })
+            }
+            IO::Custom { r: _, w: _ } => Err(std::io::Error::new(
+                std::io::ErrorKind::Unsupported,
+                "custom writers have no terminal size",
+            )),
+        }
     }
 
     fn write<T: std::fmt::Display>(&mut self, val: T) -> Result<()> {
@@ -151,6 +246,10 @@ impl<'a> Terminal for CrosstermTerminal<'a> {
     }
 
     fn write_styled<T: std::fmt::Display>(&mut self, val: &Styled<T>) -> Result<()> {
+        if !self.styled {
+            return self.write(&val.content);
+        }
+
         if let Some(color) = val.style.fg {
             self.set_fg_color(color)?;
         }
@@ -180,6 +279,57 @@ impl<'a> Terminal for CrosstermTerminal<'a> {
         self.write_command(terminal::Clear(ClearType::CurrentLine))
     }
 
+    fn clear_screen(&mut self) -> Result<()> {
+        match self.tty {
+            true => {
+                self.write_command(terminal::Clear(ClearType::All))?;
+                self.write_command(cursor::MoveTo(0, 0))
+            }
+            false => Ok(()),
+        }
+    }
+
+    fn cursor_position(&mut self) -> Result<(u16, u16)> {
+        match self.io {
+            IO::Std { w: _ } => {
+                self.flush()?;
+                cursor::position()
+            }
+            IO::Custom { r: _, w: _ } => Err(std::io::Error::new(
+                std::io::ErrorKind::Unsupported,
+                "custom writers have no cursor position",
+            )),
+        }
+    }
+
+    fn enable_mouse(&mut self) -> Result<()> {
+        match self.io {
+            IO::Std { w: _ } => self.write_command(event::EnableMouseCapture),
+            IO::Custom { r: _, w: _ } => Ok(()),
+        }
+    }
+
+    fn disable_mouse(&mut self) -> Result<()> {
+        match self.io {
+            IO::Std { w: _ } => self.write_command(event::DisableMouseCapture),
+            IO::Custom { r: _, w: _ } => Ok(()),
+        }
+    }
+
+    fn enter_alternate_screen(&mut self) -> Result<()> {
+        match self.tty {
+            true => self.write_command(terminal::EnterAlternateScreen),
+            false => Ok(()),
+        }
+    }
+
+    fn leave_alternate_screen(&mut self) -> Result<()> {
+        match self.tty {
+            true => self.write_command(terminal::LeaveAlternateScreen),
+            false => Ok(()),
+        }
+    }
+
     fn cursor_hide(&mut self) -> Result<()> {
         self.write_command(cursor::Hide)
     }
@@ -254,6 +404,20 @@ impl From<KeyModifiers> for crate::ui::KeyModifiers {
     }
 }
 
+/// Translates the mouse events prompts react to, reported only after the mouse
+/// capture is enabled: left clicks, and scrolls as arrow keys.
+fn key_from_mouse_event(event: MouseEvent) -> Option<Key> {
+    match event.kind {
+        MouseEventKind::Down(MouseButton::Left) => Some(Key::Click {
+            row: event.row,
+            column: event.column,
+        }),
+        MouseEventKind::ScrollUp => Some(Key::Up(crate::ui::KeyModifiers::NONE)),
+        MouseEventKind::ScrollDown => Some(Key::Down(crate::ui::KeyModifiers::NONE)),
+        _ => None,
+    }
+}
+
 impl From<KeyEvent> for Key {
     fn from(event: KeyEvent) -> Self {
         match event {
@@ -333,25 +497,205 @@ impl From<KeyEvent> for Key {
                 modifiers: m,
                 ..
             } => Self::Char(c, m.into()),
+            KeyEvent {
+                code: KeyCode::F(n),
+                ..
+            } => Self::F(n),
             #[allow(deprecated)]
             _ => Self::Any,
         }
     }
 }
 
+/// Lines left on screen after a terminal emulator processes the output
+/// written by a [CrosstermTerminal], without their trailing whitespace.
+///
+/// Only the sequences written by this terminal are interpreted: cursor
+/// movements, line and screen clears. Styles and other sequences are skipped.
+#[cfg(test)]
+pub fn rendered_screen(output: &str) -> Vec<String> {
+    let mut lines: Vec<Vec<char>> = vec![vec![]];
+    let (mut row, mut col) = (0usize, 0usize);
+    let mut chars = output.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\x1b' if chars.peek() == Some(&'[') => {
+                chars.next();
+                let mut params = String::new();
+                let mut command = ' ';
+                for c in chars.by_ref() {
+                    if c.is_ascii_alphabetic() {
+                        command = c;
+                        break;
+                    }
+                    params.push(c);
+                }
+                // like terminals do, a missing or zero count moves by one
+                let count = params.parse::<usize>().unwrap_or(0).max(1);
+
+                match (command, params.as_str()) {
+                    ('A', _) => row = row.saturating_sub(count),
+                    ('B', _) => row += count,
+                    ('G', _) => col = count - 1,
+                    ('K', "2") => lines[row].clear(),
+                    ('J', "2") => lines.iter_mut().for_each(Vec::clear),
+                    ('H', _) => {
+                        row = 0;
+                        col = 0;
+                    }
+                    _ => {}
+                }
+            }
+            '\r' => col = 0,
+            '\n' => row += 1,
+            c => {
+                let line = &mut lines[row];
+                if line.len() <= col {
+                    line.resize(col + 1, ' ');
+                }
+                line[col] = c;
+                col += 1;
+            }
+        }
+
+        if lines.len() <= row {
+            lines.resize(row + 1, vec![]);
+        }
+    }
+
+    let mut screen: Vec<String> = lines
+        .into_iter()
+        .map(|line| line.into_iter().collect::<String>().trim_end().to_owned())
+        .collect();
+    while screen.last().map_or(false, |line| line.is_empty()) {
+        screen.pop();
+    }
+
+    screen
+}
+
 #[cfg(test)]
 mod test {
+    use crossterm::event::{
+        KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
+    };
+
     use crate::terminal::Terminal;
-    use crate::ui::Color;
+    use crate::ui::{Color, Key, KeyModifiers as Modifiers, Styled};
 
     use super::Attributes;
-    use super::CrosstermTerminal;
+    use super::{key_from_mouse_event, CrosstermTerminal};
+
+    #[test]
+    fn custom_io_has_no_terminal_size() {
+        let mut write: Vec<u8> = Vec::new();
+        let mut read = std::iter::empty();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+
+        let err = terminal.get_size().err().unwrap();
+        assert_eq!(std::io::ErrorKind::Unsupported, err.kind());
+    }
+
+    #[test]
+    fn custom_io_falls_back_to_default_size() {
+        let mut write: Vec<u8> = Vec::new();
+        let mut read = std::iter::empty();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+
+        let size = crate::terminal::size_or_default(terminal.get_size().ok());
+
+        assert_eq!(crate::ui::DEFAULT_TERMINAL_SIZE, size);
+        assert_eq!((80, 24), size);
+    }
+
+    #[test]
+    fn left_clicks_and_scrolls_are_translated() {
+        let mouse_event = |kind| MouseEvent {
+            kind,
+            column: 3,
+            row: 7,
+            modifiers: KeyModifiers::NONE,
+        };
+
+        assert_eq!(
+            Some(Key::Click { row: 7, column: 3 }),
+            key_from_mouse_event(mouse_event(MouseEventKind::Down(MouseButton::Left)))
+        );
+        assert_eq!(
+            Some(Key::Up(Modifiers::NONE)),
+            key_from_mouse_event(mouse_event(MouseEventKind::ScrollUp))
+        );
+        assert_eq!(
+            Some(Key::Down(Modifiers::NONE)),
+            key_from_mouse_event(mouse_event(MouseEventKind::ScrollDown))
+        );
+        assert_eq!(
+            None,
+            key_from_mouse_event(mouse_event(MouseEventKind::Down(MouseButton::Right)))
+        );
+        assert_eq!(
+            None,
+            key_from_mouse_event(mouse_event(MouseEventKind::Moved))
+        );
+    }
+
+    #[test]
+    fn ctrl_c_is_interrupt() {
+        let key = Key::from(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL));
+
+        assert_eq!(Key::Interrupt, key);
+    }
+
+    #[test]
+    fn esc_is_cancel() {
+        let key = Key::from(KeyEvent::from(KeyCode::Esc));
+
+        assert_eq!(Key::Cancel, key);
+    }
+
+    #[test]
+    fn enter_and_newlines_are_submit() {
+        for code in [KeyCode::Enter, KeyCode::Char('\n'), KeyCode::Char('\r')] {
+            assert_eq!(Key::Submit, Key::from(KeyEvent::from(code)));
+        }
+    }
+
+    #[test]
+    fn modifiers_are_translated() {
+        let key = Key::from(KeyEvent::new(KeyCode::Left, KeyModifiers::CONTROL));
+        assert_eq!(Key::Left(Modifiers::CONTROL), key);
+
+        let key = Key::from(KeyEvent::new(KeyCode::Char('b'), KeyModifiers::ALT));
+        assert_eq!(Key::Char('b', Modifiers::ALT), key);
+
+        let key = Key::from(KeyEvent::new(
+            KeyCode::Delete,
+            KeyModifiers::CONTROL | KeyModifiers::SHIFT,
+        ));
+        assert_eq!(Key::Delete(Modifiers::CONTROL | Modifiers::SHIFT), key);
+    }
+
+    #[test]
+    fn function_keys_are_translated() {
+        let key = Key::from(KeyEvent::from(KeyCode::F(1)));
+
+        assert_eq!(Key::F(1), key);
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn unmapped_keys_are_any() {
+        let key = Key::from(KeyEvent::from(KeyCode::Insert));
+
+        assert_eq!(Key::Any, key);
+    }
 
     #[test]
     fn writer() {
         let mut write: Vec<u8> = Vec::new();
         let read = Vec::new();
-        let mut read = read.iter();
+        let mut read = read.into_iter();
 
         {
             let mut terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
@@ -370,7 +714,7 @@ mod test {
     fn style_management() {
         let mut write: Vec<u8> = Vec::new();
         let read = Vec::new();
-        let mut read = read.iter();
+        let mut read = read.into_iter();
 
         {
             let mut terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
@@ -392,7 +736,7 @@ mod test {
     fn style_management_with_flags() {
         let mut write: Vec<u8> = Vec::new();
         let read = Vec::new();
-        let mut read = read.iter();
+        let mut read = read.into_iter();
 
         {
             let mut terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
@@ -414,7 +758,7 @@ mod test {
     fn fg_color_management() {
         let mut write: Vec<u8> = Vec::new();
         let read = Vec::new();
-        let mut read = read.iter();
+        let mut read = read.into_iter();
 
         {
             let mut terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
@@ -436,7 +780,7 @@ mod test {
     fn bg_color_management() {
         let mut write: Vec<u8> = Vec::new();
         let read = Vec::new();
-        let mut read = read.iter();
+        let mut read = read.into_iter();
 
         {
             let mut terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
@@ -453,4 +797,68 @@ mod test {
             std::str::from_utf8(&write).unwrap()
         );
     }
+
+    #[test]
+    fn styles_are_not_rendered_by_default_on_custom_io() {
+        let mut write: Vec<u8> = Vec::new();
+        let read = Vec::new();
+        let mut read = read.into_iter();
+
+        {
+            let mut terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+
+            let styled = Styled::new("red")
+                .with_fg(Color::LightRed)
+                .with_bg(Color::Black)
+                .with_attr(Attributes::BOLD | Attributes::ITALIC);
+            terminal.write_styled(&styled).unwrap();
+        }
+
+        assert_eq!("red", std::str::from_utf8(&write).unwrap());
+    }
+
+    #[test]
+    fn screen_is_cleared_only_on_terminals() {
+        let mut write: Vec<u8> = Vec::new();
+        let mut read = std::iter::empty();
+
+        {
+            let mut terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+            terminal.clear_screen().unwrap();
+        }
+
+        assert_eq!("", std::str::from_utf8(&write).unwrap());
+
+        let mut write: Vec<u8> = Vec::new();
+        let mut read = std::iter::empty();
+
+        {
+            let mut terminal = CrosstermTerminal::new_with_io(&mut write, &mut read).with_tty(true);
+            terminal.clear_screen().unwrap();
+        }
+
+        #[cfg(unix)]
+        assert_eq!("\x1B[2J\x1B[1;1H", std::str::from_utf8(&write).unwrap());
+    }
+
+    #[test]
+    fn styles_are_rendered_when_enabled() {
+        let mut write: Vec<u8> = Vec::new();
+        let read = Vec::new();
+        let mut read = read.into_iter();
+
+        {
+            let mut terminal =
+                CrosstermTerminal::new_with_io(&mut write, &mut read).with_styles(true);
+
+            let styled = Styled::new("red").with_fg(Color::LightRed);
+            terminal.write_styled(&styled).unwrap();
+        }
+
+        #[cfg(unix)]
+        assert_eq!(
+            "\x1B[38;5;9mred\x1B[39m",
+            std::str::from_utf8(&write).unwrap()
+        );
+    }
 }
@@ -1,4 +1,4 @@
-use std::{fmt::Display, io::Result};
+use std::{fmt::Display, io::Result, time::Duration};
 
 use crate::{
     error::InquireResult,
@@ -7,6 +7,10 @@ use crate::{
 
 const INITIAL_IN_MEMORY_CAPACITY: usize = 2048;
 
+/// Dimensions, as `(width, height)`, assumed when the size of the terminal
+/// can not be detected.
+pub const DEFAULT_TERMINAL_SIZE: (u16, u16) = (80, 24);
+
 #[cfg(feature = "crossterm")]
 #[cfg_attr(docsrs, doc(cfg(feature = "crossterm")))]
 pub mod crossterm;
@@ -19,6 +23,8 @@ pub mod termion;
 #[cfg_attr(docsrs, doc(cfg(feature = "console")))]
 pub mod console;
 
+pub mod replay;
+
 pub struct TerminalSize {
     pub width: u16,
     pub height: u16,
@@ -29,10 +35,24 @@ pub trait Terminal: Sized {
     fn cursor_down(&mut self, cnt: u16) -> Result<()>;
     fn cursor_move_to_column(&mut self, idx: u16) -> Result<()>;
     fn read_key(&mut self) -> Result<Key>;
+    fn read_key_timeout(&mut self, _timeout: Duration) -> Result<Option<Key>> {
+        // Terminals unable to poll for input wait for a key indefinitely.
+        self.read_key().map(Some)
+    }
+
+    /// Whether [`Terminal::read_key_timeout`] returns when the timeout expires,
+    /// instead of waiting for a key indefinitely.
+    fn can_poll(&self) -> bool {
+        false
+    }
     fn flush(&mut self) -> Result<()>;
 
     fn get_size(&self) -> Result<TerminalSize>;
 
+    /// Returns the position of the cursor on the screen as `(column, row)`,
+    /// failing when it can not be queried.
+    fn cursor_position(&mut self) -> Result<(u16, u16)>;
+
     fn get_in_memory_content(&self) -> &str;
     fn clear_in_memory_content(&mut self);
 
@@ -41,10 +61,64 @@ pub trait Terminal: Sized {
 
     fn clear_current_line(&mut self) -> Result<()>;
 
+    /// Clears the whole screen and moves the cursor to its top-left corner.
+    ///
+    /// Does nothing when the output is not known to be a terminal.
+    fn clear_screen(&mut self) -> Result<()>;
+
+    /// Switches to the alternate screen buffer, leaving the scrollback of the
+    /// main screen untouched until [`Terminal::leave_alternate_screen`].
+    ///
+    /// Both do nothing when the output is not known to be a terminal.
+    fn enter_alternate_screen(&mut self) -> Result<()>;
+    fn leave_alternate_screen(&mut self) -> Result<()>;
+
+    /// Starts reporting mouse clicks and scrolls as keys, doing nothing on
+    /// terminals without mouse support.
+    fn enable_mouse(&mut self) -> Result<()>;
+    fn disable_mouse(&mut self) -> Result<()>;
+
     fn cursor_hide(&mut self) -> Result<()>;
     fn cursor_show(&mut self) -> Result<()>;
 }
 
+/// Returns the dimensions of the user's terminal as `(width, height)`,
+/// falling back to [`DEFAULT_TERMINAL_SIZE`] when they can not be detected.
+///
+/// Unlike creating a terminal, this does not enable its raw mode, so it can
+/// be called at any time, e.g. from formatters or previews.
+pub fn get_terminal_size() -> (u16, u16) {
+    #[cfg(feature = "crossterm")]
+    let size = ::crossterm::terminal::size().ok();
+
+    #[cfg(all(feature = "termion", not(feature = "crossterm")))]
+    let size = ::termion::terminal_size().ok();
+
+    #[cfg(all(
+        feature = "console",
+        not(feature = "termion"),
+        not(feature = "crossterm")
+    ))]
+    let size = ::console::Term::stdout()
+        .size_checked()
+        .map(|(height, width)| (width, height));
+
+    #[cfg(all(
+        not(feature = "crossterm"),
+        not(feature = "termion"),
+        not(feature = "console")
+    ))]
+    let size: Option<(u16, u16)> = None;
+
+    size_or_default(size.map(|(width, height)| TerminalSize { width, height }))
+}
+
+/// Returns `size` as `(width, height)`, falling back to
+/// [`DEFAULT_TERMINAL_SIZE`] when it is unknown.
+fn size_or_default(size: Option<TerminalSize>) -> (u16, u16) {
+    size.map_or(DEFAULT_TERMINAL_SIZE, |size| (size.width, size.height))
+}
+
 pub fn get_default_terminal() -> InquireResult<impl Terminal> {
     #[cfg(feature = "crossterm")]
     return crossterm::CrosstermTerminal::new();
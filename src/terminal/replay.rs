@@ -0,0 +1,201 @@
+use std::{collections::VecDeque, fmt::Display, io::Result, time::Duration};
+
+use crate::ui::{Key, Styled};
+
+use super::{Terminal, TerminalSize};
+
+/// Terminal that records every key read from the wrapped terminal, along with
+/// the reads that timed out, recorded as `None`.
+pub struct RecordingTerminal<'a, T: Terminal> {
+    inner: T,
+    keys: &'a mut Vec<Option<Key>>,
+}
+
+impl<'a, T: Terminal> RecordingTerminal<'a, T> {
+    /// Wraps `inner`, appending the keys read from it to `keys`.
+    pub fn new(inner: T, keys: &'a mut Vec<Option<Key>>) -> Self {
+        Self { inner, keys }
+    }
+}
+
+/// Terminal that reads its keys from a previous recording, rendering to the
+/// wrapped terminal.
+///
+/// Recorded timeouts are replayed right away, as reads timing out regardless
+/// of the duration requested.
+pub struct ReplayTerminal<T: Terminal> {
+    inner: T,
+    keys: VecDeque<Option<Key>>,
+}
+
+impl<T: Terminal> ReplayTerminal<T> {
+    /// Wraps `inner`, ignoring its input in favor of `keys`.
+    pub fn new(inner: T, keys: &[Option<Key>]) -> Self {
+        Self {
+            inner,
+            keys: keys.iter().cloned().collect(),
+        }
+    }
+}
+
+/// Terminal that reads its keys from an iterator, rendering to the wrapped
+/// terminal.
+///
+/// Reads fail with an `UnexpectedEof` error once the iterator runs out, while
+/// reads with a timeout treat it as an idle user.
+pub struct KeyStreamTerminal<'a, T: Terminal> {
+    inner: T,
+    keys: &'a mut dyn Iterator<Item = Key>,
+}
+
+impl<'a, T: Terminal> KeyStreamTerminal<'a, T> {
+    /// Wraps `inner`, ignoring its input in favor of `keys`.
+    pub fn new(inner: T, keys: &'a mut dyn Iterator<Item = Key>) -> Self {
+        Self { inner, keys }
+    }
+}
+
+macro_rules! delegate_output {
+    () => {
+        fn cursor_up(&mut self, cnt: u16) -> Result<()> {
+            self.inner.cursor_up(cnt)
+        }
+
+        fn cursor_down(&mut self, cnt: u16) -> Result<()> {
+            self.inner.cursor_down(cnt)
+        }
+
+        fn cursor_move_to_column(&mut self, idx: u16) -> Result<()> {
+            self.inner.cursor_move_to_column(idx)
+        }
+
+        fn flush(&mut self) -> Result<()> {
+            self.inner.flush()
+        }
+
+        fn get_size(&self) -> Result<TerminalSize> {
+            self.inner.get_size()
+        }
+
+        fn cursor_position(&mut self) -> Result<(u16, u16)> {
+            self.inner.cursor_position()
+        }
+
+        fn get_in_memory_content(&self) -> &str {
+            self.inner.get_in_memory_content()
+        }
+
+        fn clear_in_memory_content(&mut self) {
+            self.inner.clear_in_memory_content()
+        }
+
+        fn write<D: Display>(&mut self, val: D) -> Result<()> {
+            self.inner.write(val)
+        }
+
+        fn write_styled<D: Display>(&mut self, val: &Styled<D>) -> Result<()> {
+            self.inner.write_styled(val)
+        }
+
+        fn clear_current_line(&mut self) -> Result<()> {
+            self.inner.clear_current_line()
+        }
+
+        fn clear_screen(&mut self) -> Result<()> {
+            self.inner.clear_screen()
+        }
+
+        fn enter_alternate_screen(&mut self) -> Result<()> {
+            self.inner.enter_alternate_screen()
+        }
+
+        fn leave_alternate_screen(&mut self) -> Result<()> {
+            self.inner.leave_alternate_screen()
+        }
+
+        fn enable_mouse(&mut self) -> Result<()> {
+            self.inner.enable_mouse()
+        }
+
+        fn disable_mouse(&mut self) -> Result<()> {
+            self.inner.disable_mouse()
+        }
+
+        fn cursor_hide(&mut self) -> Result<()> {
+            self.inner.cursor_hide()
+        }
+
+        fn cursor_show(&mut self) -> Result<()> {
+            self.inner.cursor_show()
+        }
+    };
+}
+
+impl<'a, T: Terminal> Terminal for RecordingTerminal<'a, T> {
+    fn read_key(&mut self) -> Result<Key> {
+        let key = self.inner.read_key()?;
+        self.keys.push(Some(key));
+
+        Ok(key)
+    }
+
+    fn read_key_timeout(&mut self, timeout: Duration) -> Result<Option<Key>> {
+        let key = self.inner.read_key_timeout(timeout)?;
+        self.keys.push(key);
+
+        Ok(key)
+    }
+
+    fn can_poll(&self) -> bool {
+        self.inner.can_poll()
+    }
+
+    delegate_output!();
+}
+
+impl<T: Terminal> Terminal for ReplayTerminal<T> {
+    fn read_key(&mut self) -> Result<Key> {
+        // reads without a timeout wait for the next key
+        loop {
+            if let Some(key) = self.read_key_timeout(Duration::ZERO)? {
+                return Ok(key);
+            }
+        }
+    }
+
+    fn read_key_timeout(&mut self, _timeout: Duration) -> Result<Option<Key>> {
+        self.keys.pop_front().ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::UnexpectedEof,
+                "Recorded keys have been exhausted",
+            )
+        })
+    }
+
+    fn can_poll(&self) -> bool {
+        true
+    }
+
+    delegate_output!();
+}
+
+impl<'a, T: Terminal> Terminal for KeyStreamTerminal<'a, T> {
+    fn read_key(&mut self) -> Result<Key> {
+        self.keys.next().ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::UnexpectedEof,
+                "Stream of keys has ended",
+            )
+        })
+    }
+
+    fn read_key_timeout(&mut self, _timeout: Duration) -> Result<Option<Key>> {
+        Ok(self.keys.next())
+    }
+
+    fn can_poll(&self) -> bool {
+        true
+    }
+
+    delegate_output!();
+}
@@ -185,6 +185,53 @@ impl<'a> Terminal for TermionTerminal<'a> {
         write!(self.get_writer(), "{}", termion::clear::CurrentLine)
     }
 
+    fn clear_screen(&mut self) -> Result<()> {
+        match self.io {
+            IO::Std { r: _, w: _ } => write!(
+                self.get_writer(),
+                "{}{}",
+                termion::clear::All,
+                cursor::Goto(1, 1)
+            ),
+            IO::Custom { r: _, w: _ } => Ok(()),
+        }
+    }
+
+    fn cursor_position(&mut self) -> Result<(u16, u16)> {
+        Err(std::io::Error::new(
+            std::io::ErrorKind::Unsupported,
+            "cursor position is not supported",
+        ))
+    }
+
+    // reading mouse events requires reading termion events instead of keys,
+    // so the mouse is not supported
+    fn enable_mouse(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn disable_mouse(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn enter_alternate_screen(&mut self) -> Result<()> {
+        match self.io {
+            IO::Std { r: _, w: _ } => {
+                write!(self.get_writer(), "{}", termion::screen::ToAlternateScreen)
+            }
+            IO::Custom { r: _, w: _ } => Ok(()),
+        }
+    }
+
+    fn leave_alternate_screen(&mut self) -> Result<()> {
+        match self.io {
+            IO::Std { r: _, w: _ } => {
+                write!(self.get_writer(), "{}", termion::screen::ToMainScreen)
+            }
+            IO::Custom { r: _, w: _ } => Ok(()),
+        }
+    }
+
     fn cursor_hide(&mut self) -> Result<()> {
         write!(self.get_writer(), "{}", termion::cursor::Hide)
     }
@@ -266,6 +313,7 @@ impl From<Key> for crate::ui::Key {
             Key::Char(c) => Self::Char(c, KeyModifiers::empty()),
             Key::Ctrl(c) => Self::Char(c, KeyModifiers::CONTROL),
             Key::Alt(c) => Self::Char(c, KeyModifiers::ALT),
+            Key::F(n) => Self::F(n),
             #[allow(deprecated)]
             _ => Self::Any,
         }
@@ -274,8 +322,10 @@ impl From<Key> for crate::ui::Key {
 
 #[cfg(test)]
 mod test {
+    use termion::event::{parse_event, Event};
+
     use crate::terminal::Terminal;
-    use crate::ui::Color;
+    use crate::ui::{Color, Key, KeyModifiers};
 
     use super::Attributes;
     use super::TermionTerminal;
@@ -383,4 +433,26 @@ mod test {
             std::str::from_utf8(&write).unwrap()
         );
     }
+
+    fn translate(bytes: &[u8]) -> Key {
+        let mut rest = bytes[1..].iter().map(|b| Ok(*b));
+
+        match parse_event(bytes[0], &mut rest).unwrap() {
+            Event::Key(key) => Key::from(key),
+            event => panic!("unexpected event: {:?}", event),
+        }
+    }
+
+    #[test]
+    fn escape_sequences_are_translated() {
+        assert_eq!(Key::Interrupt, translate(b"\x03"));
+        assert_eq!(Key::Submit, translate(b"\r"));
+        assert_eq!(Key::Up(KeyModifiers::empty()), translate(b"\x1B[A"));
+        assert_eq!(Key::Left(KeyModifiers::empty()), translate(b"\x1B[D"));
+        assert_eq!(Key::Home, translate(b"\x1B[H"));
+        assert_eq!(Key::Delete(KeyModifiers::empty()), translate(b"\x1B[3~"));
+        assert_eq!(Key::Char('b', KeyModifiers::ALT), translate(b"\x1Bb"));
+        assert_eq!(Key::Char('w', KeyModifiers::CONTROL), translate(b"\x17"));
+        assert_eq!(Key::F(1), translate(b"\x1BOP"));
+    }
 }
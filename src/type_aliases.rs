@@ -1,6 +1,8 @@
 //! General type aliases.
 
-use crate::error::CustomUserError;
+use std::ops::Range;
+
+use crate::{error::CustomUserError, ui::Key, PasswordStrength};
 
 /// Type alias to represent the function used to filter options.
 ///
@@ -38,6 +40,22 @@ use crate::error::CustomUserError;
 /// ```
 pub type Filter<'a, T> = &'a dyn Fn(&str, &T, &str, usize) -> bool;
 
+/// Type alias to represent the function used to retrieve the options of a
+/// [`LazySelect`](crate::LazySelect) prompt on demand.
+///
+/// The function receives a range of indexes of the options and should return
+/// the options in this range, in order.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::type_aliases::OptionProvider;
+///
+/// let provider: OptionProvider<String> = &|range| range.map(|i| format!("Item {}", i)).collect();
+/// assert_eq!(vec!["Item 3", "Item 4"], provider(3..5));
+/// ```
+pub type OptionProvider<'a, T> = &'a dyn Fn(Range<usize>) -> Vec<T>;
+
 /// Type alias to represent the function used to retrieve text input suggestions.
 /// The function receives the current input and should return a collection of strings
 /// containing the suggestions to be made to the user.
@@ -47,3 +65,78 @@ pub type Suggester<'a> = &'a dyn Fn(&str) -> Result<Vec<String>, CustomUserError
 /// The function receives the current input and should return the suggestion (if any)
 /// that will replace the current input.
 pub type Completer<'a> = &'a dyn Fn(&str) -> Result<Option<String>, CustomUserError>;
+
+/// Type alias to represent the function used to preview the highlighted suggestion
+/// of a text input, e.g. displaying the first lines of a file.
+///
+/// The function receives the value of the highlighted suggestion and should return
+/// the content of the preview, which may span multiple lines.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::type_aliases::SuggestionPreview;
+///
+/// let preview: SuggestionPreview = &|suggestion| format!("{} characters", suggestion.len());
+/// assert_eq!(String::from("5 characters"), preview("hello"));
+/// ```
+pub type SuggestionPreview<'a> = &'a dyn Fn(&str) -> String;
+
+/// Type alias to represent the function used to transform a suggestion picked by
+/// the user before it is inserted into the text input or submitted, e.g. expanding
+/// a displayed file name into its absolute path.
+///
+/// The function receives the value of the suggestion and should return the text
+/// to be used in its place.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::type_aliases::SuggestionTransform;
+///
+/// let transform: SuggestionTransform = &|suggestion| format!("/home/{}", suggestion);
+/// assert_eq!(String::from("/home/ferris"), transform("ferris"));
+/// ```
+pub type SuggestionTransform<'a> = &'a dyn Fn(&str) -> String;
+
+/// Type alias to represent the function used to score the strength of the
+/// password typed in a [`Password`](crate::Password) prompt, displayed by its
+/// strength meter.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::{type_aliases::PasswordScorer, PasswordStrength};
+///
+/// let scorer: PasswordScorer = &|password| match password.len() {
+///     0..=9 => PasswordStrength::Weak,
+///     10..=19 => PasswordStrength::Medium,
+///     _ => PasswordStrength::Strong,
+/// };
+/// assert_eq!(PasswordStrength::Weak, scorer("hunter2"));
+/// assert_eq!(PasswordStrength::Strong, scorer("correct horse battery staple"));
+/// ```
+pub type PasswordScorer<'a> = &'a dyn Fn(&str) -> PasswordStrength;
+
+/// Type alias to represent the function called on every key pressed by the user
+/// in a prompt, e.g. to collect usage metrics.
+///
+/// The function receives the key pressed and the text input after the key was handled.
+///
+/// # Examples
+///
+/// ```
+/// use std::cell::Cell;
+/// use inquire::{type_aliases::EventHook, ui::Key};
+///
+/// let backspaces = Cell::new(0);
+/// let hook: EventHook = &|key, _| {
+///     if let Key::Backspace = key {
+///         backspaces.set(backspaces.get() + 1);
+///     }
+/// };
+///
+/// hook(&Key::Backspace, "");
+/// assert_eq!(1, backspaces.get());
+/// ```
+pub type EventHook<'a> = &'a dyn Fn(&Key, &str);
@@ -1,27 +1,45 @@
-use std::{collections::BTreeSet, fmt::Display, io::Result};
+use std::{collections::BTreeSet, fmt::Display, io::Result, time::Duration};
 
-use unicode_width::UnicodeWidthChar;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
 use crate::{
+    autocompletion::Suggestion,
     input::Input,
     list_option::ListOption,
     terminal::{Terminal, TerminalSize},
-    ui::{IndexPrefix, Key, RenderConfig, Styled},
+    ui::{
+        AnswerLayout, ErrorMessageRenderConfig, IndexPrefix, Key, RenderConfig, StyleSheet, Styled,
+        TextDirection,
+    },
     utils::{int_log10, Page},
     validator::ErrorMessage,
+    PasswordStrength,
 };
 
 pub trait CommonBackend {
     fn read_key(&mut self) -> Result<Key>;
+    fn read_key_timeout(&mut self, timeout: Duration) -> Result<Option<Key>>;
+    fn can_poll(&self) -> bool;
 
     fn frame_setup(&mut self) -> Result<()>;
+    fn frame_setup_below(&mut self) -> Result<()>;
     fn frame_finish(&mut self) -> Result<()>;
 
     fn render_canceled_prompt(&mut self, prompt: &str) -> Result<()>;
     fn render_prompt_with_answer(&mut self, prompt: &str, answer: &str) -> Result<()>;
 
     fn render_error_message(&mut self, error: &ErrorMessage) -> Result<()>;
+    fn render_warning_message(&mut self, warning: &ErrorMessage) -> Result<()>;
     fn render_help_message(&mut self, help: &str) -> Result<()>;
+    fn render_help_panel(&mut self, lines: &[String]) -> Result<()>;
+    fn render_no_matches_message(&mut self, message: &str) -> Result<()>;
+
+    fn ring_bell(&mut self) -> Result<()>;
+    fn clear_screen(&mut self) -> Result<()>;
+    fn enable_mouse(&mut self) -> Result<()>;
+    fn disable_mouse(&mut self) -> Result<()>;
+    fn enter_alternate_screen(&mut self) -> Result<()>;
+    fn leave_alternate_screen(&mut self) -> Result<()>;
 }
 
 pub trait TextBackend: CommonBackend {
@@ -31,7 +49,10 @@ pub trait TextBackend: CommonBackend {
         default: Option<&str>,
         cur_input: &Input,
     ) -> Result<()>;
-    fn render_suggestions<D: Display>(&mut self, page: Page<ListOption<D>>) -> Result<()>;
+    fn render_suggestions(&mut self, page: Page<ListOption<&Suggestion>>) -> Result<()>;
+    fn render_hidden_suggestions(&mut self, count: usize) -> Result<()>;
+    fn render_preview(&mut self, preview: &str) -> Result<()>;
+    fn render_list_item(&mut self, item: &str) -> Result<()>;
 }
 
 #[cfg(feature = "editor")]
@@ -41,7 +62,15 @@ pub trait EditorBackend: CommonBackend {
 
 pub trait SelectBackend: CommonBackend {
     fn render_select_prompt(&mut self, prompt: &str, cur_input: &Input) -> Result<()>;
-    fn render_options<D: Display>(&mut self, page: Page<ListOption<D>>) -> Result<()>;
+    fn render_options<D: Display>(
+        &mut self,
+        page: Page<ListOption<D>>,
+        headers: &BTreeSet<usize>,
+        disabled: &BTreeSet<usize>,
+        numbered: bool,
+        page_indicator: bool,
+    ) -> Result<()>;
+    fn option_at_row(&mut self, row: u16) -> Option<usize>;
 }
 
 pub trait MultiSelectBackend: CommonBackend {
@@ -50,7 +79,16 @@ pub trait MultiSelectBackend: CommonBackend {
         &mut self,
         page: Page<ListOption<D>>,
         checked: &BTreeSet<usize>,
+        disabled: &BTreeSet<usize>,
+        page_indicator: bool,
+    ) -> Result<()>;
+    fn render_selection_count(
+        &mut self,
+        selected: usize,
+        min: Option<usize>,
+        max: Option<usize>,
     ) -> Result<()>;
+    fn option_at_row(&mut self, row: u16) -> Option<usize>;
 }
 
 pub trait CustomTypeBackend: CommonBackend {
@@ -66,6 +104,7 @@ pub trait PasswordBackend: CommonBackend {
     fn render_prompt(&mut self, prompt: &str) -> Result<()>;
     fn render_prompt_with_masked_input(&mut self, prompt: &str, cur_input: &Input) -> Result<()>;
     fn render_prompt_with_full_input(&mut self, prompt: &str, cur_input: &Input) -> Result<()>;
+    fn render_strength_meter(&mut self, strength: PasswordStrength) -> Result<()>;
 }
 
 #[derive(Clone, Copy, Debug, Default)]
@@ -83,6 +122,9 @@ where
     prompt_cursor_offset: Option<usize>,
     prompt_cursor_position: Option<Position>,
     show_cursor: bool,
+    option_offsets: Vec<(usize, usize)>,
+    option_rows: Vec<(u16, usize)>,
+    pending_indent: bool,
     terminal: T,
     terminal_size: TerminalSize,
     render_config: RenderConfig,
@@ -104,6 +146,9 @@ where
             prompt_cursor_offset: None,
             prompt_cursor_position: None,
             show_cursor: false,
+            option_offsets: vec![],
+            option_rows: vec![],
+            pending_indent: true,
             terminal,
             render_config,
             terminal_size,
@@ -119,10 +164,22 @@ where
         let term_width = self.terminal_size.width;
 
         let mut cur_pos = Position::default();
+        let mut option_offsets = self.option_offsets.iter().peekable();
+        let mut option_rows = vec![];
 
-        for (idx, c) in input.chars().enumerate() {
+        for (idx, (byte_offset, c)) in input.char_indices().enumerate() {
             let len = UnicodeWidthChar::width(c).unwrap_or(0) as u16;
 
+            // options start on a new line, so the row before the character
+            // is processed is the one the option is rendered at
+            while let Some((offset, index)) =
+                option_offsets.next_if(|(offset, _)| *offset <= byte_offset)
+            {
+                if *offset == byte_offset {
+                    option_rows.push((cur_pos.row, *index));
+                }
+            }
+
             if c == '\n' {
                 cur_pos.row = cur_pos.row.saturating_add(1);
                 cur_pos.col = 0;
@@ -148,6 +205,28 @@ where
 
         self.prompt_current_position = cur_pos;
         self.prompt_end_position = cur_pos;
+        self.option_rows = option_rows;
+    }
+
+    /// Re-queries the terminal size, recomputing the positions of the last
+    /// rendered frame if the width changed, so that the frame is fully cleared
+    /// even after the terminal re-wrapped its lines.
+    fn refresh_terminal_size(&mut self) {
+        let terminal_size = match self.terminal.get_size() {
+            Ok(terminal_size) => terminal_size,
+            Err(_) => return,
+        };
+
+        if terminal_size.width == self.terminal_size.width {
+            return;
+        }
+
+        self.terminal_size = terminal_size;
+        self.update_position_info();
+
+        if let Some(prompt_cursor_position) = self.prompt_cursor_position {
+            self.prompt_current_position = prompt_cursor_position;
+        }
     }
 
     fn move_cursor_to_end_position(&mut self) -> Result<()> {
@@ -187,12 +266,21 @@ where
             self.terminal.clear_current_line()?;
         }
 
+        self.forget_prompt()
+    }
+
+    /// Starts tracking a new prompt from the current cursor position, leaving
+    /// whatever was previously rendered untouched.
+    fn forget_prompt(&mut self) -> Result<()> {
         self.terminal.clear_in_memory_content();
+        self.option_offsets.clear();
+        self.option_rows.clear();
 
         self.prompt_current_position = Position::default();
         self.prompt_end_position = Position::default();
         self.prompt_cursor_position = None;
         self.prompt_cursor_offset = None;
+        self.pending_indent = true;
 
         // let's default to false to catch any previous
         // default behaviors we didn't account for
@@ -219,18 +307,70 @@ where
             empty_prefix
         };
 
-        self.terminal.write_styled(&x)
+        self.write_styled(&x)
     }
 
     fn print_option_value<D: Display>(&mut self, option: &ListOption<D>) -> Result<()> {
-        self.terminal
-            .write_styled(&Styled::new(&option.value).with_style_sheet(self.render_config.option))
+        self.print_option_value_with_style(option, self.render_config.option)
     }
 
-    fn print_option_index_prefix(&mut self, index: usize, max_index: usize) -> Option<Result<()>> {
+    fn print_option_value_with_style<D: Display>(
+        &mut self,
+        option: &ListOption<D>,
+        style_sheet: StyleSheet,
+    ) -> Result<()> {
+        self.write_styled(&Styled::new(&option.value).with_style_sheet(style_sheet))
+    }
+
+    fn option_style_sheet(&self, index: usize, disabled: &BTreeSet<usize>) -> StyleSheet {
+        if disabled.contains(&index) {
+            self.render_config.disabled_option
+        } else {
+            self.render_config.option
+        }
+    }
+
+    fn print_error_message(
+        &mut self,
+        error: &ErrorMessage,
+        config: &ErrorMessageRenderConfig,
+    ) -> Result<()> {
+        self.write_styled(&config.prefix)?;
+
+        self.write_styled(&Styled::new(" ").with_style_sheet(config.separator))?;
+
+        let message = match error {
+            ErrorMessage::Default => config.default_message,
+            ErrorMessage::Custom(msg) => msg,
+            ErrorMessage::Coded(err) => &err.message,
+        };
+
+        self.write_styled(&Styled::new(message).with_style_sheet(config.message))?;
+
+        self.new_line()?;
+
+        Ok(())
+    }
+
+    fn print_option_index_prefix(
+        &mut self,
+        index_prefix: IndexPrefix,
+        index: usize,
+        max_index: usize,
+    ) -> Option<Result<()>> {
+        Self::option_index_prefix(index_prefix, index, max_index).map(|prefix| {
+            self.write_styled(&Styled::new(prefix).with_style_sheet(self.render_config.option))
+        })
+    }
+
+    fn option_index_prefix(
+        index_prefix: IndexPrefix,
+        index: usize,
+        max_index: usize,
+    ) -> Option<String> {
         let index = index.saturating_add(1);
 
-        let content = match self.render_config.option_index_prefix {
+        match index_prefix {
             IndexPrefix::None => None,
             IndexPrefix::Simple => Some(format!("{})", index)),
             IndexPrefix::SpacePadded => {
@@ -241,28 +381,139 @@ where
                 let width = int_log10(max_index.saturating_add(1));
                 Some(format!("{:0width$})", index, width = width))
             }
-        };
+        }
+    }
 
-        content.map(|prefix| {
-            self.terminal
-                .write_styled(&Styled::new(prefix).with_style_sheet(self.render_config.option))
-        })
+    /// Records that the option with the given index is rendered next, for
+    /// [`rendered_option_at_row`](Self::rendered_option_at_row).
+    fn mark_option_start(&mut self, index: usize) {
+        let offset = self.terminal.get_in_memory_content().len();
+        self.option_offsets.push((offset, index));
+    }
+
+    /// Returns the index of the option rendered at the given row of the
+    /// screen in the last frame, if any.
+    fn rendered_option_at_row(&mut self, row: u16) -> Option<usize> {
+        // the cursor is the only point of the frame whose position on the
+        // screen can be queried, the position of the rows being relative to it
+        let (_, cursor_row) = self.terminal.cursor_position().ok()?;
+        let frame_top = cursor_row.checked_sub(self.prompt_current_position.row)?;
+        let row = row.checked_sub(frame_top)?;
+
+        self.option_rows
+            .iter()
+            .find(|(option_row, _)| *option_row == row)
+            .map(|(_, index)| *index)
+    }
+
+    /// Amount of rows taken by a line of the given width, once wrapped by
+    /// the terminal.
+    fn rows_of(&self, width: usize) -> usize {
+        let term_width = usize::from(self.terminal_size.width).max(1);
+
+        ((width + term_width - 1) / term_width).max(1)
+    }
+
+    /// Narrows the page down to the options around the highlighted one that
+    /// fit in the rows left on the screen, keeping `footer_rows` rows below
+    /// them, e.g. for the help message.
+    ///
+    /// Options wrap into several rows once the terminal is narrowed, and a
+    /// page taller than the screen would scroll the highlighted option away.
+    /// `width` returns the columns taken by an option after its prefix.
+    fn fit_page<'p, D, F>(
+        &self,
+        page: Page<'p, ListOption<D>>,
+        footer_rows: usize,
+        width: F,
+    ) -> Page<'p, ListOption<D>>
+    where
+        F: Fn(&ListOption<D>) -> usize,
+    {
+        if page.selection >= page.content.len() {
+            return page;
+        }
+
+        let prefix_width = self.render_config.indent
+            + self.render_config.highlighted_option_prefix.content.width()
+            + 1;
+        let rows = |option: &ListOption<D>| self.rows_of(prefix_width + width(option));
+
+        let used_rows: usize = self
+            .terminal
+            .get_in_memory_content()
+            .split_terminator('\n')
+            .map(|line| self.rows_of(line.width()))
+            .sum();
+        let rows_left = usize::from(self.terminal_size.height)
+            .saturating_sub(used_rows)
+            .saturating_sub(footer_rows);
+
+        let (mut start, mut end) = (page.selection, page.selection + 1);
+        let mut taken = rows(&page.content[page.selection]);
+        loop {
+            let mut grown = false;
+
+            if let Some(option) = page.content.get(end) {
+                if taken + rows(option) <= rows_left {
+                    taken += rows(option);
+                    end += 1;
+                    grown = true;
+                }
+            }
+            if let Some(option) = start.checked_sub(1).map(|i| &page.content[i]) {
+                if taken + rows(option) <= rows_left {
+                    taken += rows(option);
+                    start -= 1;
+                    grown = true;
+                }
+            }
+
+            if !grown {
+                break;
+            }
+        }
+
+        Page {
+            first: page.first && start == 0,
+            last: page.last && end == page.content.len(),
+            content: &page.content[start..end],
+            start: page.start + start,
+            selection: page.selection - start,
+            total: page.total,
+        }
+    }
+
+    /// Prints the range of options displayed in `page`, once fitted to the
+    /// screen, e.g. `showing 8-14 of 20`.
+    fn print_page_indicator<D>(&mut self, page: &Page<'_, D>) -> Result<()> {
+        let indicator = format!(
+            "showing {}-{} of {}",
+            page.start + 1,
+            page.start + page.content.len(),
+            page.total
+        );
+
+        self.write_styled(
+            &Styled::new(indicator).with_style_sheet(self.render_config.help_message),
+        )?;
+
+        self.new_line()
     }
 
     fn print_default_value(&mut self, value: &str) -> Result<()> {
         let content = format!("({})", value);
         let token = Styled::new(content).with_style_sheet(self.render_config.default_value);
 
-        self.terminal.write_styled(&token)
+        self.write_styled(&token)
     }
 
     fn print_prompt_with_prefix(&mut self, prefix: Styled<&str>, prompt: &str) -> Result<()> {
-        self.terminal.write_styled(&prefix)?;
+        self.write_styled(&prefix)?;
 
-        self.terminal.write(" ")?;
+        self.write(" ")?;
 
-        self.terminal
-            .write_styled(&Styled::new(prompt).with_style_sheet(self.render_config.prompt))?;
+        self.write_styled(&Styled::new(prompt).with_style_sheet(self.render_config.prompt))?;
 
         Ok(())
     }
@@ -271,23 +522,58 @@ where
         self.print_prompt_with_prefix(self.render_config.prompt_prefix, prompt)
     }
 
+    /// Amount of spaces written before a right-to-left input so that it ends
+    /// at the right edge of the terminal, `0` when it does not fit the line.
+    fn rtl_padding(&self, input: &Input) -> usize {
+        let content = match input.is_empty() {
+            true => input.placeholder().unwrap_or(""),
+            false => input.content(),
+        };
+        if content.contains('\n') {
+            return 0;
+        }
+
+        let line = self
+            .terminal
+            .get_in_memory_content()
+            .rsplit('\n')
+            .next()
+            .unwrap_or("");
+        let cursor_space = match input.cursor() == input.length() {
+            true => 1,
+            false => 0,
+        };
+        let used = line.width() + content.width() + cursor_space;
+
+        usize::from(self.terminal_size.width).saturating_sub(used)
+    }
+
     fn print_input(&mut self, input: &Input) -> Result<()> {
-        self.terminal.write(" ")?;
+        self.write(" ")?;
+
+        if self.render_config.direction == TextDirection::Rtl {
+            let padding = self.rtl_padding(input);
+            self.write(" ".repeat(padding))?;
+        }
 
-        let cursor_offset = input.pre_cursor().chars().count();
+        // new lines are written as "\r\n", taking an extra character, and
+        // are followed by the indentation of the line
+        let pre_cursor = input.pre_cursor();
+        let cursor_offset = pre_cursor.chars().count()
+            + pre_cursor.matches('\n').count() * (1 + self.render_config.indent);
         self.mark_prompt_cursor_position(cursor_offset);
         self.show_cursor = true;
 
         if input.is_empty() {
             match input.placeholder() {
                 None => {}
-                Some(p) if p.is_empty() => {}
-                Some(p) => self.terminal.write_styled(
+                Some("") => {}
+                Some(p) => self.write_styled(
                     &Styled::new(p).with_style_sheet(self.render_config.placeholder),
                 )?,
             }
         } else {
-            self.terminal.write_styled(
+            self.write_styled(
                 &Styled::new(input.content()).with_style_sheet(self.render_config.text_input),
             )?;
         }
@@ -296,7 +582,7 @@ where
         // a space, otherwise the cursor will render on the
         // \n character, on the next line.
         if input.cursor() == input.length() {
-            self.terminal.write(' ')?;
+            self.write(' ')?;
         }
 
         Ok(())
@@ -311,7 +597,7 @@ where
         self.print_prompt(prompt)?;
 
         if let Some(default) = default {
-            self.terminal.write(" ")?;
+            self.write(" ")?;
             self.print_default_value(default)?;
         }
 
@@ -330,6 +616,61 @@ where
 
     fn new_line(&mut self) -> Result<()> {
         self.terminal.write("\r\n")?;
+        self.pending_indent = true;
+        Ok(())
+    }
+
+    fn write<D: Display>(&mut self, val: D) -> Result<()> {
+        match self.render_config.indent {
+            0 => self.terminal.write(val),
+            _ => self.write_indented(&val.to_string(), None),
+        }
+    }
+
+    fn write_styled<D: Display>(&mut self, val: &Styled<D>) -> Result<()> {
+        match self.render_config.indent {
+            0 => self.terminal.write_styled(val),
+            _ => self.write_indented(&val.content.to_string(), Some(val.style)),
+        }
+    }
+
+    /// Writes the content line by line, indenting the ones it starts.
+    ///
+    /// Lines ended by [`new_line`](Self::new_line) are only indented when
+    /// something is written on the next one, so that the cursor is left at
+    /// the start of an empty line when a frame is finished.
+    fn write_indented(&mut self, content: &str, style: Option<StyleSheet>) -> Result<()> {
+        for (idx, line) in content.split('\n').enumerate() {
+            if idx > 0 {
+                self.terminal.write("\r\n")?;
+                self.pending_indent = true;
+            }
+
+            let line = line.strip_suffix('\r').unwrap_or(line);
+            if idx > 0 || !line.is_empty() {
+                self.print_pending_indent()?;
+            }
+            if line.is_empty() {
+                continue;
+            }
+
+            match style {
+                Some(style) => self
+                    .terminal
+                    .write_styled(&Styled::new(line).with_style_sheet(style))?,
+                None => self.terminal.write(line)?,
+            }
+        }
+
+        Ok(())
+    }
+
+    fn print_pending_indent(&mut self) -> Result<()> {
+        if self.pending_indent {
+            self.pending_indent = false;
+            self.terminal.write(" ".repeat(self.render_config.indent))?;
+        }
+
         Ok(())
     }
 }
@@ -342,9 +683,28 @@ where
         self.terminal.cursor_hide()?;
         self.terminal.flush()?;
 
+        self.refresh_terminal_size();
+
         self.reset_prompt()
     }
 
+    fn frame_setup_below(&mut self) -> Result<()> {
+        self.terminal.cursor_hide()?;
+        self.terminal.flush()?;
+
+        self.refresh_terminal_size();
+
+        // frames usually end with a line break, in which case the cursor is
+        // already at the start of a fresh line
+        self.move_cursor_to_end_position()?;
+        match self.prompt_end_position.col {
+            0 => self.terminal.cursor_move_to_column(0)?,
+            _ => self.new_line()?,
+        }
+
+        self.forget_prompt()
+    }
+
     fn frame_finish(&mut self) -> Result<()> {
         self.update_position_info();
 
@@ -366,10 +726,10 @@ where
     fn render_canceled_prompt(&mut self, prompt: &str) -> Result<()> {
         self.print_prompt(prompt)?;
 
-        self.terminal.write(" ")?;
+        self.write(" ")?;
 
-        self.terminal
-            .write_styled(&self.render_config.canceled_prompt_indicator)?;
+        let indicator = self.render_config.canceled_prompt_indicator;
+        self.write_styled(&indicator)?;
 
         self.new_line()?;
 
@@ -379,10 +739,13 @@ where
     fn render_prompt_with_answer(&mut self, prompt: &str, answer: &str) -> Result<()> {
         self.print_prompt_with_prefix(self.render_config.answered_prompt_prefix, prompt)?;
 
-        self.terminal.write(" ")?;
+        match self.render_config.answer_layout {
+            AnswerLayout::Inline => self.write(" ")?,
+            AnswerLayout::Below => self.new_line()?,
+        }
 
         let token = Styled::new(answer).with_style_sheet(self.render_config.answer);
-        self.terminal.write_styled(&token)?;
+        self.write_styled(&token)?;
 
         self.new_line()?;
 
@@ -393,42 +756,95 @@ where
         self.terminal.read_key()
     }
 
+    fn read_key_timeout(&mut self, timeout: Duration) -> Result<Option<Key>> {
+        self.terminal.read_key_timeout(timeout)
+    }
+
+    fn can_poll(&self) -> bool {
+        self.terminal.can_poll()
+    }
+
     fn render_error_message(&mut self, error: &ErrorMessage) -> Result<()> {
-        self.terminal
-            .write_styled(&self.render_config.error_message.prefix)?;
+        let config = self.render_config.error_message;
+        self.print_error_message(error, &config)
+    }
 
-        self.terminal.write_styled(
-            &Styled::new(" ").with_style_sheet(self.render_config.error_message.separator),
-        )?;
+    fn render_warning_message(&mut self, warning: &ErrorMessage) -> Result<()> {
+        let config = self.render_config.warning_message;
+        self.print_error_message(warning, &config)
+    }
 
-        let message = match error {
-            ErrorMessage::Default => self.render_config.error_message.default_message,
-            ErrorMessage::Custom(msg) => msg,
-        };
+    fn render_help_message(&mut self, help: &str) -> Result<()> {
+        if let Some(separator) = self.render_config.help_message_separator {
+            self.write_styled(&separator)?;
+            self.new_line()?;
+        }
 
-        self.terminal.write_styled(
-            &Styled::new(message).with_style_sheet(self.render_config.error_message.message),
-        )?;
+        self.write_styled(&Styled::new("[").with_style_sheet(self.render_config.help_message))?;
+
+        self.write_styled(&Styled::new(help).with_style_sheet(self.render_config.help_message))?;
+
+        self.write_styled(&Styled::new("]").with_style_sheet(self.render_config.help_message))?;
 
         self.new_line()?;
 
         Ok(())
     }
 
-    fn render_help_message(&mut self, help: &str) -> Result<()> {
-        self.terminal
-            .write_styled(&Styled::new("[").with_style_sheet(self.render_config.help_message))?;
+    fn render_help_panel(&mut self, lines: &[String]) -> Result<()> {
+        for line in lines {
+            self.write_styled(
+                &Styled::new(line).with_style_sheet(self.render_config.help_message),
+            )?;
+
+            self.new_line()?;
+        }
 
-        self.terminal
-            .write_styled(&Styled::new(help).with_style_sheet(self.render_config.help_message))?;
+        Ok(())
+    }
 
-        self.terminal
-            .write_styled(&Styled::new("]").with_style_sheet(self.render_config.help_message))?;
+    fn render_no_matches_message(&mut self, message: &str) -> Result<()> {
+        self.write_styled(&Styled::new(message).with_style_sheet(self.render_config.placeholder))?;
 
         self.new_line()?;
 
         Ok(())
     }
+
+    fn ring_bell(&mut self) -> Result<()> {
+        self.terminal.write('\x07')
+    }
+
+    fn clear_screen(&mut self) -> Result<()> {
+        self.terminal.clear_screen()?;
+        self.terminal.flush()
+    }
+
+    fn enable_mouse(&mut self) -> Result<()> {
+        self.terminal.enable_mouse()?;
+        self.terminal.flush()
+    }
+
+    fn disable_mouse(&mut self) -> Result<()> {
+        self.terminal.disable_mouse()?;
+        self.terminal.flush()
+    }
+
+    fn enter_alternate_screen(&mut self) -> Result<()> {
+        self.terminal.enter_alternate_screen()?;
+        self.terminal.flush()?;
+
+        self.forget_prompt()
+    }
+
+    fn leave_alternate_screen(&mut self) -> Result<()> {
+        self.terminal.leave_alternate_screen()?;
+        self.terminal.flush()?;
+
+        // the frames rendered in the alternate screen are gone, so the
+        // cursor must not be moved past them when the backend is dropped
+        self.forget_prompt()
+    }
 }
 
 impl<T> TextBackend for Backend<T>
@@ -444,19 +860,67 @@ where
         self.print_prompt_with_input(prompt, default, cur_input)
     }
 
-    fn render_suggestions<D: Display>(&mut self, page: Page<ListOption<D>>) -> Result<()> {
+    fn render_suggestions(&mut self, page: Page<ListOption<&Suggestion>>) -> Result<()> {
+        let page = self.fit_page(page, 1, |option| {
+            let description_width = option
+                .value
+                .description
+                .as_ref()
+                .map_or(0, |description| description.width() + 1);
+
+            option.value.to_string().width() + description_width
+        });
+
         for (idx, option) in page.content.iter().enumerate() {
             self.print_option_prefix(idx, &page)?;
 
-            self.terminal.write(" ")?;
+            self.write(" ")?;
 
             self.print_option_value(option)?;
 
+            if let Some(description) = &option.value.description {
+                self.write(" ")?;
+                self.write_styled(
+                    &Styled::new(description)
+                        .with_style_sheet(self.render_config.suggestion_description),
+                )?;
+            }
+
             self.new_line()?;
         }
 
         Ok(())
     }
+
+    fn render_hidden_suggestions(&mut self, count: usize) -> Result<()> {
+        let indicator = format!("...and {} more", count);
+
+        self.write_styled(
+            &Styled::new(indicator).with_style_sheet(self.render_config.help_message),
+        )?;
+
+        self.new_line()?;
+
+        Ok(())
+    }
+
+    fn render_preview(&mut self, preview: &str) -> Result<()> {
+        for line in preview.lines() {
+            self.write(line)?;
+            self.new_line()?;
+        }
+
+        Ok(())
+    }
+
+    fn render_list_item(&mut self, item: &str) -> Result<()> {
+        self.write("  - ")?;
+
+        let token = Styled::new(item).with_style_sheet(self.render_config.answer);
+        self.write_styled(&token)?;
+
+        self.new_line()
+    }
 }
 
 #[cfg(feature = "editor")]
@@ -467,11 +931,11 @@ where
     fn render_prompt(&mut self, prompt: &str, editor_command: &str) -> Result<()> {
         self.print_prompt(prompt)?;
 
-        self.terminal.write(" ")?;
+        self.write(" ")?;
 
         let message = format!("[(e) to open {}, (enter) to submit]", editor_command);
         let token = Styled::new(message).with_style_sheet(self.render_config.editor_prompt);
-        self.terminal.write_styled(&token)?;
+        self.write_styled(&token)?;
 
         self.new_line()?;
 
@@ -487,24 +951,72 @@ where
         self.print_prompt_with_input(prompt, None, cur_input)
     }
 
-    fn render_options<D: Display>(&mut self, page: Page<ListOption<D>>) -> Result<()> {
+    fn render_options<D: Display>(
+        &mut self,
+        page: Page<ListOption<D>>,
+        headers: &BTreeSet<usize>,
+        disabled: &BTreeSet<usize>,
+        numbered: bool,
+        page_indicator: bool,
+    ) -> Result<()> {
+        // numbered options are prefixed with their index unless the render
+        // config defines how
+        let index_prefix = match self.render_config.option_index_prefix {
+            IndexPrefix::None if numbered => IndexPrefix::Simple,
+            index_prefix => index_prefix,
+        };
+
+        let total = page.total;
+        let footer_rows = 1 + usize::from(page_indicator);
+        let page = self.fit_page(page, footer_rows, |option| {
+            let value_width = option.value.to_string().width();
+
+            match Self::option_index_prefix(index_prefix, option.index, total) {
+                Some(prefix) if !headers.contains(&option.index) => {
+                    prefix.width() + 1 + value_width
+                }
+                _ => value_width,
+            }
+        });
+
         for (idx, option) in page.content.iter().enumerate() {
+            self.mark_option_start(option.index);
+
             self.print_option_prefix(idx, &page)?;
 
-            self.terminal.write(" ")?;
+            self.write(" ")?;
+
+            if headers.contains(&option.index) {
+                let header =
+                    Styled::new(&option.value).with_style_sheet(self.render_config.option_header);
+                self.write_styled(&header)?;
+                self.new_line()?;
+                continue;
+            }
 
-            if let Some(res) = self.print_option_index_prefix(option.index, page.total) {
+            if let Some(res) =
+                self.print_option_index_prefix(index_prefix, option.index, page.total)
+            {
                 res?;
-                self.terminal.write(" ")?;
+                self.write(" ")?;
             }
 
-            self.print_option_value(option)?;
+            let style_sheet = self.option_style_sheet(option.index, disabled);
+            self.print_option_value_with_style(option, style_sheet)?;
 
             self.new_line()?;
         }
 
+        if page_indicator {
+            self.print_page_indicator(&page)?;
+        }
+
         Ok(())
     }
+
+    fn option_at_row(&mut self, row: u16) -> Option<usize> {
+        self.rendered_option_at_row(row)
+    }
 }
 
 impl<T> MultiSelectBackend for Backend<T>
@@ -519,35 +1031,84 @@ where
         &mut self,
         page: Page<ListOption<D>>,
         checked: &BTreeSet<usize>,
+        disabled: &BTreeSet<usize>,
+        page_indicator: bool,
     ) -> Result<()> {
+        let checkbox_width = self
+            .render_config
+            .selected_checkbox
+            .content
+            .width()
+            .max(self.render_config.unselected_checkbox.content.width());
+        let index_prefix = self.render_config.option_index_prefix;
+        let total = page.total;
+        let footer_rows = 1 + usize::from(page_indicator);
+        let page = self.fit_page(page, footer_rows, |option| {
+            let index_prefix_width = Self::option_index_prefix(index_prefix, option.index, total)
+                .map_or(0, |prefix| prefix.width() + 1);
+
+            index_prefix_width + checkbox_width + 1 + option.value.to_string().width()
+        });
+
         for (idx, option) in page.content.iter().enumerate() {
+            self.mark_option_start(option.index);
+
             self.print_option_prefix(idx, &page)?;
 
-            self.terminal.write(" ")?;
+            self.write(" ")?;
 
-            if let Some(res) = self.print_option_index_prefix(option.index, page.total) {
+            if let Some(res) =
+                self.print_option_index_prefix(index_prefix, option.index, page.total)
+            {
                 res?;
-                self.terminal.write(" ")?;
+                self.write(" ")?;
             }
 
-            match checked.contains(&option.index) {
-                true => self
-                    .terminal
-                    .write_styled(&self.render_config.selected_checkbox)?,
-                false => self
-                    .terminal
-                    .write_styled(&self.render_config.unselected_checkbox)?,
-            }
+            let checkbox = match checked.contains(&option.index) {
+                true => self.render_config.selected_checkbox,
+                false => self.render_config.unselected_checkbox,
+            };
+            self.write_styled(&checkbox)?;
 
-            self.terminal.write(" ")?;
+            self.write(" ")?;
 
-            self.print_option_value(option)?;
+            let style_sheet = self.option_style_sheet(option.index, disabled);
+            self.print_option_value_with_style(option, style_sheet)?;
 
             self.new_line()?;
         }
 
+        if page_indicator {
+            self.print_page_indicator(&page)?;
+        }
+
         Ok(())
     }
+
+    fn render_selection_count(
+        &mut self,
+        selected: usize,
+        min: Option<usize>,
+        max: Option<usize>,
+    ) -> Result<()> {
+        let mut count = format!("selected {}", selected);
+        if let Some(min) = min {
+            count.push_str(&format!("/min {}", min));
+        }
+        if let Some(max) = max {
+            count.push_str(&format!("/max {}", max));
+        }
+
+        self.write_styled(&Styled::new(count).with_style_sheet(self.render_config.help_message))?;
+
+        self.new_line()?;
+
+        Ok(())
+    }
+
+    fn option_at_row(&mut self, row: u16) -> Option<usize> {
+        self.rendered_option_at_row(row)
+    }
 }
 
 #[cfg(feature = "date")]
@@ -598,9 +1159,9 @@ pub mod date {
         ) -> Result<()> {
             macro_rules! write_prefix {
                 () => {{
-                    self.terminal
-                        .write_styled(&self.render_config.calendar.prefix)?;
-                    self.terminal.write(" ")
+                    let prefix = self.render_config.calendar.prefix;
+                    self.write_styled(&prefix)?;
+                    self.write(" ")
                 }};
             }
 
@@ -611,7 +1172,7 @@ pub mod date {
 
             write_prefix!()?;
 
-            self.terminal.write_styled(&header)?;
+            self.write_styled(&header)?;
 
             self.new_line()?;
 
@@ -632,7 +1193,7 @@ pub mod date {
 
             write_prefix!()?;
 
-            self.terminal.write_styled(&week_days)?;
+            self.write_styled(&week_days)?;
             self.new_line()?;
 
             // print dates
@@ -651,7 +1212,7 @@ pub mod date {
 
                 for i in 0..7 {
                     if i > 0 {
-                        self.terminal.write(" ")?;
+                        self.write(" ")?;
                     }
 
                     let date = format!("{:2}", date_it.day());
@@ -687,7 +1248,7 @@ pub mod date {
                     }
 
                     let token = Styled::new(date).with_style_sheet(style_sheet);
-                    self.terminal.write_styled(&token)?;
+                    self.write_styled(&token)?;
 
                     date_it = date_it.succ();
                 }
@@ -737,6 +1298,21 @@ where
     fn render_prompt_with_full_input(&mut self, prompt: &str, cur_input: &Input) -> Result<()> {
         self.print_prompt_with_input(prompt, None, cur_input)
     }
+
+    fn render_strength_meter(&mut self, strength: PasswordStrength) -> Result<()> {
+        let bar = match strength {
+            PasswordStrength::Weak => "[#--]",
+            PasswordStrength::Medium => "[##-]",
+            PasswordStrength::Strong => "[###]",
+        };
+        let meter = format!("Strength: {} {}", bar, strength);
+
+        self.write_styled(&Styled::new(meter).with_style_sheet(self.render_config.help_message))?;
+
+        self.new_line()?;
+
+        Ok(())
+    }
 }
 
 impl<T> Drop for Backend<T>
@@ -748,3 +1324,453 @@ where
         let _ = self.terminal.cursor_show();
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::{cell::Cell, collections::VecDeque, fmt::Display, io::Result, rc::Rc};
+
+    use unicode_width::UnicodeWidthStr;
+
+    use super::{Backend, CommonBackend, SelectBackend, TextBackend};
+    use crate::{
+        input::Input,
+        list_option::ListOption,
+        terminal::{Terminal, TerminalSize},
+        ui::{Key, RenderConfig, Styled, TextDirection},
+        utils::paginate,
+        MultiSelect, Select,
+    };
+
+    /// Terminal whose reported width can be changed between frames, counting
+    /// the lines cleared by the backend and tracking its cursor movements.
+    /// Keys are read from `keys`, and are submissions once it is empty.
+    struct ResizableTerminal {
+        width: Rc<Cell<u16>>,
+        height: u16,
+        keys: VecDeque<Key>,
+        cleared_lines: Rc<Cell<usize>>,
+        rows_up: u16,
+        column: u16,
+        screen_row: u16,
+        in_memory_content: String,
+    }
+
+    impl ResizableTerminal {
+        fn new(width: Rc<Cell<u16>>, cleared_lines: Rc<Cell<usize>>) -> Self {
+            Self {
+                width,
+                height: 1000,
+                keys: VecDeque::new(),
+                cleared_lines,
+                rows_up: 0,
+                column: 0,
+                screen_row: 0,
+                in_memory_content: String::new(),
+            }
+        }
+    }
+
+    impl Terminal for ResizableTerminal {
+        fn cursor_up(&mut self, cnt: u16) -> Result<()> {
+            self.rows_up = cnt;
+            Ok(())
+        }
+
+        fn cursor_down(&mut self, _: u16) -> Result<()> {
+            Ok(())
+        }
+
+        fn cursor_move_to_column(&mut self, idx: u16) -> Result<()> {
+            self.column = idx;
+            Ok(())
+        }
+
+        fn read_key(&mut self) -> Result<Key> {
+            Ok(self.keys.pop_front().unwrap_or(Key::Submit))
+        }
+
+        fn flush(&mut self) -> Result<()> {
+            Ok(())
+        }
+
+        fn get_size(&self) -> Result<TerminalSize> {
+            Ok(TerminalSize {
+                width: self.width.get(),
+                height: self.height,
+            })
+        }
+
+        fn cursor_position(&mut self) -> Result<(u16, u16)> {
+            Ok((self.column, self.screen_row))
+        }
+
+        fn get_in_memory_content(&self) -> &str {
+            &self.in_memory_content
+        }
+
+        fn clear_in_memory_content(&mut self) {
+            self.in_memory_content.clear()
+        }
+
+        fn write<T: Display>(&mut self, val: T) -> Result<()> {
+            let formatted = format!("{}", val);
+            let converted = newline_converter::unix2dos(&formatted);
+
+            self.in_memory_content.push_str(converted.as_ref());
+            Ok(())
+        }
+
+        fn write_styled<T: Display>(&mut self, val: &Styled<T>) -> Result<()> {
+            self.write(&val.content)
+        }
+
+        fn clear_current_line(&mut self) -> Result<()> {
+            self.cleared_lines.set(self.cleared_lines.get() + 1);
+            Ok(())
+        }
+
+        fn clear_screen(&mut self) -> Result<()> {
+            Ok(())
+        }
+
+        fn enable_mouse(&mut self) -> Result<()> {
+            Ok(())
+        }
+
+        fn disable_mouse(&mut self) -> Result<()> {
+            Ok(())
+        }
+
+        fn enter_alternate_screen(&mut self) -> Result<()> {
+            Ok(())
+        }
+
+        fn leave_alternate_screen(&mut self) -> Result<()> {
+            Ok(())
+        }
+
+        fn cursor_hide(&mut self) -> Result<()> {
+            Ok(())
+        }
+
+        fn cursor_show(&mut self) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn narrowing_the_terminal_clears_all_rewrapped_lines() {
+        let width = Rc::new(Cell::new(100));
+        let cleared_lines = Rc::new(Cell::new(0));
+
+        let terminal = ResizableTerminal::new(width.clone(), cleared_lines.clone());
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        // "? " + 27 characters + the input and cursor spaces, 31 columns in total
+        let message = "a".repeat(27);
+        let input = Input::new();
+
+        backend.frame_setup().unwrap();
+        backend.render_prompt(&message, None, &input).unwrap();
+        backend.frame_finish().unwrap();
+
+        backend.frame_setup().unwrap();
+        assert_eq!(1, cleared_lines.get());
+        backend.render_prompt(&message, None, &input).unwrap();
+        backend.frame_finish().unwrap();
+
+        cleared_lines.set(0);
+        width.set(10);
+
+        backend.frame_setup().unwrap();
+        assert_eq!(4, cleared_lines.get());
+    }
+
+    #[test]
+    fn long_prompt_and_input_wrap_on_narrow_terminals() {
+        let width = Rc::new(Cell::new(10));
+        let cleared_lines = Rc::new(Cell::new(0));
+
+        let terminal = ResizableTerminal::new(width, cleared_lines.clone());
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        // "? " + 16 characters + a space take the first 19 columns, so the
+        // input starts at the last column of the second row and the cursor,
+        // placed before its sixth character, lands on the fifth column of
+        // the third row.
+        let message = "abcdefghijklmnop";
+        let input = Input::new_with("0123456789").with_cursor(5);
+
+        backend.frame_setup().unwrap();
+        backend.render_prompt(message, None, &input).unwrap();
+        backend.frame_finish().unwrap();
+
+        assert_eq!(1, backend.terminal.rows_up);
+        assert_eq!(4, backend.terminal.column);
+
+        backend.frame_setup().unwrap();
+        assert_eq!(3, cleared_lines.get());
+    }
+
+    #[test]
+    fn cursor_is_placed_after_new_lines_of_the_input() {
+        let width = Rc::new(Cell::new(100));
+        let cleared_lines = Rc::new(Cell::new(0));
+
+        let terminal = ResizableTerminal::new(width, cleared_lines);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        // cursor placed right before the "d", in the second line
+        let input = Input::new_with("ab\ncd").with_cursor(4);
+
+        backend.frame_setup().unwrap();
+        backend.render_prompt("Q", None, &input).unwrap();
+        backend.frame_finish().unwrap();
+
+        assert_eq!(1, backend.terminal.rows_up);
+        assert_eq!(1, backend.terminal.column);
+    }
+
+    #[test]
+    fn cursor_column_accounts_for_double_width_characters() {
+        let width = Rc::new(Cell::new(100));
+        let cleared_lines = Rc::new(Cell::new(0));
+
+        let terminal = ResizableTerminal::new(width, cleared_lines);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        // "? Q " takes 4 columns and each of the two glyphs before the cursor 2
+        let input = Input::new_with("日本語").with_cursor(2);
+
+        backend.frame_setup().unwrap();
+        backend.render_prompt("Q", None, &input).unwrap();
+        backend.frame_finish().unwrap();
+
+        assert_eq!(1, backend.terminal.rows_up);
+        assert_eq!(8, backend.terminal.column);
+    }
+
+    #[test]
+    fn double_width_characters_wrap_when_not_fitting_the_line() {
+        let width = Rc::new(Cell::new(10));
+        let cleared_lines = Rc::new(Cell::new(0));
+
+        let terminal = ResizableTerminal::new(width, cleared_lines);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        // "? abcdef " leaves a single column in the first row, so the first
+        // glyph is wrapped whole to the second row and the cursor, placed
+        // after it, lands on its third column.
+        let input = Input::new_with("日本").with_cursor(1);
+
+        backend.frame_setup().unwrap();
+        backend.render_prompt("abcdef", None, &input).unwrap();
+        backend.frame_finish().unwrap();
+
+        assert_eq!(1, backend.terminal.rows_up);
+        assert_eq!(2, backend.terminal.column);
+    }
+
+    fn render_rtl_prompt(input: &Input) -> Backend<ResizableTerminal> {
+        let width = Rc::new(Cell::new(40));
+        let cleared_lines = Rc::new(Cell::new(0));
+
+        let terminal = ResizableTerminal::new(width, cleared_lines);
+        let render_config = RenderConfig::empty().with_direction(TextDirection::Rtl);
+        let mut backend = Backend::new(terminal, render_config).unwrap();
+
+        backend.frame_setup().unwrap();
+        backend.render_prompt("Q", None, input).unwrap();
+        backend.frame_finish().unwrap();
+
+        backend
+    }
+
+    #[test]
+    fn rtl_cursor_starts_at_the_right_edge() {
+        let backend = render_rtl_prompt(&Input::new());
+
+        assert_eq!(1, backend.terminal.rows_up);
+        assert_eq!(39, backend.terminal.column);
+    }
+
+    #[test]
+    fn rtl_input_ends_at_the_right_edge() {
+        let backend = render_rtl_prompt(&Input::new_with("שלום"));
+
+        let line = backend.terminal.in_memory_content.lines().next().unwrap();
+        assert_eq!(40, line.width());
+        assert!(line.ends_with(" שלום "));
+        assert_eq!(39, backend.terminal.column);
+    }
+
+    #[test]
+    fn ltr_cursor_follows_the_prompt() {
+        let width = Rc::new(Cell::new(40));
+        let cleared_lines = Rc::new(Cell::new(0));
+
+        let terminal = ResizableTerminal::new(width, cleared_lines);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        backend.frame_setup().unwrap();
+        backend.render_prompt("Q", None, &Input::new()).unwrap();
+        backend.frame_finish().unwrap();
+
+        assert_eq!(4, backend.terminal.column);
+    }
+
+    #[test]
+    fn clicked_rows_are_mapped_to_the_options_rendered_there() {
+        let width = Rc::new(Cell::new(100));
+        let cleared_lines = Rc::new(Cell::new(0));
+
+        let terminal = ResizableTerminal::new(width, cleared_lines);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let options: Vec<ListOption<&str>> = vec!["a", "b", "c", "d"]
+            .into_iter()
+            .enumerate()
+            .map(|(i, value)| ListOption::new(i, value))
+            .collect();
+        let page = paginate(4, &options, 2);
+
+        backend.frame_setup().unwrap();
+        backend.render_select_prompt("Q", &Input::new()).unwrap();
+        backend
+            .render_options(page, &Default::default(), &Default::default(), false, false)
+            .unwrap();
+        backend.frame_finish().unwrap();
+
+        // the cursor is left on the prompt line, which is the tenth row of the
+        // screen, so the options are on the next ones
+        backend.terminal.screen_row = 9;
+
+        assert_eq!(None, backend.option_at_row(9));
+        assert_eq!(Some(0), backend.option_at_row(10));
+        assert_eq!(Some(2), backend.option_at_row(12));
+        assert_eq!(Some(3), backend.option_at_row(13));
+        assert_eq!(None, backend.option_at_row(14));
+        assert_eq!(None, backend.option_at_row(3));
+    }
+
+    #[test]
+    fn highlighted_option_stays_on_screen_after_narrowing_the_terminal() {
+        let width = Rc::new(Cell::new(100));
+        let cleared_lines = Rc::new(Cell::new(0));
+
+        let mut terminal = ResizableTerminal::new(width.clone(), cleared_lines);
+        terminal.height = 8;
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        // prefix, space and 18 characters, 20 columns in total
+        let options: Vec<ListOption<String>> = (0..5)
+            .map(|i| ListOption::new(i, format!("{}{}", i, "x".repeat(17))))
+            .collect();
+
+        let render_frame = |backend: &mut Backend<ResizableTerminal>| {
+            backend.frame_setup().unwrap();
+            backend.render_select_prompt("Q", &Input::new()).unwrap();
+            backend
+                .render_options(
+                    paginate(5, &options, 4),
+                    &Default::default(),
+                    &Default::default(),
+                    false,
+                    false,
+                )
+                .unwrap();
+            backend.frame_finish().unwrap();
+        };
+
+        render_frame(&mut backend);
+        for i in 0..5 {
+            assert!(backend
+                .terminal
+                .in_memory_content
+                .contains(&format!("{}x", i)));
+        }
+
+        // each option now takes two rows, of the six left below the prompt
+        // when keeping one for the help message
+        width.set(10);
+        render_frame(&mut backend);
+
+        let content = &backend.terminal.in_memory_content;
+        assert!(content.contains(&format!("> 4{}", "x".repeat(17))));
+        assert!(content.contains("3x"));
+        assert!(content.contains("2x"));
+        assert!(!content.contains("1x"));
+        assert!(!content.contains("0x"));
+    }
+
+    #[test]
+    fn page_indicator_reports_the_options_fitted_on_a_short_terminal() {
+        let mut terminal = ResizableTerminal::new(Rc::new(Cell::new(100)), Rc::new(Cell::new(0)));
+        terminal.height = 8;
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let options: Vec<ListOption<String>> = (0..20)
+            .map(|i| ListOption::new(i, format!("option {}", i)))
+            .collect();
+
+        backend.frame_setup().unwrap();
+        backend.render_select_prompt("Q", &Input::new()).unwrap();
+        backend
+            .render_options(
+                paginate(7, &options, 0),
+                &Default::default(),
+                &Default::default(),
+                false,
+                true,
+            )
+            .unwrap();
+        backend.frame_finish().unwrap();
+
+        // five rows are left below the prompt, keeping one for the indicator
+        // and one for the help message
+        let content = &backend.terminal.in_memory_content;
+        assert!(content.contains("option 4"));
+        assert!(!content.contains("option 5"));
+        assert!(content.contains("showing 1-5 of 20"));
+    }
+
+    /// Backend whose frames are rendered with the prompt line on the tenth
+    /// row of the screen, reading the given keys.
+    fn backend_reading(keys: Vec<Key>) -> Backend<ResizableTerminal> {
+        let mut terminal = ResizableTerminal::new(Rc::new(Cell::new(100)), Rc::new(Cell::new(0)));
+        terminal.keys = keys.into_iter().collect();
+        terminal.screen_row = 9;
+
+        Backend::new(terminal, RenderConfig::empty()).unwrap()
+    }
+
+    #[test]
+    fn clicking_the_highlighted_option_of_select_prompts_submits_it() {
+        let click = Key::Click { row: 12, column: 4 };
+        let mut backend = backend_reading(vec![click, click]);
+
+        let ans = Select::new("Question", vec!["a", "b", "c", "d"])
+            .with_mouse(true)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(ListOption::new(2, "c"), ans);
+    }
+
+    #[test]
+    fn clicking_options_of_multiselect_prompts_toggles_them() {
+        let mut backend = backend_reading(vec![
+            Key::Click { row: 10, column: 4 },
+            Key::Click { row: 13, column: 4 },
+            Key::Click { row: 12, column: 4 },
+            Key::Click { row: 13, column: 4 },
+        ]);
+
+        let ans = MultiSelect::new("Question", vec!["a", "b", "c", "d"])
+            .with_mouse(true)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(vec![ListOption::new(0, "a"), ListOption::new(2, "c")], ans);
+    }
+}
@@ -1,33 +1,140 @@
+use std::fmt;
+
 use bitflags::bitflags;
 
 // Using the same struct, but without importing, to cut prompts' direct dependencies to crossterm
 // https://github.com/crossterm-rs/crossterm/blob/e1260446e94e9a8f7809fef61dc1369b6f8d6e12/src/event.rs#L376-L385
 bitflags! {
+    /// Modifier keys pressed along with a [Key].
     pub struct KeyModifiers: u8 {
+        /// Shift key.
         const SHIFT = 0b0000_0001;
+        /// Control key.
         const CONTROL = 0b0000_0010;
+        /// Alt key.
         const ALT = 0b0000_0100;
+        /// No modifiers.
         const NONE = 0b0000_0000;
     }
 }
 
+/// Key pressed by the user, independent of the terminal back-end in use.
+///
+/// Keys may be added in future releases, so matches on them must include a
+/// wildcard arm.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum Key {
+    /// Cancel key, `Esc` by default.
     Cancel,
+    /// Interrupt key, `Ctrl+C` by default.
     Interrupt,
+    /// Submit key, `Enter` by default.
     Submit,
+    /// Backspace key.
     Backspace,
+    /// Tab key.
     Tab,
+    /// Delete key.
     Delete(KeyModifiers),
+    /// Home key.
     Home,
+    /// End key.
     End,
+    /// Page up key.
     PageUp,
+    /// Page down key.
     PageDown,
+    /// Up arrow key.
     Up(KeyModifiers),
+    /// Down arrow key.
     Down(KeyModifiers),
+    /// Left arrow key.
     Left(KeyModifiers),
+    /// Right arrow key.
     Right(KeyModifiers),
+    /// Character key.
     Char(char, KeyModifiers),
-    #[deprecated(note = "Please implement the proper matcher for your key on key.rs")]
+    /// Function key, e.g. `F(1)` for F1.
+    F(u8),
+    /// Left mouse button pressed at the given position of the screen, only
+    /// reported by prompts with mouse support enabled.
+    Click {
+        /// Row of the screen, starting at 0.
+        row: u16,
+        /// Column of the screen, starting at 0.
+        column: u16,
+    },
+    /// Any other key, not mapped to a variant of its own yet.
+    #[doc(hidden)]
+    #[deprecated(
+        note = "keys read as `Any` may get variants of their own, match them with a wildcard arm instead"
+    )]
     Any,
 }
+
+impl fmt::Display for Key {
+    /// Formats the key as displayed in help messages, e.g. `ctrl+w`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let modifiers = match self {
+            Self::Delete(m)
+            | Self::Up(m)
+            | Self::Down(m)
+            | Self::Left(m)
+            | Self::Right(m)
+            | Self::Char(_, m) => *m,
+            _ => KeyModifiers::NONE,
+        };
+
+        for (modifier, name) in [
+            (KeyModifiers::CONTROL, "ctrl+"),
+            (KeyModifiers::ALT, "alt+"),
+            (KeyModifiers::SHIFT, "shift+"),
+        ] {
+            if modifiers.contains(modifier) {
+                f.write_str(name)?;
+            }
+        }
+
+        match self {
+            Self::Cancel => f.write_str("esc"),
+            Self::Interrupt => f.write_str("ctrl+c"),
+            Self::Submit => f.write_str("enter"),
+            Self::Backspace => f.write_str("backspace"),
+            Self::Tab => f.write_str("tab"),
+            Self::Delete(_) => f.write_str("delete"),
+            Self::Home => f.write_str("home"),
+            Self::End => f.write_str("end"),
+            Self::PageUp => f.write_str("page up"),
+            Self::PageDown => f.write_str("page down"),
+            Self::Up(_) => f.write_str("up"),
+            Self::Down(_) => f.write_str("down"),
+            Self::Left(_) => f.write_str("left"),
+            Self::Right(_) => f.write_str("right"),
+            Self::Char(' ', _) => f.write_str("space"),
+            Self::Char(c, _) => write!(f, "{}", c),
+            Self::F(n) => write!(f, "F{}", n),
+            Self::Click { .. } => f.write_str("click"),
+            #[allow(deprecated)]
+            Self::Any => f.write_str("any"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Key, KeyModifiers};
+
+    #[test]
+    fn keys_are_displayed_with_their_modifiers() {
+        assert_eq!("enter", Key::Submit.to_string());
+        assert_eq!("up", Key::Up(KeyModifiers::NONE).to_string());
+        assert_eq!("ctrl+w", Key::Char('w', KeyModifiers::CONTROL).to_string());
+        assert_eq!(
+            "ctrl+shift+left",
+            Key::Left(KeyModifiers::CONTROL | KeyModifiers::SHIFT).to_string()
+        );
+        assert_eq!("space", Key::Char(' ', KeyModifiers::NONE).to_string());
+        assert_eq!("F1", Key::F(1).to_string());
+    }
+}
@@ -0,0 +1,185 @@
+use super::{Key, KeyModifiers};
+
+/// Logical actions that can be bound to keys in a [Keymap].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Action {
+    /// Moves the highlighted suggestion up.
+    MoveUp,
+    /// Moves the highlighted suggestion down.
+    MoveDown,
+    /// Triggers an autocompletion of the text input.
+    Complete,
+    /// Submits the current answer.
+    Submit,
+    /// Cancels the prompt.
+    Cancel,
+    /// Deletes the word to the left of the cursor.
+    DeleteWord,
+    /// Shows or hides the panel listing the key bindings of the prompt.
+    ToggleHelp,
+    /// Starts an incremental search over the history, or moves to the next
+    /// older match when already searching.
+    SearchHistory,
+}
+
+impl Action {
+    const ALL: [Action; 8] = [
+        Action::MoveUp,
+        Action::MoveDown,
+        Action::Complete,
+        Action::Submit,
+        Action::Cancel,
+        Action::DeleteWord,
+        Action::ToggleHelp,
+        Action::SearchHistory,
+    ];
+
+    fn description(self) -> &'static str {
+        match self {
+            Action::MoveUp => "move the highlighted suggestion up",
+            Action::MoveDown => "move the highlighted suggestion down",
+            Action::Complete => "autocomplete",
+            Action::Submit => "submit",
+            Action::Cancel => "cancel",
+            Action::DeleteWord => "delete the previous word",
+            Action::ToggleHelp => "toggle this help",
+            Action::SearchHistory => "search the history",
+        }
+    }
+}
+
+/// Key bindings of the logical actions of a prompt.
+///
+/// Keys not bound to any action keep their default behavior, e.g. inserting
+/// characters into the text input. When a key is bound more than once, the
+/// latest binding takes precedence.
+///
+/// # Example
+///
+/// ```
+/// use inquire::ui::{Action, Key, KeyModifiers, Keymap};
+///
+/// let keymap = Keymap::default()
+///     .with_binding(Key::Char('k', KeyModifiers::ALT), Action::MoveUp)
+///     .with_binding(Key::Char('j', KeyModifiers::ALT), Action::MoveDown);
+///
+/// assert_eq!(Some(Action::MoveUp), keymap.action(Key::Up(KeyModifiers::NONE)));
+/// assert_eq!(Some(Action::MoveUp), keymap.action(Key::Char('k', KeyModifiers::ALT)));
+/// assert_eq!(None, keymap.action(Key::Char('k', KeyModifiers::NONE)));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Keymap {
+    bindings: Vec<(Key, Action)>,
+}
+
+impl Keymap {
+    /// Keymap without any bindings.
+    pub fn empty() -> Self {
+        Self { bindings: vec![] }
+    }
+
+    /// Keymap with vim-style bindings, added on top of the default ones.
+    ///
+    /// As in vim's insert mode, `Ctrl+P` and `Ctrl+N` move the highlighted
+    /// suggestion up and down, and `Ctrl+Y` accepts it.
+    pub fn vim() -> Self {
+        Self::default()
+            .with_binding(Key::Char('p', KeyModifiers::CONTROL), Action::MoveUp)
+            .with_binding(Key::Char('n', KeyModifiers::CONTROL), Action::MoveDown)
+            .with_binding(Key::Char('y', KeyModifiers::CONTROL), Action::Complete)
+    }
+
+    /// Binds the key to the action.
+    pub fn with_binding(mut self, key: Key, action: Action) -> Self {
+        self.bindings.push((key, action));
+        self
+    }
+
+    /// Action bound to the key, if any.
+    pub fn action(&self, key: Key) -> Option<Action> {
+        self.bindings
+            .iter()
+            .rev()
+            .find(|(k, _)| *k == key)
+            .map(|(_, action)| *action)
+    }
+
+    /// Keys currently bound to the action, in the order they were bound.
+    pub fn keys(&self, action: Action) -> Vec<Key> {
+        let mut keys: Vec<Key> = vec![];
+
+        for (key, _) in self.bindings.iter().filter(|(_, a)| *a == action) {
+            if !keys.contains(key) && self.action(*key) == Some(action) {
+                keys.push(*key);
+            }
+        }
+
+        keys
+    }
+
+    /// Lines describing each bound action, as displayed in the help panel of
+    /// a prompt, e.g. `tab: autocomplete`.
+    pub(crate) fn help_lines(&self) -> Vec<String> {
+        Action::ALL
+            .iter()
+            .filter_map(|action| {
+                let keys = self.keys(*action);
+                if keys.is_empty() {
+                    return None;
+                }
+
+                let keys: Vec<String> = keys.iter().map(Key::to_string).collect();
+                Some(format!("{}: {}", keys.join(", "), action.description()))
+            })
+            .collect()
+    }
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        Self::empty()
+            .with_binding(Key::Up(KeyModifiers::NONE), Action::MoveUp)
+            .with_binding(Key::Down(KeyModifiers::NONE), Action::MoveDown)
+            .with_binding(Key::Tab, Action::Complete)
+            .with_binding(Key::Submit, Action::Submit)
+            .with_binding(Key::Cancel, Action::Cancel)
+            .with_binding(Key::Char('w', KeyModifiers::CONTROL), Action::DeleteWord)
+            .with_binding(Key::F(1), Action::ToggleHelp)
+            .with_binding(Key::Char('r', KeyModifiers::CONTROL), Action::SearchHistory)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Action, Key, KeyModifiers, Keymap};
+
+    #[test]
+    fn overridden_bindings_are_not_listed() {
+        let keymap = Keymap::default().with_binding(Key::Tab, Action::MoveDown);
+
+        assert_eq!(
+            vec![Key::Down(KeyModifiers::NONE), Key::Tab],
+            keymap.keys(Action::MoveDown)
+        );
+        assert!(keymap.keys(Action::Complete).is_empty());
+    }
+
+    #[test]
+    fn help_lines_list_every_bound_action() {
+        let keymap = Keymap::vim();
+
+        assert_eq!(
+            vec![
+                "up, ctrl+p: move the highlighted suggestion up",
+                "down, ctrl+n: move the highlighted suggestion down",
+                "tab, ctrl+y: autocomplete",
+                "enter: submit",
+                "esc: cancel",
+                "ctrl+w: delete the previous word",
+                "F1: toggle this help",
+                "ctrl+r: search the history",
+            ],
+            keymap.help_lines()
+        );
+    }
+}
@@ -3,12 +3,16 @@
 mod backend;
 mod color;
 mod key;
+mod keymap;
 mod render_config;
 mod style;
 
 pub(crate) use backend::*;
-pub(crate) use key::{Key, KeyModifiers};
 
 pub use color::Color;
+pub use key::{Key, KeyModifiers};
+pub use keymap::{Action, Keymap};
 pub use render_config::*;
 pub use style::{Attributes, StyleSheet, Styled};
+
+pub use crate::terminal::{get_terminal_size, DEFAULT_TERMINAL_SIZE};
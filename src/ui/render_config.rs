@@ -1,6 +1,6 @@
 use std::env;
 
-use super::{Color, StyleSheet, Styled};
+use super::{Attributes, Color, StyleSheet, Styled};
 
 /// Rendering configuration that can be applied to a prompt.
 ///
@@ -55,6 +55,12 @@ pub struct RenderConfig {
     /// Note: help messages are displayed wrapped in brackets, e.g. [Be careful!].
     pub help_message: StyleSheet,
 
+    /// Line rendered between the rest of the prompt, e.g. its options, and
+    /// the help message. No line is rendered when `None`.
+    ///
+    /// Set it to an empty string to add a blank line before help messages.
+    pub help_message_separator: Option<Styled<&'static str>>,
+
     /// Character used to mask password text inputs when in mode
     /// [`Masked`](crate::prompts::PasswordDisplayMode).
     ///
@@ -74,6 +80,18 @@ pub struct RenderConfig {
     /// a separator from the prompt message (or default value display).
     pub answer: StyleSheet,
 
+    /// Layout of final prompt answers (submissions), defining whether they
+    /// are rendered on the same line as the prompt message or below it.
+    pub answer_layout: AnswerLayout,
+
+    /// Number of spaces added at the start of every rendered line, useful to
+    /// nest prompts inside a framed interface.
+    pub indent: usize,
+
+    /// Direction of the text typed by the user. In right-to-left mode, text
+    /// inputs are anchored to the right edge of the terminal.
+    pub direction: TextDirection,
+
     /// Render configuration of the message printed in the place of an answer
     /// when the prompt is canceled by the user - by pressing ESC.
     ///
@@ -84,6 +102,10 @@ pub struct RenderConfig {
     /// Render configuration for error messages.
     pub error_message: ErrorMessageRenderConfig,
 
+    /// Render configuration for warning messages, returned by validators
+    /// as [`Validation::Warning`](crate::validator::Validation::Warning).
+    pub warning_message: ErrorMessageRenderConfig,
+
     /// Prefix for the current highlighted option.
     ///
     /// Note: a space character will be added to separate the prefix
@@ -127,6 +149,26 @@ pub struct RenderConfig {
     /// a separator from the prefix.
     pub option: StyleSheet,
 
+    /// Style sheet for section headers in option lists, which can not be
+    /// selected.
+    ///
+    /// Note: a non-styled space character is added before the header as
+    /// a separator from the prefix.
+    pub option_header: StyleSheet,
+
+    /// Style sheet for disabled options, which are displayed but can not be
+    /// selected.
+    ///
+    /// Note: a non-styled space character is added before the option value as
+    /// a separator from the prefix.
+    pub disabled_option: StyleSheet,
+
+    /// Style sheet for the descriptions of autocompletion suggestions.
+    ///
+    /// Note: a non-styled space character is added before the description as
+    /// a separator from the suggestion value.
+    pub suggestion_description: StyleSheet,
+
     /// Render configuration for calendar
 
     #[cfg(feature = "date")]
@@ -151,9 +193,14 @@ impl RenderConfig {
             default_value: StyleSheet::empty(),
             placeholder: StyleSheet::empty(),
             help_message: StyleSheet::empty(),
+            help_message_separator: None,
             text_input: StyleSheet::empty(),
             error_message: ErrorMessageRenderConfig::empty(),
+            warning_message: ErrorMessageRenderConfig::empty_warning(),
             answer: StyleSheet::empty(),
+            answer_layout: AnswerLayout::Inline,
+            indent: 0,
+            direction: TextDirection::Ltr,
             canceled_prompt_indicator: Styled::new("<canceled>"),
             password_mask: '*',
             highlighted_option_prefix: Styled::new(">"),
@@ -163,6 +210,9 @@ impl RenderConfig {
             unselected_checkbox: Styled::new("[ ]"),
             option_index_prefix: IndexPrefix::None,
             option: StyleSheet::empty(),
+            option_header: StyleSheet::empty(),
+            disabled_option: StyleSheet::empty(),
+            suggestion_description: StyleSheet::empty(),
 
             #[cfg(feature = "date")]
             calendar: calendar::CalendarRenderConfig::empty(),
@@ -181,10 +231,15 @@ impl RenderConfig {
             default_value: StyleSheet::empty(),
             placeholder: StyleSheet::new().with_fg(Color::DarkGrey),
             help_message: StyleSheet::empty().with_fg(Color::LightCyan),
+            help_message_separator: None,
             text_input: StyleSheet::empty(),
             error_message: ErrorMessageRenderConfig::default_colored(),
+            warning_message: ErrorMessageRenderConfig::default_colored_warning(),
             password_mask: '*',
             answer: StyleSheet::empty().with_fg(Color::LightCyan),
+            answer_layout: AnswerLayout::Inline,
+            indent: 0,
+            direction: TextDirection::Ltr,
             canceled_prompt_indicator: Styled::new("<canceled>").with_fg(Color::DarkRed),
             highlighted_option_prefix: Styled::new(">").with_fg(Color::LightCyan),
             scroll_up_prefix: Styled::new("^"),
@@ -193,6 +248,9 @@ impl RenderConfig {
             unselected_checkbox: Styled::new("[ ]"),
             option_index_prefix: IndexPrefix::None,
             option: StyleSheet::empty(),
+            option_header: StyleSheet::new().with_attr(Attributes::BOLD),
+            disabled_option: StyleSheet::new().with_fg(Color::DarkGrey),
+            suggestion_description: StyleSheet::new().with_fg(Color::DarkGrey),
 
             #[cfg(feature = "date")]
             calendar: calendar::CalendarRenderConfig::default_colored(),
@@ -208,6 +266,15 @@ impl RenderConfig {
         self
     }
 
+    /// Sets the answered prompt prefix and its style sheet.
+    pub fn with_answered_prompt_prefix(
+        mut self,
+        answered_prompt_prefix: Styled<&'static str>,
+    ) -> Self {
+        self.answered_prompt_prefix = answered_prompt_prefix;
+        self
+    }
+
     /// Sets style for text inputs.
     pub fn with_text_input(mut self, text_input: StyleSheet) -> Self {
         self.text_input = text_input;
@@ -226,18 +293,48 @@ impl RenderConfig {
         self
     }
 
+    /// Sets the line rendered before help messages.
+    pub fn with_help_message_separator(mut self, separator: Styled<&'static str>) -> Self {
+        self.help_message_separator = Some(separator);
+        self
+    }
+
     /// Sets the style sheet for answers.
     pub fn with_answer(mut self, answer: StyleSheet) -> Self {
         self.answer = answer;
         self
     }
 
+    /// Sets the layout of final prompt answers.
+    pub fn with_answer_layout(mut self, answer_layout: AnswerLayout) -> Self {
+        self.answer_layout = answer_layout;
+        self
+    }
+
+    /// Sets the number of spaces added at the start of every rendered line.
+    pub fn with_indent(mut self, indent: usize) -> Self {
+        self.indent = indent;
+        self
+    }
+
+    /// Sets the direction of the text typed by the user.
+    pub fn with_direction(mut self, direction: TextDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+
     /// Sets the render configuration for error messages.
     pub fn with_error_message(mut self, error_message: ErrorMessageRenderConfig) -> Self {
         self.error_message = error_message;
         self
     }
 
+    /// Sets the render configuration for warning messages.
+    pub fn with_warning_message(mut self, warning_message: ErrorMessageRenderConfig) -> Self {
+        self.warning_message = warning_message;
+        self
+    }
+
     /// Sets the styled component for prefixes in highlighted options.
     pub fn with_highlighted_option_prefix(
         mut self,
@@ -283,6 +380,24 @@ impl RenderConfig {
         self
     }
 
+    /// Sets the style sheet for section headers in option lists.
+    pub fn with_option_header(mut self, option_header: StyleSheet) -> Self {
+        self.option_header = option_header;
+        self
+    }
+
+    /// Sets the style sheet for disabled options.
+    pub fn with_disabled_option(mut self, disabled_option: StyleSheet) -> Self {
+        self.disabled_option = disabled_option;
+        self
+    }
+
+    /// Sets the style sheet for descriptions of autocompletion suggestions.
+    pub fn with_suggestion_description(mut self, suggestion_description: StyleSheet) -> Self {
+        self.suggestion_description = suggestion_description;
+        self
+    }
+
     /// Sets the indicator for canceled prompts.
     pub fn with_canceled_prompt_indicator(
         mut self,
@@ -352,6 +467,33 @@ pub enum IndexPrefix {
     ZeroPadded,
 }
 
+/// Definition of where the answer of a finished prompt is rendered.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum AnswerLayout {
+    /// The answer is rendered on the same line as the prompt message,
+    /// e.g. `? What's your name? John`.
+    Inline,
+
+    /// The answer is rendered on the line below the prompt message.
+    Below,
+}
+
+/// Direction of the text typed by the user, a rendering hint for languages
+/// such as Arabic or Hebrew.
+///
+/// Only the anchoring of text inputs is affected: the characters themselves
+/// are written in the order they were typed, leaving any bidirectional
+/// reordering to the terminal.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TextDirection {
+    /// Left-to-right text, with inputs rendered right after the prompt message.
+    Ltr,
+
+    /// Right-to-left text, with single-line inputs aligned to the right edge of
+    /// the terminal, where the cursor starts when the input is empty.
+    Rtl,
+}
+
 /// Render configuration for error messages.
 #[derive(Copy, Clone, Debug)]
 pub struct ErrorMessageRenderConfig {
@@ -392,6 +534,28 @@ impl ErrorMessageRenderConfig {
         }
     }
 
+    /// Render configuration for warning messages in which no colors or
+    /// attributes are applied.
+    pub fn empty_warning() -> Self {
+        Self {
+            prefix: Styled::new("!"),
+            separator: StyleSheet::empty(),
+            message: StyleSheet::empty(),
+            default_message: "Please double-check your input.",
+        }
+    }
+
+    /// Render configuration for warning messages where default colors and
+    /// attributes are applied.
+    pub fn default_colored_warning() -> Self {
+        Self {
+            prefix: Styled::new("!").with_fg(Color::LightYellow),
+            separator: StyleSheet::empty(),
+            message: StyleSheet::empty().with_fg(Color::LightYellow),
+            default_message: "Please double-check your input.",
+        }
+    }
+
     /// Sets the prefix.
     pub fn with_prefix(mut self, prefix: Styled<&'static str>) -> Self {
         self.prefix = prefix;
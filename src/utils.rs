@@ -4,13 +4,29 @@ pub struct Page<'a, T> {
     pub first: bool,
     pub last: bool,
     pub content: &'a [T],
+    pub start: usize,
     pub selection: usize,
     pub total: usize,
 }
 
-pub fn paginate<T>(page_size: usize, choices: &[T], sel: usize) -> Page<T> {
-    let (start, end, cursor) = if choices.len() <= page_size {
-        (0, choices.len(), sel)
+pub fn paginate<T>(page_size: usize, choices: &[T], sel: usize) -> Page<'_, T> {
+    let (start, end, cursor) = page_window(page_size, choices.len(), sel);
+
+    Page {
+        first: start == 0,
+        last: end == choices.len(),
+        content: &choices[start..end],
+        start,
+        selection: cursor,
+        total: choices.len(),
+    }
+}
+
+/// Computes the start and end of the page displaying the `sel` index out
+/// of `len` choices, along with the position of `sel` in the page.
+pub fn page_window(page_size: usize, len: usize, sel: usize) -> (usize, usize, usize) {
+    if len <= page_size {
+        (0, len, sel)
     } else if sel < page_size / 2 {
         // if we are in the first half page
         let start = 0;
@@ -18,10 +34,10 @@ pub fn paginate<T>(page_size: usize, choices: &[T], sel: usize) -> Page<T> {
         let cursor = sel;
 
         (start, end, cursor)
-    } else if choices.len() - sel - 1 < page_size / 2 {
+    } else if len - sel - 1 < page_size / 2 {
         // if we are in the last half page
-        let start = choices.len() - page_size;
-        let end = choices.len();
+        let start = len - page_size;
+        let end = len;
         let cursor = sel - start;
 
         (start, end, cursor)
@@ -35,15 +51,38 @@ pub fn paginate<T>(page_size: usize, choices: &[T], sel: usize) -> Page<T> {
         let cursor = page_size / 2;
 
         (start, end, cursor)
+    }
+}
+
+/// Finds the index out of `len` closest to `cursor` for which `selectable`
+/// holds, looking first in the direction the cursor was moving: from `cursor`
+/// on when moving `forward`, or back from it otherwise.
+///
+/// The indexes behind the cursor are looked at next, starting from the other
+/// end of the list when `wrap` is set, or from the cursor otherwise.
+pub fn closest_selectable<F>(
+    cursor: usize,
+    len: usize,
+    forward: bool,
+    wrap: bool,
+    selectable: F,
+) -> Option<usize>
+where
+    F: Fn(usize) -> bool,
+{
+    let ahead: Box<dyn Iterator<Item = usize>> = if forward {
+        Box::new(cursor..len)
+    } else {
+        Box::new((0..=cursor.min(len.saturating_sub(1))).rev())
+    };
+    let behind: Box<dyn Iterator<Item = usize>> = match (forward, wrap) {
+        (true, true) => Box::new(0..cursor),
+        (true, false) => Box::new((0..cursor).rev()),
+        (false, true) => Box::new((cursor + 1..len).rev()),
+        (false, false) => Box::new(cursor + 1..len),
     };
 
-    Page {
-        first: start == 0,
-        last: end == choices.len(),
-        content: &choices[start..end],
-        selection: cursor,
-        total: choices.len(),
-    }
+    ahead.chain(behind).find(|i| selectable(*i))
 }
 
 pub fn int_log10<T>(mut i: T) -> usize
@@ -62,15 +101,67 @@ where
     len
 }
 
+/// Lowercases `s` and strips the diacritics of its characters, e.g. so that
+/// `"Café"` becomes `"cafe"`.
+///
+/// Only precomposed Latin letters and combining diacritical marks are folded.
+pub fn fold_accents(s: &str) -> String {
+    s.to_lowercase()
+        .chars()
+        .filter(|c| !('\u{300}'..='\u{36f}').contains(c))
+        .map(|c| match c {
+            'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ā' | 'ă' | 'ą' => 'a',
+            'ç' | 'ć' | 'ĉ' | 'ċ' | 'č' => 'c',
+            'ď' | 'đ' => 'd',
+            'è' | 'é' | 'ê' | 'ë' | 'ē' | 'ĕ' | 'ė' | 'ę' | 'ě' => 'e',
+            'ĝ' | 'ğ' | 'ġ' | 'ģ' => 'g',
+            'ĥ' | 'ħ' => 'h',
+            'ì' | 'í' | 'î' | 'ï' | 'ĩ' | 'ī' | 'ĭ' | 'į' | 'ı' => 'i',
+            'ĵ' => 'j',
+            'ķ' => 'k',
+            'ĺ' | 'ļ' | 'ľ' | 'ŀ' | 'ł' => 'l',
+            'ñ' | 'ń' | 'ņ' | 'ň' => 'n',
+            'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' | 'ō' | 'ŏ' | 'ő' => 'o',
+            'ŕ' | 'ŗ' | 'ř' => 'r',
+            'ś' | 'ŝ' | 'ş' | 'š' => 's',
+            'ţ' | 'ť' | 'ŧ' => 't',
+            'ù' | 'ú' | 'û' | 'ü' | 'ũ' | 'ū' | 'ŭ' | 'ů' | 'ű' | 'ų' => 'u',
+            'ŵ' => 'w',
+            'ý' | 'ÿ' | 'ŷ' => 'y',
+            'ź' | 'ż' | 'ž' => 'z',
+            c => c,
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod test {
     #![allow(clippy::bool_assert_comparison)]
 
     use crate::{
         list_option::ListOption,
-        utils::{int_log10, paginate},
+        utils::{closest_selectable, fold_accents, int_log10, paginate},
     };
 
+    #[test]
+    fn closest_selectable_looks_ahead_first() {
+        let selectable = |i| i != 2 && i != 3;
+
+        assert_eq!(Some(4), closest_selectable(2, 6, true, false, selectable));
+        assert_eq!(Some(1), closest_selectable(3, 6, false, false, selectable));
+        assert_eq!(Some(5), closest_selectable(2, 6, false, true, |i| i == 5));
+    }
+
+    #[test]
+    fn closest_selectable_looks_behind_at_the_ends() {
+        let selectable = |i| i < 3;
+
+        assert_eq!(Some(2), closest_selectable(4, 6, true, false, selectable));
+        assert_eq!(Some(0), closest_selectable(4, 6, true, true, selectable));
+        assert_eq!(None, closest_selectable(0, 6, true, false, |_| false));
+        assert_eq!(None, closest_selectable(0, 0, true, false, selectable));
+    }
+
     #[test]
     fn int_log10_works() {
         for i in 1..10 {
@@ -153,4 +244,71 @@ mod test {
         assert_eq!(true, page.last);
         assert_eq!(6, page.total);
     }
+
+    fn long_list() -> Vec<ListOption<usize>> {
+        ListOption::from_list((0..20).collect())
+    }
+
+    #[test]
+    fn paginate_long_list_top() {
+        let choices = long_list();
+
+        let page = paginate(7, &choices, 2);
+
+        assert_eq!(choices[0..7], page.content[..]);
+        assert_eq!(2usize, page.selection);
+        assert_eq!(true, page.first);
+        assert_eq!(false, page.last);
+        assert_eq!(20, page.total);
+    }
+
+    #[test]
+    fn paginate_long_list_middle() {
+        let choices = long_list();
+
+        let page = paginate(7, &choices, 10);
+
+        assert_eq!(choices[7..14], page.content[..]);
+        assert_eq!(3usize, page.selection);
+        assert_eq!(false, page.first);
+        assert_eq!(false, page.last);
+        assert_eq!(20, page.total);
+    }
+
+    #[test]
+    fn paginate_long_list_bottom() {
+        let choices = long_list();
+
+        let page = paginate(7, &choices, 19);
+
+        assert_eq!(choices[13..20], page.content[..]);
+        assert_eq!(6usize, page.selection);
+        assert_eq!(false, page.first);
+        assert_eq!(true, page.last);
+        assert_eq!(20, page.total);
+    }
+
+    #[test]
+    fn paginate_long_list_scrolls_one_option_at_a_time() {
+        let choices = long_list();
+
+        for sel in 3..17 {
+            let page = paginate(7, &choices, sel);
+
+            assert_eq!(choices[sel - 3..sel + 4], page.content[..]);
+            assert_eq!(3usize, page.selection, "selection not centered at {}", sel);
+        }
+    }
+
+    #[test]
+    fn fold_accents_strips_diacritics_and_case() {
+        assert_eq!("cafe", fold_accents("Café"));
+        assert_eq!("creme brulee", fold_accents("CRÈME BRÛLÉE"));
+        assert_eq!("sao paulo", fold_accents("São Paulo"));
+    }
+
+    #[test]
+    fn fold_accents_strips_combining_marks() {
+        assert_eq!("cafe", fold_accents("cafe\u{301}"));
+    }
 }
@@ -5,6 +5,8 @@
 //! they are valid, returning `Ok(Validation::Valid)` in the process, or
 //! invalid, returning `Ok(Validation::Invalid(ErrorMessage))`, where the
 //! `ErrorMessage` content is an error message to be displayed to the end user.
+//! Messages built from a [`ValidationError`] also carry a code, which callers
+//! can inspect with [`ErrorMessage::code`].
 //!
 //! Validators can also return errors, which propagate to the caller prompt
 //! and cause the prompt to return the error.
@@ -12,6 +14,8 @@
 //! This module also provides several macros as shorthands to the struct
 //! constructor functions, exported with the `macros` feature.
 
+use std::{marker::PhantomData, str::FromStr};
+
 use dyn_clone::DynClone;
 
 use crate::{error::CustomUserError, list_option::ListOption};
@@ -26,6 +30,20 @@ pub enum ErrorMessage {
 
     /// Custom error message, used instead of the standard one.
     Custom(String),
+
+    /// Custom error message identified by a code, for callers that need to
+    /// handle validation errors programmatically, e.g. to translate them.
+    Coded(ValidationError),
+}
+
+impl ErrorMessage {
+    /// Returns the code identifying the error, if any.
+    pub fn code(&self) -> Option<&str> {
+        match self {
+            ErrorMessage::Coded(err) => Some(&err.code),
+            _ => None,
+        }
+    }
 }
 
 impl Default for ErrorMessage {
@@ -34,6 +52,55 @@ impl Default for ErrorMessage {
     }
 }
 
+impl From<ValidationError> for ErrorMessage {
+    fn from(err: ValidationError) -> Self {
+        Self::Coded(err)
+    }
+}
+
+/// Validation error carrying a code along with the message displayed to the user.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::validator::{StringValidator, Validation, ValidationError};
+///
+/// let validator = |input: &str| match input.len() {
+///     0..=7 => Ok(Validation::Invalid(
+///         ValidationError::new("too_short", "Use at least 8 characters").into(),
+///     )),
+///     _ => Ok(Validation::Valid),
+/// };
+///
+/// match validator.validate("hunter2")? {
+///     Validation::Invalid(msg) => assert_eq!(Some("too_short"), msg.code()),
+///     _ => panic!("expected an invalid answer"),
+/// }
+/// # Ok::<(), inquire::error::CustomUserError>(())
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ValidationError {
+    /// Code identifying the error.
+    pub code: String,
+
+    /// Message displayed to the user.
+    pub message: String,
+}
+
+impl ValidationError {
+    /// Creates a [ValidationError] with the given code and message.
+    pub fn new<C, M>(code: C, message: M) -> Self
+    where
+        C: ToString,
+        M: ToString,
+    {
+        Self {
+            code: code.to_string(),
+            message: message.to_string(),
+        }
+    }
+}
+
 impl<T> From<T> for ErrorMessage
 where
     T: ToString,
@@ -56,6 +123,14 @@ pub enum Validation {
     /// When empty a standard error message, configured via the RenderConfig struct, will be shown
     /// instead.
     Invalid(ErrorMessage),
+
+    /// Variant that indicates that the input value is accepted, but deserves a warning to the user,
+    /// e.g. because it looks like a typo.
+    ///
+    /// Warnings do not prevent the submission of the answer. Prompts with live validation,
+    /// such as [`Text`](crate::Text), display the message while the user types. When empty,
+    /// a standard warning message, configured via the RenderConfig struct, will be shown instead.
+    Warning(ErrorMessage),
 }
 
 /// Validator that receives a string slice as the input, such as [`Text`](crate::Text) and
@@ -67,6 +142,9 @@ pub enum Validation {
 /// where the content of `ErrorMessage` is recommended to be a string whose content will be displayed
 /// to the user as an error message. It is also recommended that this value gives a helpful feedback to the user.
 ///
+/// If the input is acceptable but questionable, your validator can return `Ok(Validation::Warning(ErrorMessage))`
+/// instead: the message is displayed during live validation, but the answer can still be submitted.
+///
 /// # Examples
 ///
 /// ```
@@ -699,14 +777,238 @@ macro_rules! length {
     };
 }
 
+/// Built-in validator that checks whether the answer length is within the
+/// specified inclusive range.
+///
+/// The validator uses a custom-built length function that
+/// has a special implementation for strings which counts the number of
+/// graphemes. See this [StackOverflow question](https://stackoverflow.com/questions/46290655/get-the-string-length-in-characters-in-rust).
+///
+/// # Examples
+///
+/// ```
+/// use inquire::validator::{LengthRangeValidator, StringValidator, Validation};
+///
+/// let validator = LengthRangeValidator::new(2, 4);
+/// assert_eq!(Validation::Valid, validator.validate("Yes")?);
+/// assert_eq!(
+///     Validation::Invalid("The length of the response should be between 2 and 4".into()),
+///     validator.validate("Nope!")?,
+/// );
+///
+/// let validator = LengthRangeValidator::new(2, 4).with_message("Two to four characters.");
+/// assert_eq!(Validation::Valid, validator.validate("No")?);
+/// assert_eq!(Validation::Invalid("Two to four characters.".into()), validator.validate("N")?);
+/// # Ok::<(), inquire::error::CustomUserError>(())
+/// ```
+#[derive(Clone)]
+pub struct LengthRangeValidator {
+    min: usize,
+    max: usize,
+    message: String,
+}
+
+impl LengthRangeValidator {
+    /// Create a new instance of this validator, requiring a length between `min` and `max`,
+    /// inclusive, otherwise returning an error with default message.
+    pub fn new(min: usize, max: usize) -> Self {
+        Self {
+            min,
+            max,
+            message: format!(
+                "The length of the response should be between {} and {}",
+                min, max
+            ),
+        }
+    }
+
+    /// Define a custom error message returned by the validator.
+    /// Defaults to `The length of the response should be between $min and $max`.
+    pub fn with_message(mut self, message: impl Into<String>) -> Self {
+        self.message = message.into();
+        self
+    }
+
+    fn validate_inquire_length<T: InquireLength>(
+        &self,
+        input: T,
+    ) -> Result<Validation, CustomUserError> {
+        let length = input.inquire_length();
+
+        Ok(if length >= self.min && length <= self.max {
+            Validation::Valid
+        } else {
+            Validation::Invalid(self.message.as_str().into())
+        })
+    }
+}
+
+impl StringValidator for LengthRangeValidator {
+    fn validate(&self, input: &str) -> Result<Validation, CustomUserError> {
+        self.validate_inquire_length(input)
+    }
+}
+
+impl<T: ?Sized> MultiOptionValidator<T> for LengthRangeValidator {
+    fn validate(&self, input: &[ListOption<&T>]) -> Result<Validation, CustomUserError> {
+        self.validate_inquire_length(input)
+    }
+}
+
+/// Shorthand for the built-in [`LengthRangeValidator`] that checks whether the answer length is
+/// within the specified inclusive range.
+///
+/// # Arguments
+///
+/// * `$min` - Minimum length of the input.
+/// * `$max` - Maximum length of the input.
+/// * `$message` - optional - Error message returned by the validator.
+///   Defaults to "The length of the response should be between $min and $max"
+///
+/// # Examples
+///
+/// ```
+/// use inquire::{length_between, validator::{StringValidator, Validation}};
+///
+/// let validator = length_between!(2, 4);
+/// assert_eq!(Validation::Valid, validator.validate("Yes")?);
+/// assert_eq!(Validation::Invalid("The length of the response should be between 2 and 4".into()), validator.validate("Nope!")?);
+///
+/// let validator = length_between!(2, 4, "Two to four characters.");
+/// assert_eq!(Validation::Valid, validator.validate("No")?);
+/// assert_eq!(Validation::Invalid("Two to four characters.".into()), validator.validate("N")?);
+/// # Ok::<(), inquire::error::CustomUserError>(())
+/// ```
+#[macro_export]
+#[cfg(feature = "macros")]
+macro_rules! length_between {
+    ($min:expr, $max:expr) => {
+        $crate::validator::LengthRangeValidator::new($min, $max)
+    };
+
+    ($min:expr, $max:expr, $message:expr) => {
+        $crate::length_between!($min, $max).with_message($message)
+    };
+}
+
+/// Built-in validator that checks whether the answer can be parsed into a value
+/// of type `T`, using its [`FromStr`](std::str::FromStr) implementation.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::validator::{ParseableValidator, StringValidator, Validation};
+///
+/// let validator = ParseableValidator::<u32>::new();
+/// assert_eq!(Validation::Valid, validator.validate("42")?);
+/// assert_eq!(
+///     Validation::Invalid("The response could not be parsed into a valid value".into()),
+///     validator.validate("-42")?,
+/// );
+///
+/// let validator = ParseableValidator::<f64>::new().with_message("Please type a number.");
+/// assert_eq!(Validation::Valid, validator.validate("4.2")?);
+/// assert_eq!(Validation::Invalid("Please type a number.".into()), validator.validate("four")?);
+/// # Ok::<(), inquire::error::CustomUserError>(())
+/// ```
+pub struct ParseableValidator<T> {
+    message: String,
+    phantom: PhantomData<fn() -> T>,
+}
+
+impl<T> ParseableValidator<T>
+where
+    T: FromStr,
+{
+    /// Create a new instance of this validator with the default error message.
+    pub fn new() -> Self {
+        Self {
+            message: String::from("The response could not be parsed into a valid value"),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Define a custom error message returned by the validator.
+    /// Defaults to `The response could not be parsed into a valid value`.
+    pub fn with_message(mut self, message: impl Into<String>) -> Self {
+        self.message = message.into();
+        self
+    }
+}
+
+impl<T> Default for ParseableValidator<T>
+where
+    T: FromStr,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Clone for ParseableValidator<T> {
+    fn clone(&self) -> Self {
+        Self {
+            message: self.message.clone(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T> StringValidator for ParseableValidator<T>
+where
+    T: FromStr,
+{
+    fn validate(&self, input: &str) -> Result<Validation, CustomUserError> {
+        Ok(match input.parse::<T>() {
+            Ok(_) => Validation::Valid,
+            Err(_) => Validation::Invalid(self.message.as_str().into()),
+        })
+    }
+}
+
+/// Shorthand for the built-in [`ParseableValidator`] that checks whether the answer can be
+/// parsed into a value of the given type.
+///
+/// # Arguments
+///
+/// * `$type` - Target type, which must implement `FromStr`.
+/// * `$message` - optional - Error message returned by the validator.
+///   Defaults to "The response could not be parsed into a valid value"
+///
+/// # Examples
+///
+/// ```
+/// use inquire::{parseable, validator::{StringValidator, Validation}};
+///
+/// let validator = parseable!(u8);
+/// assert_eq!(Validation::Valid, validator.validate("255")?);
+/// assert_eq!(Validation::Invalid("The response could not be parsed into a valid value".into()), validator.validate("256")?);
+///
+/// let validator = parseable!(u8, "Please type a byte.");
+/// assert_eq!(Validation::Invalid("Please type a byte.".into()), validator.validate("byte")?);
+/// # Ok::<(), inquire::error::CustomUserError>(())
+/// ```
+#[macro_export]
+#[cfg(feature = "macros")]
+macro_rules! parseable {
+    ($type:ty) => {
+        $crate::validator::ParseableValidator::<$type>::new()
+    };
+
+    ($type:ty, $message:expr) => {
+        $crate::parseable!($type).with_message($message)
+    };
+}
+
 #[cfg(test)]
 mod validators_test {
     use crate::{
         error::CustomUserError,
         list_option::ListOption,
         validator::{
-            ExactLengthValidator, MaxLengthValidator, MinLengthValidator, MultiOptionValidator,
-            StringValidator, Validation,
+            ErrorMessage, ExactLengthValidator, LengthRangeValidator, MaxLengthValidator,
+            MinLengthValidator, MultiOptionValidator, ParseableValidator, StringValidator,
+            Validation, ValidationError, ValueRequiredValidator,
         },
     };
 
@@ -918,4 +1220,105 @@ mod validators_test {
 
         Ok(())
     }
+
+    #[test]
+    fn value_required() -> Result<(), CustomUserError> {
+        let validator = ValueRequiredValidator::default();
+
+        assert_eq!(Validation::Valid, validator.validate(" ")?);
+        assert_eq!(
+            Validation::Invalid("A response is required.".into()),
+            validator.validate("")?
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn length_range_is_inclusive() -> Result<(), CustomUserError> {
+        let validator = LengthRangeValidator::new(2, 4);
+        let validator: &dyn StringValidator = &validator;
+
+        assert!(matches!(validator.validate("🤦🏼‍♂️")?, Validation::Invalid(_)));
+        assert!(matches!(validator.validate("🤦🏼‍♂️🤦🏼‍♂️")?, Validation::Valid));
+        assert!(matches!(validator.validate("four")?, Validation::Valid));
+        assert!(matches!(
+            validator.validate("five!")?,
+            Validation::Invalid(_)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn slice_length_range() -> Result<(), CustomUserError> {
+        let validator = LengthRangeValidator::new(2, 3).with_message("Pick two or three");
+        let validator: &dyn MultiOptionValidator<str> = &validator;
+
+        assert_eq!(
+            Validation::Invalid("Pick two or three".into()),
+            validator.validate(&build_option_vec(1))?
+        );
+        assert_eq!(Validation::Valid, validator.validate(&build_option_vec(3))?);
+        assert_eq!(
+            Validation::Invalid("Pick two or three".into()),
+            validator.validate(&build_option_vec(4))?
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn parseable() -> Result<(), CustomUserError> {
+        let validator = ParseableValidator::<u32>::new();
+
+        assert_eq!(Validation::Valid, validator.validate("1234")?);
+        assert!(matches!(
+            validator.validate("12.34")?,
+            Validation::Invalid(_)
+        ));
+        assert!(matches!(validator.validate("")?, Validation::Invalid(_)));
+
+        let validator = ParseableValidator::<f64>::new().with_message("Not a number");
+
+        assert_eq!(Validation::Valid, validator.validate("12.34")?);
+        assert_eq!(
+            Validation::Invalid("Not a number".into()),
+            validator.validate("twelve")?
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn validation_error_code_survives_the_conversion() -> Result<(), CustomUserError> {
+        let validator = |input: &str| match input.parse::<u32>() {
+            Ok(_) => Ok(Validation::Valid),
+            Err(_) => Ok(Validation::Invalid(
+                ValidationError::new("not_a_number", "Not a number").into(),
+            )),
+        };
+        let validator: Box<dyn StringValidator> = Box::new(validator);
+
+        match validator.validate("twelve")? {
+            Validation::Invalid(msg) => assert_eq!(Some("not_a_number"), msg.code()),
+            validation => panic!("unexpected validation: {:?}", validation),
+        }
+        match validator.clone().validate("twelve")? {
+            Validation::Invalid(msg) => assert_eq!(Some("not_a_number"), msg.code()),
+            validation => panic!("unexpected validation: {:?}", validation),
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn plain_messages_have_no_code() {
+        assert_eq!(None, ErrorMessage::from("Not a number").code());
+        assert_eq!(
+            None,
+            ErrorMessage::from(String::from("Not a number")).code()
+        );
+        assert_eq!(None, ErrorMessage::Default.code());
+    }
 }